@@ -20,6 +20,7 @@ use crate::{
     expressions::{
         ArrayInitializerExpression,
         ArrayInlineExpression,
+        CastExpression,
         CircuitInlineExpression,
         Expression,
         PostfixExpression,
@@ -27,6 +28,7 @@ use crate::{
         UnaryExpression,
     },
     operations::{BinaryOperation, UnaryOperation},
+    types::DataType,
     values::Value,
 };
 
@@ -68,8 +70,14 @@ fn precedence_climber() -> PrecClimber<Rule> {
             | Operator::new(Rule::operation_gt, Assoc::Left)
             | Operator::new(Rule::operation_le, Assoc::Left)
             | Operator::new(Rule::operation_lt, Assoc::Left),
+        Operator::new(Rule::operation_bit_or, Assoc::Left),
+        Operator::new(Rule::operation_bit_xor, Assoc::Left),
+        Operator::new(Rule::operation_bit_and, Assoc::Left),
+        Operator::new(Rule::operation_shl, Assoc::Left) | Operator::new(Rule::operation_shr, Assoc::Left),
         Operator::new(Rule::operation_add, Assoc::Left) | Operator::new(Rule::operation_sub, Assoc::Left),
-        Operator::new(Rule::operation_mul, Assoc::Left) | Operator::new(Rule::operation_div, Assoc::Left),
+        Operator::new(Rule::operation_mul, Assoc::Left)
+            | Operator::new(Rule::operation_div, Assoc::Left)
+            | Operator::new(Rule::operation_rem, Assoc::Left),
         Operator::new(Rule::operation_pow, Assoc::Left),
     ])
 }
@@ -124,6 +132,25 @@ fn parse_term(pair: Pair<Rule>) -> Box<Expression> {
                 ),
             }
         }
+        Rule::expression_cast => {
+            // Casts are left associative, so `a as u8 as u16` is `(a as u8) as u16`
+            let start = pair.as_span().start_pos();
+            let mut inner = pair.into_inner();
+            let mut expression = *parse_term(inner.next().unwrap());
+
+            while inner.peek().is_some() {
+                let end = inner.peek().unwrap().as_span().end_pos();
+                let type_ = DataType::from_pest(&mut inner).unwrap();
+
+                expression = Expression::Cast(CastExpression {
+                    expression: Box::new(expression),
+                    type_,
+                    span: start.span(&end),
+                });
+            }
+
+            expression
+        }
         rule => unreachable!(
             "`parse_expression_term` should be invoked on `Rule::expression_term`, found {:#?}",
             rule
@@ -154,6 +181,12 @@ fn binary_expression<'ast>(
         Rule::operation_mul => Expression::binary(BinaryOperation::Mul, lhs, rhs, span),
         Rule::operation_div => Expression::binary(BinaryOperation::Div, lhs, rhs, span),
         Rule::operation_pow => Expression::binary(BinaryOperation::Pow, lhs, rhs, span),
+        Rule::operation_rem => Expression::binary(BinaryOperation::Rem, lhs, rhs, span),
+        Rule::operation_bit_and => Expression::binary(BinaryOperation::BitAnd, lhs, rhs, span),
+        Rule::operation_bit_or => Expression::binary(BinaryOperation::BitOr, lhs, rhs, span),
+        Rule::operation_bit_xor => Expression::binary(BinaryOperation::BitXor, lhs, rhs, span),
+        Rule::operation_shl => Expression::binary(BinaryOperation::Shl, lhs, rhs, span),
+        Rule::operation_shr => Expression::binary(BinaryOperation::Shr, lhs, rhs, span),
         _ => unreachable!(),
     })
 }
@@ -14,7 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ast::Rule, common::SelfKeywordOrIdentifier, functions::InputKeyword, types::SelfType};
+use crate::{
+    ast::Rule,
+    common::{GroupKeyword, SelfKeywordOrIdentifier},
+    functions::InputKeyword,
+    types::SelfType,
+};
 
 use pest_ast::FromPest;
 use serde::Serialize;
@@ -25,6 +30,7 @@ use std::fmt;
 pub enum KeywordOrIdentifier<'ast> {
     SelfType(SelfType<'ast>),
     Input(InputKeyword<'ast>),
+    Group(GroupKeyword<'ast>),
     SelfKeywordOrIdentifier(SelfKeywordOrIdentifier<'ast>),
 }
 
@@ -33,6 +39,7 @@ impl<'ast> fmt::Display for KeywordOrIdentifier<'ast> {
         match self {
             KeywordOrIdentifier::SelfType(self_type) => write!(f, "{}", self_type),
             KeywordOrIdentifier::Input(input_keyword) => write!(f, "{}", input_keyword),
+            KeywordOrIdentifier::Group(group_keyword) => write!(f, "{}", group_keyword),
             KeywordOrIdentifier::SelfKeywordOrIdentifier(name) => write!(f, "{}", name),
         }
     }
@@ -23,6 +23,9 @@ pub use declare::*;
 pub mod eoi;
 pub use eoi::*;
 
+pub mod group_keyword;
+pub use group_keyword::*;
+
 pub mod identifier;
 pub use identifier::*;
 
@@ -35,6 +35,7 @@ impl From<Error<Rule>> for SyntaxError {
             Rule::file => "an import, circuit, or function".to_owned(),
             Rule::identifier => "a variable name".to_owned(),
             Rule::type_ => "a type".to_owned(),
+            Rule::type_data => "a type".to_owned(),
             Rule::access => "`.`, `::`, `()`".to_owned(),
 
             Rule::operation_and => "`&&`".to_owned(),
@@ -50,6 +51,12 @@ impl From<Error<Rule>> for SyntaxError {
             Rule::operation_mul => "`*`".to_owned(),
             Rule::operation_div => "`/`".to_owned(),
             Rule::operation_pow => "`**`".to_owned(),
+            Rule::operation_rem => "`%`".to_owned(),
+            Rule::operation_bit_and => "`&`".to_owned(),
+            Rule::operation_bit_or => "`|`".to_owned(),
+            Rule::operation_bit_xor => "`^`".to_owned(),
+            Rule::operation_shl => "`<<`".to_owned(),
+            Rule::operation_shr => "`>>`".to_owned(),
 
             Rule::package => "package. Check package and import names for errors.".to_owned(),
             Rule::package_name => {
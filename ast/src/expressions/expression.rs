@@ -26,6 +26,7 @@ pub enum Expression<'ast> {
     Identifier(Identifier<'ast>),
     Unary(UnaryExpression<'ast>),
     Binary(BinaryExpression<'ast>),
+    Cast(CastExpression<'ast>),
     Ternary(TernaryExpression<'ast>),
     ArrayInline(ArrayInlineExpression<'ast>),
     ArrayInitializer(ArrayInitializerExpression<'ast>),
@@ -69,6 +70,7 @@ impl<'ast> Expression<'ast> {
             Expression::Identifier(expression) => &expression.span,
             Expression::Unary(expression) => &expression.span,
             Expression::Binary(expression) => &expression.span,
+            Expression::Cast(expression) => &expression.span,
             Expression::Ternary(expression) => &expression.span,
             Expression::ArrayInline(expression) => &expression.span,
             Expression::ArrayInitializer(expression) => &expression.span,
@@ -86,6 +88,7 @@ impl<'ast> fmt::Display for Expression<'ast> {
             Expression::Identifier(ref expression) => write!(f, "{}", expression),
             Expression::Unary(ref expression) => write!(f, "{}", expression),
             Expression::Binary(ref expression) => write!(f, "{} == {}", expression.left, expression.right),
+            Expression::Cast(ref expression) => write!(f, "{}", expression),
             Expression::Ternary(ref expression) => write!(
                 f,
                 "if {} ? {} : {}",
@@ -23,6 +23,9 @@ pub use array_inline_expression::*;
 pub mod binary_expression;
 pub use binary_expression::*;
 
+pub mod cast_expression;
+pub use cast_expression::*;
+
 pub mod circuit_inline_expression;
 pub use circuit_inline_expression::*;
 
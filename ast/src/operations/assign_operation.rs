@@ -28,6 +28,12 @@ pub enum AssignOperation {
     MulAssign(MulAssign),
     DivAssign(DivAssign),
     PowAssign(PowAssign),
+    RemAssign(RemAssign),
+    BitAndAssign(BitAndAssign),
+    BitOrAssign(BitOrAssign),
+    BitXorAssign(BitXorAssign),
+    ShlAssign(ShlAssign),
+    ShrAssign(ShrAssign),
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
@@ -53,3 +59,27 @@ pub struct DivAssign {}
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
 #[pest_ast(rule(Rule::operation_pow_assign))]
 pub struct PowAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_rem_assign))]
+pub struct RemAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_bit_and_assign))]
+pub struct BitAndAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_bit_or_assign))]
+pub struct BitOrAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_bit_xor_assign))]
+pub struct BitXorAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_shl_assign))]
+pub struct ShlAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_shr_assign))]
+pub struct ShrAssign {}
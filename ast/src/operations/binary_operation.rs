@@ -35,4 +35,10 @@ pub enum BinaryOperation {
     Mul,
     Div,
     Pow,
+    Rem,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
 }
@@ -23,11 +23,15 @@ use crate::{
     ImportParser,
     OutputBytes,
     OutputFile,
+    TestOptions,
+    TestReport,
 };
 use leo_ast::LeoAst;
 use leo_input::LeoInputParser;
+use leo_optimizer::{OptLevel, Optimizer};
 use leo_package::inputs::InputPairs;
 use leo_state::verify_local_data_commitment;
+use leo_type_inference::TypeChecker;
 use leo_typed::{Input, LeoTypedAst, MainInput, Program};
 
 use snarkos_dpc::{base_dpc::instantiated::Components, SystemParameters};
@@ -48,6 +52,7 @@ pub struct Compiler<F: Field + PrimeField, G: GroupType<F>> {
     program: Program,
     program_input: Input,
     imported_programs: ImportParser,
+    opt_level: OptLevel,
     _engine: PhantomData<F>,
     _group: PhantomData<G>,
 }
@@ -61,6 +66,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             program: Program::new(package_name),
             program_input: Input::new(),
             imported_programs: ImportParser::new(),
+            opt_level: OptLevel::None,
             _engine: PhantomData,
             _group: PhantomData,
         }
@@ -162,12 +168,34 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         self.program = typed_tree.into_repr();
         self.imported_programs = ImportParser::parse(&self.program)?;
 
+        // Run the type checker before any constraints are generated.
+        TypeChecker::check_program(&self.program, self.imported_programs.imports()).map_err(|mut e| {
+            e.set_path(self.main_file_path.clone());
+
+            e
+        })?;
+
         tracing::debug!("Program parsing complete\n{:#?}", self.program);
 
         Ok(())
     }
 
+    /// Runs the optimization passes enabled by the given level over the parsed program.
+    pub fn optimize(&mut self, opt_level: OptLevel) {
+        let program = std::mem::replace(&mut self.program, Program::new(self.package_name.clone()));
+
+        self.program = Optimizer::new(opt_level).optimize(program);
+        self.opt_level = opt_level;
+
+        tracing::debug!("Program optimization complete (opt-level {})", opt_level);
+    }
+
     /// Manually sets main function input
+    /// Returns the typed syntax tree of the main program.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
     pub fn set_main_input(&mut self, input: MainInput) {
         self.program_input.set_main_input(input);
     }
@@ -190,6 +218,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         // Hash the file contents
         let mut hasher = Sha256::new();
         hasher.update(unparsed_file.as_bytes());
+
+        // The same file synthesizes a different circuit at each optimization level.
+        if self.opt_level != OptLevel::None {
+            hasher.update(format!("opt-level={}", self.opt_level).as_bytes());
+        }
         let hash = hasher.finalize();
 
         Ok(hex::encode(hash))
@@ -209,13 +242,18 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
     }
 
     /// Synthesizes the circuit for test functions with program input.
-    pub fn compile_test_constraints(self, input_pairs: InputPairs) -> Result<(u32, u32), CompilerError> {
+    pub fn compile_test_constraints(
+        self,
+        input_pairs: InputPairs,
+        options: &TestOptions,
+    ) -> Result<TestReport, CompilerError> {
         generate_test_constraints::<F, G>(
             self.program,
             input_pairs,
             &self.imported_programs,
             &self.main_file_path,
             &self.output_directory,
+            options,
         )
     }
 
@@ -248,6 +286,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             program,
             program_input,
             imported_programs: ImportParser::new(),
+            opt_level: OptLevel::None,
             _engine: PhantomData,
             _group: PhantomData,
         })
@@ -25,6 +25,10 @@ use crate::{
     ImportParser,
     OutputBytes,
     OutputFile,
+    TestOptions,
+    TestReport,
+    TestResult,
+    TestStatus,
 };
 use leo_typed::{Input, Program};
 
@@ -34,7 +38,7 @@ use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::r1cs::{ConstraintSystem, TestConstraintSystem},
 };
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Instant};
 
 pub fn generate_constraints<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
@@ -67,11 +71,14 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
     imported_programs: &ImportParser,
     main_file_path: &PathBuf,
     output_directory: &PathBuf,
-) -> Result<(u32, u32), CompilerError> {
+    options: &TestOptions,
+) -> Result<TestReport, CompilerError> {
     let mut resolved_program = ConstrainedProgram::<F, G>::new();
     let program_name = program.get_name();
 
-    let tests = program.tests.clone();
+    // Run the tests in order of their names
+    let mut tests = program.tests.clone().into_iter().collect::<Vec<_>>();
+    tests.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
 
     // Store definitions
     resolved_program.store_definitions(program, imported_programs)?;
@@ -79,11 +86,17 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
     // Get default input
     let default = input.pairs.get(&program_name);
 
-    tracing::info!("Running {} tests", tests.len());
+    // Filter the tests to run
+    let (tests, filtered_out): (Vec<_>, Vec<_>) = tests
+        .into_iter()
+        .partition(|(test_name, _)| options.matches(&test_name.name, &format!("{}::{}", program_name, test_name)));
+
+    let mut report = TestReport {
+        results: vec![],
+        filtered_out: filtered_out.len(),
+    };
 
-    // Count passed and failed tests
-    let mut passed = 0;
-    let mut failed = 0;
+    tracing::info!("Running {} tests", tests.len());
 
     for (test_name, test) in tests.into_iter() {
         let cs = &mut TestConstraintSystem::<F>::new();
@@ -118,16 +131,23 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
         input.parse_state(state_ast)?;
 
         // run test function on new program with input
+        let start = Instant::now();
         let result = resolved_program.clone().enforce_main_function(
             cs,
             program_name.clone(),
             test.function,
             input, // pass program input into every test
         );
+        let duration = start.elapsed();
 
-        match (result.is_ok(), cs.is_satisfied()) {
+        let status = match (result.is_ok(), cs.is_satisfied()) {
             (true, true) => {
-                tracing::info!("{} ... ok\n", full_test_name);
+                tracing::info!(
+                    "{} ... ok ({} constraints in {:?})\n",
+                    full_test_name,
+                    cs.num_constraints(),
+                    duration
+                );
 
                 // write result to file
                 let output = result?;
@@ -135,14 +155,12 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
 
                 output_file.write(output_directory, output.bytes()).unwrap();
 
-                // increment passed tests
-                passed += 1;
+                TestStatus::Passed
             }
             (true, false) => {
                 tracing::error!("{} constraint system not satisfied\n", full_test_name);
 
-                // increment failed tests
-                failed += 1;
+                TestStatus::Failed("constraint system not satisfied".to_string())
             }
             (false, _) => {
                 // Set file location of error
@@ -151,11 +169,24 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
 
                 tracing::error!("{} failed due to error\n\n{}\n", full_test_name, error);
 
-                // increment failed tests
-                failed += 1;
+                TestStatus::Failed(error.to_string())
             }
+        };
+
+        let failed = status != TestStatus::Passed;
+
+        report.results.push(TestResult {
+            name: full_test_name,
+            status,
+            num_constraints: cs.num_constraints(),
+            duration,
+        });
+
+        if failed && options.fail_fast {
+            tracing::error!("Stopping after the first failure\n");
+            break;
         }
     }
 
-    Ok((passed, failed))
+    Ok(report)
 }
@@ -18,3 +18,6 @@
 
 pub mod constraints;
 pub use self::constraints::*;
+
+pub mod test_report;
+pub use self::test_report::*;
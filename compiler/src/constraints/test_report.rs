@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Options and results of running the test functions of a Leo program.
+
+use std::time::Duration;
+
+/// Selects which test functions are run and when to stop.
+#[derive(Clone, Debug, Default)]
+pub struct TestOptions {
+    /// Only run tests whose name contains this filter.
+    pub filter: Option<String>,
+    /// Only run the test whose name is exactly the filter.
+    pub exact: bool,
+    /// Stop running tests after the first failure.
+    pub fail_fast: bool,
+}
+
+impl TestOptions {
+    /// Returns `true` if the test with the given name and full `program::test` name should be run.
+    pub fn matches(&self, test_name: &str, full_test_name: &str) -> bool {
+        match &self.filter {
+            Some(filter) if self.exact => test_name == filter || full_test_name == filter,
+            Some(filter) => full_test_name.contains(filter.as_str()),
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TestStatus {
+    Passed,
+    Failed(String),
+}
+
+/// The result of running a single test function.
+#[derive(Clone, Debug)]
+pub struct TestResult {
+    pub name: String,
+    pub status: TestStatus,
+    pub num_constraints: usize,
+    pub duration: Duration,
+}
+
+impl TestResult {
+    pub fn is_passed(&self) -> bool {
+        self.status == TestStatus::Passed
+    }
+}
+
+/// The results of running the test functions of a program.
+#[derive(Clone, Debug, Default)]
+pub struct TestReport {
+    pub results: Vec<TestResult>,
+    /// The number of tests that did not match the filter.
+    pub filtered_out: usize,
+}
+
+impl TestReport {
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|result| result.is_passed()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.results.iter().map(|result| result.duration).sum()
+    }
+}
@@ -18,6 +18,7 @@ use crate::errors::{FunctionError, ImportError, OutputBytesError, OutputFileErro
 use leo_ast::ParserError;
 use leo_input::InputParserError;
 use leo_state::LocalDataVerificationError;
+use leo_type_inference::TypeInferenceError;
 
 use bincode::Error as SerdeError;
 use std::path::PathBuf;
@@ -62,6 +63,9 @@ pub enum CompilerError {
 
     #[error("{}", _0)]
     SerdeError(#[from] SerdeError),
+
+    #[error("{}", _0)]
+    TypeInferenceError(#[from] TypeInferenceError),
 }
 
 impl CompilerError {
@@ -70,6 +74,7 @@ impl CompilerError {
             CompilerError::InputParserError(error) => error.set_path(path),
             CompilerError::FunctionError(error) => error.set_path(path),
             CompilerError::OutputStringError(error) => error.set_path(path),
+            CompilerError::TypeInferenceError(error) => error.set_path(path),
             _ => {}
         }
     }
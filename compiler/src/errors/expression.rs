@@ -85,6 +85,12 @@ impl ExpressionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn cast_overflow(value: String, type_: String, span: Span) -> Self {
+        let message = format!("cannot cast `{}` as `{}` without losing its value", value, type_);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("if, else conditional must resolve to a boolean, found `{}`", actual);
 
@@ -109,6 +115,12 @@ impl ExpressionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn invalid_cast(actual: String, type_: String, span: Span) -> Self {
+        let message = format!("cannot cast `{}` as `{}`", actual, type_);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn invalid_index(actual: String, span: Span) -> Self {
         let message = format!("index must resolve to an integer, found `{}`", actual);
 
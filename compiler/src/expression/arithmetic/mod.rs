@@ -33,3 +33,6 @@ pub use self::div::*;
 
 pub mod pow;
 pub use self::pow::*;
+
+pub mod rem;
+pub use self::rem::*;
@@ -37,6 +37,14 @@ pub fn enforce_mul<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<
         (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
             Ok(ConstrainedValue::Field(field_1.mul(cs, &field_2, span)?))
         }
+        (ConstrainedValue::Group(group), ConstrainedValue::Field(scalar))
+        | (ConstrainedValue::Field(scalar), ConstrainedValue::Group(group)) => {
+            let bits = scalar
+                .to_bits_le(cs.ns(|| format!("scalar bits {}:{}", span.line, span.start)))
+                .map_err(|error| ExpressionError::cannot_enforce(format!("*"), error, span.clone()))?;
+
+            Ok(ConstrainedValue::Group(group.mul(cs, &bits, span)?))
+        }
         (ConstrainedValue::Unresolved(string), val_2) => {
             let val_1 = ConstrainedValue::from_other(string, &val_2, span.clone())?;
             enforce_mul(cs, val_1, val_2, span)
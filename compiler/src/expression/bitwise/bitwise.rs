@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a bitwise expression in a compiled Leo program.
+
+use crate::{bitwise::*, errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use leo_typed::{Expression, Type};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::r1cs::ConstraintSystem,
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Kept out of `enforce_expression` to keep its stack frame small for deeply nested expressions.
+    pub(crate) fn enforce_bitwise_expression<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        expected_type: Option<Type>,
+        expression: Expression,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let (enforce, left, right, span): (fn(_, _, _, _) -> _, _, _, _) = match expression {
+            Expression::BitAnd(left, right, span) => (enforce_bit_and::<F, G, CS>, left, right, span),
+            Expression::BitOr(left, right, span) => (enforce_bit_or::<F, G, CS>, left, right, span),
+            Expression::BitXor(left, right, span) => (enforce_bit_xor::<F, G, CS>, left, right, span),
+            Expression::Shl(left, right, span) => (enforce_shl::<F, G, CS>, left, right, span),
+            Expression::Shr(left, right, span) => (enforce_shr::<F, G, CS>, left, right, span),
+            expression => unreachable!("`{}` is not a bitwise expression", expression),
+        };
+
+        let (resolved_left, resolved_right) = self.enforce_binary_expression(
+            cs,
+            file_scope,
+            function_scope,
+            expected_type,
+            *left,
+            *right,
+            span.clone(),
+        )?;
+
+        enforce(cs, resolved_left, resolved_right, span)
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a cast `as` expression in a compiled Leo program.
+
+use crate::{errors::ExpressionError, value::ConstrainedValue, FieldType, GroupType, Integer};
+use leo_gadgets::bits::SignExtend;
+use leo_typed::{IntegerType, Span, Type};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        curves::FpGadget,
+        r1cs::{ConstraintSystem, ConstraintVar, LinearCombination},
+        utilities::{boolean::Boolean, eq::EqGadget},
+    },
+};
+
+pub fn enforce_cast<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    value: ConstrainedValue<F, G>,
+    type_: Type,
+    span: Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    match (value, type_) {
+        (ConstrainedValue::Mutable(value), type_) => enforce_cast(cs, *value, type_, span),
+        (ConstrainedValue::Unresolved(value), type_ @ Type::IntegerType(_))
+        | (ConstrainedValue::Unresolved(value), type_ @ Type::Field) => {
+            Ok(ConstrainedValue::from_type(value, &type_, span)?)
+        }
+        (ConstrainedValue::Integer(integer), Type::IntegerType(integer_type)) => Ok(ConstrainedValue::Integer(
+            cast_integer(cs, integer, &integer_type, span)?,
+        )),
+        (ConstrainedValue::Integer(integer), Type::Field) => Ok(ConstrainedValue::Field(integer_to_field::<F, CS>(
+            &integer.get_bits(),
+            integer.get_type().is_signed(),
+        ))),
+        (ConstrainedValue::Boolean(boolean), Type::IntegerType(integer_type)) => {
+            let mut bits = vec![boolean];
+            bits.resize(integer_type.size(), Boolean::constant(false));
+
+            Ok(ConstrainedValue::Integer(Integer::from_bits(&integer_type, bits)))
+        }
+        (value, type_) => Err(ExpressionError::invalid_cast(
+            value.to_string(),
+            type_.to_string(),
+            span,
+        )),
+    }
+}
+
+/// Widening sign extends signed integers and zero extends unsigned integers.
+/// Casting to a type of the same size reinterprets the bits.
+/// Narrowing fails if the value does not fit in the target type.
+fn cast_integer<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    integer: Integer,
+    integer_type: &IntegerType,
+    span: Span,
+) -> Result<Integer, ExpressionError> {
+    let source_type = integer.get_type();
+    let mut bits = integer.get_bits();
+    let size = integer_type.size();
+
+    if size > bits.len() {
+        bits = if source_type.is_signed() {
+            Boolean::sign_extend(&bits, size)
+        } else {
+            let mut bits = bits;
+            bits.resize(size, Boolean::constant(false));
+            bits
+        };
+    } else if size < bits.len() {
+        let dropped = bits.split_off(size);
+        let sign = bits[size - 1];
+
+        // Every dropped bit must equal the sign of the result, which is the most significant
+        // kept bit for signed to signed casts and zero otherwise.
+        let (fill, mut checked) = if source_type.is_signed() && integer_type.is_signed() {
+            (sign, dropped)
+        } else {
+            (Boolean::constant(false), dropped)
+        };
+        if !source_type.is_signed() && integer_type.is_signed() {
+            checked.push(sign);
+        }
+
+        let overflow = checked.iter().any(|bit| match (bit.get_value(), fill.get_value()) {
+            (Some(bit), Some(fill)) => bit != fill,
+            _ => false,
+        });
+        if overflow {
+            return Err(ExpressionError::cast_overflow(
+                integer.to_string(),
+                integer_type.to_string(),
+                span,
+            ));
+        }
+
+        for (i, bit) in checked.iter().enumerate() {
+            bit.enforce_equal(cs.ns(|| format!("cast bit {} {}:{}", i, span.line, span.start)), &fill)
+                .map_err(|error| ExpressionError::cannot_enforce("as".to_string(), error, span.clone()))?;
+        }
+    }
+
+    Ok(Integer::from_bits(integer_type, bits))
+}
+
+/// Packs little-endian integer bits into a field element without adding constraints.
+fn integer_to_field<F: Field + PrimeField, CS: ConstraintSystem<F>>(bits: &[Boolean], signed: bool) -> FieldType<F> {
+    let mut lc = LinearCombination::zero();
+    let mut value = Some(F::zero());
+    let mut coeff = F::one();
+
+    for (i, bit) in bits.iter().enumerate() {
+        // The most significant bit of a signed integer has a negative weight.
+        let weight = if signed && i == bits.len() - 1 { -coeff } else { coeff };
+
+        lc = lc + bit.lc(CS::one(), weight);
+        value = value.and_then(|value| bit.get_value().map(|bit| if bit { value + &weight } else { value }));
+        coeff.double_in_place();
+    }
+
+    if bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))) {
+        FieldType::Constant(value.unwrap())
+    } else {
+        FieldType::Allocated(FpGadget {
+            value,
+            variable: ConstraintVar::LC(lc),
+        })
+    }
+}
@@ -55,14 +55,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             }
         }
 
-        let (circuit_name, members) = match self.enforce_operand(
+        let value = self.enforce_operand(
             cs,
             file_scope.clone(),
-            function_scope.clone(),
+            function_scope,
             expected_type,
-            *circuit_identifier.clone(),
+            *circuit_identifier,
             span.clone(),
-        )? {
+        )?;
+
+        self.access_circuit_member(file_scope, value, circuit_member, span)
+    }
+
+    /// Returns the member of an evaluated circuit value.
+    pub(crate) fn access_circuit_member(
+        &mut self,
+        file_scope: String,
+        value: ConstrainedValue<F, G>,
+        circuit_member: Identifier,
+        span: Span,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let (circuit_name, members) = match value {
             ConstrainedValue::CircuitExpression(name, members) => (name, members),
             value => return Err(ExpressionError::undefined_circuit(value.to_string(), span)),
         };
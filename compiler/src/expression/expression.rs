@@ -18,6 +18,7 @@
 
 use crate::{
     arithmetic::*,
+    cast::*,
     errors::ExpressionError,
     logical::*,
     program::ConstrainedProgram,
@@ -132,6 +133,35 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
                 enforce_pow(cs, resolved_left, resolved_right, span)
             }
+            Expression::Rem(left, right, span) => {
+                let (resolved_left, resolved_right) = self.enforce_binary_expression(
+                    cs,
+                    file_scope.clone(),
+                    function_scope.clone(),
+                    expected_type,
+                    *left,
+                    *right,
+                    span.clone(),
+                )?;
+
+                enforce_rem(cs, resolved_left, resolved_right, span)
+            }
+
+            // Bitwise operations
+            expression @ Expression::BitAnd(..)
+            | expression @ Expression::BitOr(..)
+            | expression @ Expression::BitXor(..)
+            | expression @ Expression::Shl(..)
+            | expression @ Expression::Shr(..) => {
+                self.enforce_bitwise_expression(cs, file_scope, function_scope, expected_type, expression)
+            }
+
+            // Casts
+            Expression::Cast(expression, type_, span) => {
+                let resolved_value = self.enforce_expression(cs, file_scope, function_scope, None, *expression)?;
+
+                enforce_cast(cs, resolved_value, type_, span)
+            }
 
             // Boolean operations
             Expression::Not(expression, span) => Ok(evaluate_not(
@@ -16,7 +16,14 @@
 
 //! Enforce a function call expression in a compiled Leo program.
 
-use crate::{errors::ExpressionError, new_scope, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use crate::{
+    errors::ExpressionError,
+    expression::function::group_function::*,
+    new_scope,
+    program::ConstrainedProgram,
+    value::ConstrainedValue,
+    GroupType,
+};
 use leo_typed::{Expression, Span, Type};
 
 use snarkos_models::{
@@ -43,8 +50,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 let circuit_id_string = format!("{}", circuit_identifier);
                 let declared_circuit_reference = new_scope(function_scope.clone(), circuit_id_string);
 
-                (
-                    declared_circuit_reference,
+                let is_self = match *circuit_identifier {
+                    Expression::Identifier(ref identifier) => identifier.is_self(),
+                    _ => false,
+                };
+
+                let function_value = if is_group_accessor(&circuit_member) && !is_self {
+                    // Group values have coordinate accessors instead of circuit members.
+                    match self.enforce_operand(
+                        cs,
+                        file_scope.clone(),
+                        function_scope.clone(),
+                        expected_type,
+                        *circuit_identifier,
+                        span.clone(),
+                    )? {
+                        ConstrainedValue::Group(group) => {
+                            return enforce_group_member_call(group, circuit_member, arguments, span);
+                        }
+                        value => self.access_circuit_member(file_scope.clone(), value, circuit_member, span)?,
+                    }
+                } else {
                     self.enforce_circuit_access(
                         cs,
                         file_scope.clone(),
@@ -53,8 +79,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         circuit_identifier,
                         circuit_member,
                         span,
-                    )?,
-                )
+                    )?
+                };
+
+                (declared_circuit_reference, function_value)
+            }
+            Expression::CircuitStaticFunctionAccess(ref circuit, ref member, ref span) if is_group_keyword(circuit) => {
+                return enforce_group_static_call(member.clone(), arguments, span.clone());
             }
             function => (
                 function_scope.clone(),
@@ -62,11 +93,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             ),
         };
 
+        let function_name = function_value.function_name();
         let (outer_scope, function_call) = function_value.extract_function(file_scope.clone(), span.clone())?;
 
+        // Circuit functions are qualified by their circuit so their constraints can be attributed to it.
         let name_unique = format!(
             "function call {} {}:{}",
-            function_call.get_name(),
+            function_name.unwrap_or_else(|| function_call.get_name()),
             span.line,
             span.start,
         );
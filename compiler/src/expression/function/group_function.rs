@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces the built-in functions of the `group` type in a compiled Leo program.
+
+use crate::{
+    errors::{ExpressionError, FunctionError},
+    value::ConstrainedValue,
+    GroupType,
+};
+use leo_typed::{Expression, Identifier, Span};
+
+use snarkos_models::curves::{Field, PrimeField};
+
+pub static GROUP_KEYWORD: &str = "group";
+
+/// Returns `true` if the expression is the `group` keyword in `group::generator()`.
+pub fn is_group_keyword(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(identifier) => identifier.name == GROUP_KEYWORD,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the member is a coordinate accessor of a group value.
+pub fn is_group_accessor(member: &Identifier) -> bool {
+    member.name == "to_x_coordinate" || member.name == "to_y_coordinate"
+}
+
+/// Enforces a static `group::` function call.
+pub fn enforce_group_static_call<F: Field + PrimeField, G: GroupType<F>>(
+    member: Identifier,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    match member.name.as_str() {
+        // The generator is the `one` element used to construct group values.
+        "generator" => {
+            expect_no_arguments(&arguments, span)?;
+
+            Ok(ConstrainedValue::Group(G::one()))
+        }
+        _ => Err(ExpressionError::undefined_static_access(
+            GROUP_KEYWORD.to_string(),
+            member.to_string(),
+            span,
+        )),
+    }
+}
+
+/// Enforces a method call on a group value.
+pub fn enforce_group_member_call<F: Field + PrimeField, G: GroupType<F>>(
+    group: G,
+    member: Identifier,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    let coordinate = match member.name.as_str() {
+        "to_x_coordinate" => group.to_x_coordinate(),
+        "to_y_coordinate" => group.to_y_coordinate(),
+        _ => {
+            return Err(ExpressionError::undefined_member_access(
+                GROUP_KEYWORD.to_string(),
+                member.to_string(),
+                span,
+            ));
+        }
+    };
+
+    expect_no_arguments(&arguments, span)?;
+
+    Ok(ConstrainedValue::Field(coordinate))
+}
+
+fn expect_no_arguments(arguments: &[Expression], span: Span) -> Result<(), ExpressionError> {
+    if arguments.is_empty() {
+        Ok(())
+    } else {
+        Err(ExpressionError::from(Box::new(FunctionError::arguments_length(
+            0,
+            arguments.len(),
+            span,
+        ))))
+    }
+}
@@ -21,3 +21,6 @@ pub use self::core_circuit::*;
 
 pub mod function;
 pub use self::function::*;
+
+pub mod group_function;
+pub use self::group_function::*;
@@ -25,6 +25,12 @@ pub use self::array::*;
 pub mod binary;
 pub use self::binary::*;
 
+pub mod bitwise;
+pub use self::bitwise::*;
+
+pub mod cast;
+pub use self::cast::*;
+
 pub mod circuit;
 pub use self::circuit::*;
 
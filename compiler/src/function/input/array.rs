@@ -31,6 +31,7 @@ use snarkos_models::{
 };
 
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    #[allow(clippy::too_many_arguments)]
     pub fn allocate_array<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
@@ -38,6 +39,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         array_type: Type,
         array_dimensions: Vec<usize>,
         input_value: Option<InputValue>,
+        public: bool,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, FunctionError> {
         let expected_length = array_dimensions[0];
@@ -55,6 +57,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         value_type,
                         value_name,
                         Some(value),
+                        public,
                         span.clone(),
                     )?)
                 }
@@ -70,6 +73,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         value_type,
                         value_name,
                         None,
+                        public,
                         span.clone(),
                     )?);
                 }
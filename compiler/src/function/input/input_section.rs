@@ -41,6 +41,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 parameter.type_,
                 parameter.variable.name,
                 option,
+                false,
                 parameter.span,
             )?;
             let member = ConstrainedCircuitMember(member_name, member_value);
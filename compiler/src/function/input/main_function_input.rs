@@ -44,22 +44,26 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         type_: Type,
         name: String,
         input_option: Option<InputValue>,
+        public: bool,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, FunctionError> {
         match type_ {
-            Type::Address => Ok(Address::from_input(cs, name, input_option, span)?),
-            Type::Boolean => Ok(bool_from_input(cs, name, input_option, span)?),
-            Type::Field => Ok(field_from_input(cs, name, input_option, span)?),
-            Type::Group => Ok(group_from_input(cs, name, input_option, span)?),
+            Type::Address => Ok(Address::from_input(cs, name, input_option, public, span)?),
+            Type::Boolean => Ok(bool_from_input(cs, name, input_option, public, span)?),
+            Type::Field => Ok(field_from_input(cs, name, input_option, public, span)?),
+            Type::Group => Ok(group_from_input(cs, name, input_option, public, span)?),
             Type::IntegerType(integer_type) => Ok(ConstrainedValue::Integer(Integer::from_input(
                 cs,
                 integer_type,
                 name,
                 input_option,
+                public,
                 span,
             )?)),
-            Type::Array(type_, dimensions) => self.allocate_array(cs, name, *type_, dimensions, input_option, span),
-            Type::Tuple(types) => self.allocate_tuple(cs, name, types, input_option, span),
+            Type::Array(type_, dimensions) => {
+                self.allocate_array(cs, name, *type_, dimensions, input_option, public, span)
+            }
+            Type::Tuple(types) => self.allocate_tuple(cs, name, types, input_option, public, span),
             _ => unimplemented!("main function input not implemented for type"),
         }
     }
@@ -37,6 +37,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         name: String,
         types: Vec<Type>,
         input_value: Option<InputValue>,
+        public: bool,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, FunctionError> {
         let mut tuple_values = vec![];
@@ -52,6 +53,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         type_,
                         value_name,
                         Some(value),
+                        public,
                         span.clone(),
                     )?)
                 }
@@ -61,7 +63,14 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 for (i, type_) in types.into_iter().enumerate() {
                     let value_name = new_scope(name.clone(), i.to_string());
 
-                    tuple_values.push(self.allocate_main_function_input(cs, type_, value_name, None, span.clone())?);
+                    tuple_values.push(self.allocate_main_function_input(
+                        cs,
+                        type_,
+                        value_name,
+                        None,
+                        public,
+                        span.clone(),
+                    )?);
                 }
             }
             _ => return Err(FunctionError::invalid_tuple(input_value.unwrap().to_string(), span)),
@@ -52,6 +52,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 }
                 InputVariable::FunctionInput(input_model) => {
                     let name = input_model.identifier.name.clone();
+                    let public = input.is_public(&name);
                     let input_option = input
                         .get(&name)
                         .ok_or(FunctionError::input_not_found(name.clone(), function.span.clone()))?;
@@ -60,6 +61,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         input_model.type_,
                         name.clone(),
                         input_option,
+                        public,
                         function.span.clone(),
                     )?;
 
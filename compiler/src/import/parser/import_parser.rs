@@ -17,13 +17,14 @@
 use crate::errors::ImportError;
 use leo_typed::{Package, Program};
 
-use std::{collections::HashMap, env::current_dir};
+use std::{collections::HashMap, env::current_dir, path::PathBuf};
 
 /// Parses all relevant import files for a program.
 /// Stores compiled program structs.
 #[derive(Clone)]
 pub struct ImportParser {
     imports: HashMap<String, Program>,
+    import_paths: HashMap<String, PathBuf>,
     core_packages: Vec<Package>,
 }
 
@@ -31,12 +32,14 @@ impl ImportParser {
     pub fn new() -> Self {
         Self {
             imports: HashMap::new(),
+            import_paths: HashMap::new(),
             core_packages: vec![],
         }
     }
 
-    pub(crate) fn insert_import(&mut self, file_name: String, program: Program) {
+    pub(crate) fn insert_import(&mut self, file_name: String, program: Program, file_path: PathBuf) {
         // todo: handle conflicting versions for duplicate imports here
+        let _res = self.import_paths.insert(file_name.clone(), file_path);
         let _res = self.imports.insert(file_name, program);
     }
 
@@ -48,6 +51,15 @@ impl ImportParser {
         self.imports.get(file_name)
     }
 
+    /// Returns the path of the file the import was parsed from.
+    pub fn get_import_path(&self, file_name: &String) -> Option<&PathBuf> {
+        self.import_paths.get(file_name)
+    }
+
+    pub fn imports(&self) -> &HashMap<String, Program> {
+        &self.imports
+    }
+
     pub fn core_packages(&self) -> &Vec<Package> {
         &self.core_packages
     }
@@ -23,7 +23,7 @@ use std::{ffi::OsString, fs::DirEntry, path::PathBuf};
 static LIBRARY_FILE: &str = "src/lib.leo";
 static FILE_EXTENSION: &str = "leo";
 
-fn parse_import_file(entry: &DirEntry, span: &Span) -> Result<Program, ImportError> {
+fn parse_import_file(entry: &DirEntry, span: &Span) -> Result<(Program, PathBuf), ImportError> {
     // make sure the given entry is file
     let file_type = entry
         .file_type()
@@ -51,7 +51,7 @@ fn parse_import_file(entry: &DirEntry, span: &Span) -> Result<Program, ImportErr
     let ast = &LeoAst::new(&file_path, &program_string)?;
 
     // Generates the Leo program from file.
-    Ok(Program::from(&file_name, ast.as_repr()))
+    Ok((Program::from(&file_name, ast.as_repr()), file_path))
 }
 
 impl ImportParser {
@@ -70,7 +70,7 @@ impl ImportParser {
         // import * can only be invoked on a package with a library file or a leo file
         if is_package || is_leo_file {
             // Generate aleo program from file
-            let program = parse_import_file(entry, &span)?;
+            let (program, file_path) = parse_import_file(entry, &span)?;
 
             // Store program's imports in imports hashmap
             program
@@ -88,7 +88,7 @@ impl ImportParser {
                 .into_string()
                 .unwrap(); // the file exists so these will not fail
 
-            self.insert_import(file_name, program);
+            self.insert_import(file_name, program, file_path);
 
             Ok(())
         } else {
@@ -99,7 +99,7 @@ impl ImportParser {
 
     pub fn parse_import_symbol(&mut self, entry: &DirEntry, symbol: &ImportSymbol) -> Result<(), ImportError> {
         // Generate aleo program from file
-        let program = parse_import_file(entry, &symbol.span)?;
+        let (program, file_path) = parse_import_file(entry, &symbol.span)?;
 
         // Store program's imports in imports hashmap
         program
@@ -117,7 +117,7 @@ impl ImportParser {
             .into_string()
             .unwrap(); // the file exists so these will not fail
 
-        self.insert_import(file_name, program);
+        self.insert_import(file_name, program, file_path);
 
         Ok(())
     }
@@ -43,6 +43,9 @@ pub use self::import::*;
 pub mod output;
 pub use self::output::*;
 
+pub mod profiler;
+pub use self::profiler::*;
+
 pub mod program;
 pub use self::program::*;
 
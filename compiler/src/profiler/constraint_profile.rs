@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Constraint counts attributed to the functions and source lines of a program.
+
+use std::{collections::HashMap, fmt::Write, ops::AddAssign};
+
+/// The name of the outermost frame of every call stack.
+pub const MAIN_FRAME_NAME: &str = "main";
+
+/// The number of constraints and private variables synthesized at a location.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintCost {
+    pub constraints: usize,
+    pub aux_variables: usize,
+}
+
+impl AddAssign for ConstraintCost {
+    fn add_assign(&mut self, other: Self) {
+        self.constraints += other.constraints;
+        self.aux_variables += other.aux_variables;
+    }
+}
+
+/// A function call stack and the source line in its innermost function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProfileLocation {
+    /// The called functions from `main` inwards, circuit functions as `Circuit::function`.
+    pub stack: Vec<String>,
+    /// The line in the innermost function, if the constraint came from a statement or expression.
+    pub line: Option<usize>,
+}
+
+impl ProfileLocation {
+    /// Returns the name of the innermost function.
+    pub fn function(&self) -> &str {
+        self.stack.last().map(String::as_str).unwrap_or(MAIN_FRAME_NAME)
+    }
+}
+
+/// The constraint costs of a synthesized program.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintProfile {
+    pub locations: HashMap<ProfileLocation, ConstraintCost>,
+}
+
+impl ConstraintProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total cost of the program.
+    pub fn total(&self) -> ConstraintCost {
+        let mut total = ConstraintCost::default();
+        for cost in self.locations.values() {
+            total += *cost;
+        }
+
+        total
+    }
+
+    /// Returns the cost of each function excluding the functions it calls, most expensive first.
+    pub fn functions(&self) -> Vec<(String, ConstraintCost)> {
+        let mut functions = HashMap::<String, ConstraintCost>::new();
+        for (location, cost) in self.locations.iter() {
+            *functions.entry(location.function().to_string()).or_default() += *cost;
+        }
+
+        sorted(functions.into_iter().collect())
+    }
+
+    /// Returns the cost of each line of each function, most expensive first.
+    pub fn lines(&self) -> Vec<((String, Option<usize>), ConstraintCost)> {
+        let mut lines = HashMap::<(String, Option<usize>), ConstraintCost>::new();
+        for (location, cost) in self.locations.iter() {
+            *lines
+                .entry((location.function().to_string(), location.line))
+                .or_default() += *cost;
+        }
+
+        sorted(lines.into_iter().collect())
+    }
+
+    /// Returns the profile in the folded stack format read by flamegraph tools.
+    ///
+    /// Each line is a `;` separated call stack ending in the source line, followed by its constraints.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut stacks = self
+            .locations
+            .iter()
+            .filter(|(_, cost)| cost.constraints > 0)
+            .map(|(location, cost)| {
+                let mut frames = location.stack.clone();
+                if let Some(line) = location.line {
+                    frames.push(format!("line {}", line));
+                }
+
+                (frames.join(";"), cost.constraints)
+            })
+            .collect::<Vec<_>>();
+        stacks.sort();
+
+        let mut folded = String::new();
+        for (stack, constraints) in stacks {
+            writeln!(folded, "{} {}", stack, constraints).unwrap();
+        }
+
+        folded
+    }
+
+    /// Returns the per-function and per-line costs as printable tables.
+    pub fn to_table(&self) -> String {
+        let total = self.total();
+        let mut table = String::new();
+
+        writeln!(table, "{:>12} {:>12}  Function", "Constraints", "Variables").unwrap();
+        for (function, cost) in self.functions() {
+            writeln!(
+                table,
+                "{:>12} {:>12}  {}",
+                cost.constraints, cost.aux_variables, function
+            )
+            .unwrap();
+        }
+        writeln!(table).unwrap();
+
+        writeln!(table, "{:>12} {:>12}  Line", "Constraints", "Variables").unwrap();
+        for ((function, line), cost) in self.lines() {
+            let line = line.map(|line| line.to_string()).unwrap_or_else(|| "-".to_string());
+            writeln!(
+                table,
+                "{:>12} {:>12}  {}:{}",
+                cost.constraints, cost.aux_variables, function, line
+            )
+            .unwrap();
+        }
+        writeln!(table).unwrap();
+
+        write!(table, "{:>12} {:>12}  Total", total.constraints, total.aux_variables).unwrap();
+
+        table
+    }
+}
+
+/// Sorts by constraints, then variables, most expensive first and by key among equal costs.
+fn sorted<K: Ord>(mut entries: Vec<(K, ConstraintCost)>) -> Vec<(K, ConstraintCost)> {
+    entries.sort_by(|(a_key, a), (b_key, b)| {
+        (b.constraints, b.aux_variables)
+            .cmp(&(a.constraints, a.aux_variables))
+            .then_with(|| a_key.cmp(b_key))
+    });
+
+    entries
+}
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A constraint system that attributes constraints to the namespaces they are synthesized in.
+
+use crate::{ConstraintCost, ConstraintProfile, ProfileLocation, MAIN_FRAME_NAME};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::Field,
+    gadgets::r1cs::{ConstraintSystem, LinearCombination, Variable},
+};
+
+use std::marker::PhantomData;
+
+const FUNCTION_CALL_PREFIX: &str = "function call ";
+
+/// What a namespace pushed by the compiler tells about the source of its constraints.
+#[derive(Clone, Debug)]
+enum Frame {
+    /// A call to the named function.
+    Function(String),
+    /// A statement or expression at the given line of the current function.
+    Line(usize),
+    /// A namespace pushed by a gadget.
+    Other,
+}
+
+impl Frame {
+    /// Parses a namespace name ending in the `{line}:{start}` of its span.
+    fn parse(name: &str) -> Self {
+        let (rest, position) = match name.rfind(' ') {
+            Some(index) => (&name[..index], &name[index + 1..]),
+            None => return Frame::Other,
+        };
+        let line = match position.split(':').collect::<Vec<_>>().as_slice() {
+            [line, start] if start.parse::<usize>().is_ok() => line.parse::<usize>().ok(),
+            _ => None,
+        };
+
+        match line {
+            Some(_) if rest.starts_with(FUNCTION_CALL_PREFIX) => {
+                Frame::Function(rest[FUNCTION_CALL_PREFIX.len()..].to_string())
+            }
+            Some(line) => Frame::Line(line),
+            None => Frame::Other,
+        }
+    }
+}
+
+/// Wraps a constraint system and records the cost of every function and line of the program.
+///
+/// Constraints are attributed to the innermost function call namespace and the innermost
+/// namespace within it that names a source position.
+pub struct ProfilingConstraintSystem<F: Field, CS: ConstraintSystem<F>> {
+    inner: CS,
+    frames: Vec<Frame>,
+    location: Option<ProfileLocation>,
+    profile: ConstraintProfile,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ProfilingConstraintSystem<F, CS> {
+    pub fn new(inner: CS) -> Self {
+        Self {
+            inner,
+            frames: vec![],
+            location: None,
+            profile: ConstraintProfile::new(),
+            _field: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped constraint system and the recorded profile.
+    pub fn into_parts(self) -> (CS, ConstraintProfile) {
+        (self.inner, self.profile)
+    }
+
+    pub fn profile(&self) -> &ConstraintProfile {
+        &self.profile
+    }
+
+    fn record(&mut self, cost: ConstraintCost) {
+        if self.location.is_none() {
+            self.location = Some(self.current_location());
+        }
+
+        let location = self.location.clone().unwrap();
+        *self.profile.locations.entry(location).or_default() += cost;
+    }
+
+    fn current_location(&self) -> ProfileLocation {
+        let mut stack = vec![MAIN_FRAME_NAME.to_string()];
+        let mut line = None;
+
+        for frame in self.frames.iter() {
+            match frame {
+                Frame::Function(function) => {
+                    stack.push(function.clone());
+                    line = None;
+                }
+                Frame::Line(frame_line) => line = Some(*frame_line),
+                Frame::Other => {}
+            }
+        }
+
+        ProfileLocation { stack, line }
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for ProfilingConstraintSystem<F, CS> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.record(ConstraintCost {
+            constraints: 0,
+            aux_variables: 1,
+        });
+
+        self.inner.alloc(annotation, f)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.inner.alloc_input(annotation, f)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.record(ConstraintCost {
+            constraints: 1,
+            aux_variables: 0,
+        });
+
+        self.inner.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn().into();
+        let frame = Frame::parse(&name);
+
+        // Gadget namespaces do not move the location.
+        if let Frame::Function(_) | Frame::Line(_) = frame {
+            self.location = None;
+        }
+
+        self.frames.push(frame);
+        self.inner.push_namespace(|| name);
+    }
+
+    fn pop_namespace(&mut self) {
+        if let Some(Frame::Function(_)) | Some(Frame::Line(_)) = self.frames.pop() {
+            self.location = None;
+        }
+
+        self.inner.pop_namespace();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.inner.num_constraints()
+    }
+}
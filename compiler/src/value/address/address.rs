@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::AddressError, ConstrainedValue, GroupType};
+use crate::{errors::AddressError, value::allocate::alloc_gadget, ConstrainedValue, GroupType};
 use leo_typed::{InputValue, Span};
 
 use snarkos_dpc::base_dpc::instantiated::Components;
@@ -72,6 +72,7 @@ impl Address {
         cs: &mut CS,
         name: String,
         input_value: Option<InputValue>,
+        public: bool,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, AddressError> {
         // Check that the input value is the correct type
@@ -89,10 +90,8 @@ impl Address {
         let address_name = format!("{}: address", name);
         let address_namespace = format!("`{}` {}:{}", address_name, span.line, span.start);
 
-        let address = Address::alloc(cs.ns(|| address_namespace), || {
-            address_value.ok_or(SynthesisError::AssignmentMissing)
-        })
-        .map_err(|_| AddressError::missing_address(span))?;
+        let address = alloc_gadget(cs.ns(|| address_namespace), address_value, public)
+            .map_err(|_| AddressError::missing_address(span))?;
 
         Ok(ConstrainedValue::Address(address))
     }
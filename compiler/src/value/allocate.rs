@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Allocates values as private or public variables of the constraint system.
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::Field,
+    gadgets::{r1cs::ConstraintSystem, utilities::alloc::AllocGadget},
+};
+
+/// Allocates a public input variable if `public` is `true`, or a private variable otherwise.
+pub(crate) fn alloc_gadget<V, T: AllocGadget<V, F>, F: Field, CS: ConstraintSystem<F>>(
+    cs: CS,
+    option: Option<V>,
+    public: bool,
+) -> Result<T, SynthesisError> {
+    let value_gen = || option.ok_or(SynthesisError::AssignmentMissing);
+
+    if public {
+        T::alloc_input(cs, value_gen)
+    } else {
+        T::alloc(cs, value_gen)
+    }
+}
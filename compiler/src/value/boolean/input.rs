@@ -16,16 +16,16 @@
 
 //! Methods to enforce constraints on input boolean values in a resolved Leo program.
 
-use crate::{errors::BooleanError, value::ConstrainedValue, GroupType};
+use crate::{
+    errors::BooleanError,
+    value::{allocate::alloc_gadget, ConstrainedValue},
+    GroupType,
+};
 use leo_typed::{InputValue, Span};
 
-use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
     curves::{Field, PrimeField},
-    gadgets::{
-        r1cs::ConstraintSystem,
-        utilities::{alloc::AllocGadget, boolean::Boolean},
-    },
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
 };
 
 pub(crate) fn new_bool_constant(string: String, span: Span) -> Result<Boolean, BooleanError> {
@@ -40,21 +40,21 @@ pub(crate) fn allocate_bool<F: Field + PrimeField, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: String,
     option: Option<bool>,
+    public: bool,
     span: Span,
 ) -> Result<Boolean, BooleanError> {
     let boolean_name = format!("{}: bool", name);
     let boolean_name_unique = format!("`{}` {}:{}", boolean_name, span.line, span.start);
 
-    Boolean::alloc(cs.ns(|| boolean_name_unique), || {
-        option.ok_or(SynthesisError::AssignmentMissing)
-    })
-    .map_err(|_| BooleanError::missing_boolean(boolean_name, span))
+    alloc_gadget(cs.ns(|| boolean_name_unique), option, public)
+        .map_err(|_| BooleanError::missing_boolean(boolean_name, span))
 }
 
 pub(crate) fn bool_from_input<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: String,
     input_value: Option<InputValue>,
+    public: bool,
     span: Span,
 ) -> Result<ConstrainedValue<F, G>, BooleanError> {
     // Check that the input value is the correct type
@@ -69,7 +69,7 @@ pub(crate) fn bool_from_input<F: Field + PrimeField, G: GroupType<F>, CS: Constr
         None => None,
     };
 
-    let number = allocate_bool(cs, name, option, span)?;
+    let number = allocate_bool(cs, name, option, public, span)?;
 
     Ok(ConstrainedValue::Boolean(number))
 }
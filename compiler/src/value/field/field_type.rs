@@ -37,6 +37,8 @@ use snarkos_models::{
     },
 };
 
+use snarkos_utilities::BitIterator;
+
 use std::{borrow::Borrow, cmp::Ordering};
 
 #[derive(Clone, Debug)]
@@ -160,6 +162,26 @@ impl<F: Field + PrimeField> FieldType<F> {
         self.mul(cs, &inverse, span)
     }
 
+    /// Returns the little-endian bits of the field element, enforcing that they are canonical.
+    pub fn to_bits_le<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        match self {
+            FieldType::Constant(value) => {
+                let mut bits = BitIterator::new(value.into_repr())
+                    .map(Boolean::constant)
+                    .collect::<Vec<_>>();
+                bits.reverse();
+
+                Ok(bits)
+            }
+            FieldType::Allocated(gadget) => {
+                let mut bits = gadget.to_bits_strict(cs)?;
+                bits.reverse();
+
+                Ok(bits)
+            }
+        }
+    }
+
     pub fn alloc_helper<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<String>>(
         value_gen: Fn,
     ) -> Result<F, SynthesisError> {
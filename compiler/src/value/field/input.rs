@@ -16,34 +16,37 @@
 
 //! Methods to enforce constraints on input field values in a compiled Leo program.
 
-use crate::{errors::FieldError, value::ConstrainedValue, FieldType, GroupType};
+use crate::{
+    errors::FieldError,
+    value::{allocate::alloc_gadget, ConstrainedValue},
+    FieldType,
+    GroupType,
+};
 use leo_typed::{InputValue, Span};
 
-use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
     curves::{Field, PrimeField},
-    gadgets::{r1cs::ConstraintSystem, utilities::alloc::AllocGadget},
+    gadgets::r1cs::ConstraintSystem,
 };
 
 pub(crate) fn allocate_field<F: Field + PrimeField, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: String,
     option: Option<String>,
+    public: bool,
     span: Span,
 ) -> Result<FieldType<F>, FieldError> {
     let field_name = format!("{}: field", name);
     let field_name_unique = format!("`{}` {}:{}", field_name, span.line, span.start);
 
-    FieldType::alloc(cs.ns(|| field_name_unique), || {
-        option.ok_or(SynthesisError::AssignmentMissing)
-    })
-    .map_err(|_| FieldError::missing_field(field_name, span))
+    alloc_gadget(cs.ns(|| field_name_unique), option, public).map_err(|_| FieldError::missing_field(field_name, span))
 }
 
 pub(crate) fn field_from_input<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: String,
     input_value: Option<InputValue>,
+    public: bool,
     span: Span,
 ) -> Result<ConstrainedValue<F, G>, FieldError> {
     // Check that the parameter value is the correct type
@@ -58,7 +61,7 @@ pub(crate) fn field_from_input<F: Field + PrimeField, G: GroupType<F>, CS: Const
         None => None,
     };
 
-    let field = allocate_field(cs, name, option, span)?;
+    let field = allocate_field(cs, name, option, public, span)?;
 
     Ok(ConstrainedValue::Field(field))
 }
@@ -16,15 +16,16 @@
 
 //! A data type that represents members in the group formed by the set of affine points on a curve.
 
-use crate::errors::GroupError;
+use crate::{errors::GroupError, FieldType};
 use leo_typed::{GroupValue, Span};
 
 use snarkos_models::{
-    curves::{Field, One},
+    curves::{Field, One, PrimeField},
     gadgets::{
         r1cs::ConstraintSystem,
         utilities::{
             alloc::AllocGadget,
+            boolean::Boolean,
             eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
             select::CondSelectGadget,
             ToBitsGadget,
@@ -34,7 +35,7 @@ use snarkos_models::{
 };
 use std::fmt::{Debug, Display};
 
-pub trait GroupType<F: Field>:
+pub trait GroupType<F: Field + PrimeField>:
     Sized
     + Clone
     + Debug
@@ -57,4 +58,11 @@ pub trait GroupType<F: Field>:
     fn add<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: Span) -> Result<Self, GroupError>;
 
     fn sub<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: Span) -> Result<Self, GroupError>;
+
+    /// Multiplies the group element by a scalar given as little-endian bits.
+    fn mul<CS: ConstraintSystem<F>>(&self, cs: CS, scalar: &[Boolean], span: Span) -> Result<Self, GroupError>;
+
+    fn to_x_coordinate(&self) -> FieldType<F>;
+
+    fn to_y_coordinate(&self) -> FieldType<F>;
 }
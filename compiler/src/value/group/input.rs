@@ -16,10 +16,9 @@
 
 //! Methods to enforce constraints on input group values in a Leo program.
 
-use crate::{errors::GroupError, ConstrainedValue, GroupType};
+use crate::{errors::GroupError, value::allocate::alloc_gadget, ConstrainedValue, GroupType};
 use leo_typed::{GroupValue, InputValue, Span};
 
-use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::r1cs::ConstraintSystem,
@@ -29,21 +28,20 @@ pub(crate) fn allocate_group<F: Field + PrimeField, G: GroupType<F>, CS: Constra
     cs: &mut CS,
     name: String,
     option: Option<GroupValue>,
+    public: bool,
     span: Span,
 ) -> Result<G, GroupError> {
     let group_name = format!("{}: group", name);
     let group_name_unique = format!("`{}` {}:{}", group_name, span.line, span.start);
 
-    G::alloc(cs.ns(|| group_name_unique), || {
-        option.ok_or(SynthesisError::AssignmentMissing)
-    })
-    .map_err(|_| GroupError::missing_group(group_name, span))
+    alloc_gadget(cs.ns(|| group_name_unique), option, public).map_err(|_| GroupError::missing_group(group_name, span))
 }
 
 pub(crate) fn group_from_input<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: String,
     input_value: Option<InputValue>,
+    public: bool,
     span: Span,
 ) -> Result<ConstrainedValue<F, G>, GroupError> {
     // Check that the parameter value is the correct type
@@ -58,7 +56,7 @@ pub(crate) fn group_from_input<F: Field + PrimeField, G: GroupType<F>, CS: Const
         None => None,
     };
 
-    let group = allocate_group(cs, name, option, span)?;
+    let group = allocate_group(cs, name, option, public, span)?;
 
     Ok(ConstrainedValue::Group(group))
 }
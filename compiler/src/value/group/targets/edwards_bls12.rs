@@ -14,12 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::GroupError, GroupType};
+use crate::{errors::GroupError, FieldType, GroupType};
 use leo_typed::{GroupCoordinate, GroupTuple, GroupValue, Span};
 
 use snarkos_curves::{
     edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq},
-    templates::twisted_edwards_extended::GroupAffine,
+    templates::twisted_edwards_extended::{GroupAffine, GroupProjective},
 };
 use snarkos_errors::gadgets::SynthesisError;
 use snarkos_gadgets::curves::edwards_bls12::EdwardsBlsGadget;
@@ -131,6 +131,80 @@ impl GroupType<Fq> for EdwardsGroupType {
             }
         }
     }
+
+    fn mul<CS: ConstraintSystem<Fq>>(&self, mut cs: CS, scalar: &[Boolean], span: Span) -> Result<Self, GroupError> {
+        let constant_scalar = scalar.iter().all(|bit| matches!(bit, Boolean::Constant(_)));
+
+        match self {
+            EdwardsGroupType::Constant(group) if constant_scalar => {
+                let mut result = EdwardsAffine::zero();
+                let mut base = *group;
+
+                for bit in scalar {
+                    if bit.get_value() == Some(true) {
+                        result = result.add(&base);
+                    }
+                    base = base.add(&base);
+                }
+
+                Ok(EdwardsGroupType::Constant(result))
+            }
+            EdwardsGroupType::Constant(group) => {
+                // Add the constant powers of two of the base for each scalar bit.
+                let mut powers = Vec::with_capacity(scalar.len());
+                let mut base = *group;
+
+                for _ in scalar {
+                    powers.push(base.into_projective());
+                    base = base.add(&base);
+                }
+
+                let mut result = <EdwardsBlsGadget as GroupGadget<GroupAffine<EdwardsParameters>, Fq>>::zero(
+                    cs.ns(|| format!("zero {}:{}", span.line, span.start)),
+                )
+                .map_err(|e| GroupError::binary_operation(format!("*"), e, span.clone()))?;
+
+                <EdwardsBlsGadget as GroupGadget<GroupProjective<EdwardsParameters>, Fq>>::precomputed_base_scalar_mul(
+                    &mut result,
+                    cs.ns(|| format!("scalar multiplication {}:{}", span.line, span.start)),
+                    scalar.iter().zip(powers.iter()),
+                )
+                .map_err(|e| GroupError::binary_operation(format!("*"), e, span))?;
+
+                Ok(EdwardsGroupType::Allocated(result))
+            }
+            EdwardsGroupType::Allocated(group) => {
+                let zero = <EdwardsBlsGadget as GroupGadget<GroupAffine<EdwardsParameters>, Fq>>::zero(
+                    cs.ns(|| format!("zero {}:{}", span.line, span.start)),
+                )
+                .map_err(|e| GroupError::binary_operation(format!("*"), e, span.clone()))?;
+
+                let result = <EdwardsBlsGadget as GroupGadget<GroupAffine<EdwardsParameters>, Fq>>::mul_bits(
+                    group,
+                    cs.ns(|| format!("scalar multiplication {}:{}", span.line, span.start)),
+                    &zero,
+                    scalar.iter(),
+                )
+                .map_err(|e| GroupError::binary_operation(format!("*"), e, span))?;
+
+                Ok(EdwardsGroupType::Allocated(result))
+            }
+        }
+    }
+
+    fn to_x_coordinate(&self) -> FieldType<Fq> {
+        match self {
+            EdwardsGroupType::Constant(group) => FieldType::Constant(group.x),
+            EdwardsGroupType::Allocated(group) => FieldType::Allocated(group.x.clone()),
+        }
+    }
+
+    fn to_y_coordinate(&self) -> FieldType<Fq> {
+        match self {
+            EdwardsGroupType::Constant(group) => FieldType::Constant(group.y),
+            EdwardsGroupType::Allocated(group) => FieldType::Allocated(group.y.clone()),
+        }
+    }
 }
 
 impl EdwardsGroupType {
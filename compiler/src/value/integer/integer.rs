@@ -15,10 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 //! Conversion of integer declarations to constraints in Leo.
-use crate::{errors::IntegerError, IntegerTrait};
+use crate::{errors::IntegerError, value::allocate::alloc_gadget, IntegerTrait};
 use leo_gadgets::{
     arithmetic::*,
     bits::comparator::{ComparatorGadget, EvaluateLtGadget},
+    bitwise::*,
     signed_integer::*,
 };
 use leo_typed::{InputValue, IntegerType, Span};
@@ -29,7 +30,6 @@ use snarkos_models::{
     gadgets::{
         r1cs::ConstraintSystem,
         utilities::{
-            alloc::AllocGadget,
             boolean::Boolean,
             eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
             select::CondSelectGadget,
@@ -148,6 +148,62 @@ impl Integer {
         match_integer!(integer => integer.get_bits())
     }
 
+    /// Returns an integer of the given type from little-endian bits of the same length.
+    pub fn from_bits(integer_type: &IntegerType, bits: Vec<Boolean>) -> Self {
+        let value = bits.iter().rev().try_fold(0u128, |value, bit| {
+            bit.get_value().map(|bit| (value << 1) | bit as u128)
+        });
+
+        match integer_type {
+            IntegerType::U8 => Integer::U8(UInt8 {
+                bits,
+                negated: false,
+                value: value.map(|value| value as u8),
+            }),
+            IntegerType::U16 => Integer::U16(UInt16 {
+                bits,
+                negated: false,
+                value: value.map(|value| value as u16),
+            }),
+            IntegerType::U32 => Integer::U32(UInt32 {
+                bits,
+                negated: false,
+                value: value.map(|value| value as u32),
+            }),
+            IntegerType::U64 => Integer::U64(UInt64 {
+                bits,
+                negated: false,
+                value: value.map(|value| value as u64),
+            }),
+            IntegerType::U128 => Integer::U128(UInt128 {
+                bits,
+                negated: false,
+                value,
+            }),
+
+            IntegerType::I8 => Integer::I8(Int8 {
+                bits,
+                value: value.map(|value| value as i8),
+            }),
+            IntegerType::I16 => Integer::I16(Int16 {
+                bits,
+                value: value.map(|value| value as i16),
+            }),
+            IntegerType::I32 => Integer::I32(Int32 {
+                bits,
+                value: value.map(|value| value as i32),
+            }),
+            IntegerType::I64 => Integer::I64(Int64 {
+                bits,
+                value: value.map(|value| value as i64),
+            }),
+            IntegerType::I128 => Integer::I128(Int128 {
+                bits,
+                value: value.map(|value| value as i128),
+            }),
+        }
+    }
+
     pub fn get_value(&self) -> Option<String> {
         let integer = self;
         match_integer!(integer => integer.get_value())
@@ -185,6 +241,7 @@ impl Integer {
         integer_type: IntegerType,
         name: String,
         option: Option<String>,
+        public: bool,
         span: Span,
     ) -> Result<Self, IntegerError> {
         Ok(match integer_type {
@@ -198,10 +255,8 @@ impl Integer {
                         .unwrap()
                 });
 
-                let u8_result = UInt8::alloc(cs.ns(|| u8_name_unique), || {
-                    u8_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(u8_name, span))?;
+                let u8_result = alloc_gadget(cs.ns(|| u8_name_unique), u8_option, public)
+                    .map_err(|_| IntegerError::missing_integer(u8_name, span))?;
 
                 Integer::U8(u8_result)
             }
@@ -213,10 +268,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let u16_result = UInt16::alloc(cs.ns(|| u16_name_unique), || {
-                    u16_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(u16_name, span))?;
+                let u16_result = alloc_gadget(cs.ns(|| u16_name_unique), u16_option, public)
+                    .map_err(|_| IntegerError::missing_integer(u16_name, span))?;
 
                 Integer::U16(u16_result)
             }
@@ -228,10 +281,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let u32_result = UInt32::alloc(cs.ns(|| u32_name_unique), || {
-                    u32_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(u32_name, span))?;
+                let u32_result = alloc_gadget(cs.ns(|| u32_name_unique), u32_option, public)
+                    .map_err(|_| IntegerError::missing_integer(u32_name, span))?;
 
                 Integer::U32(u32_result)
             }
@@ -243,10 +294,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let u64_result = UInt64::alloc(cs.ns(|| u64_name_unique), || {
-                    u64_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(u64_name, span))?;
+                let u64_result = alloc_gadget(cs.ns(|| u64_name_unique), u64_option, public)
+                    .map_err(|_| IntegerError::missing_integer(u64_name, span))?;
 
                 Integer::U64(u64_result)
             }
@@ -258,10 +307,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let u128_result = UInt128::alloc(cs.ns(|| u128_name_unique), || {
-                    u128_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(u128_name, span))?;
+                let u128_result = alloc_gadget(cs.ns(|| u128_name_unique), u128_option, public)
+                    .map_err(|_| IntegerError::missing_integer(u128_name, span))?;
 
                 Integer::U128(u128_result)
             }
@@ -274,10 +321,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let i8_result = Int8::alloc(cs.ns(|| i8_name_unique), || {
-                    i8_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(i8_name, span))?;
+                let i8_result = alloc_gadget(cs.ns(|| i8_name_unique), i8_option, public)
+                    .map_err(|_| IntegerError::missing_integer(i8_name, span))?;
 
                 Integer::I8(i8_result)
             }
@@ -289,10 +334,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let i16_result = Int16::alloc(cs.ns(|| i16_name_unique), || {
-                    i16_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(i16_name, span))?;
+                let i16_result = alloc_gadget(cs.ns(|| i16_name_unique), i16_option, public)
+                    .map_err(|_| IntegerError::missing_integer(i16_name, span))?;
 
                 Integer::I16(i16_result)
             }
@@ -304,10 +347,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let i32_result = Int32::alloc(cs.ns(|| i32_name_unique), || {
-                    i32_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(i32_name, span))?;
+                let i32_result = alloc_gadget(cs.ns(|| i32_name_unique), i32_option, public)
+                    .map_err(|_| IntegerError::missing_integer(i32_name, span))?;
 
                 Integer::I32(i32_result)
             }
@@ -319,10 +360,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let i64_result = Int64::alloc(cs.ns(|| i64_name_unique), || {
-                    i64_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(i64_name, span))?;
+                let i64_result = alloc_gadget(cs.ns(|| i64_name_unique), i64_option, public)
+                    .map_err(|_| IntegerError::missing_integer(i64_name, span))?;
 
                 Integer::I64(i64_result)
             }
@@ -334,10 +373,8 @@ impl Integer {
                         .map_err(|_| IntegerError::invalid_integer(s, span.clone()))
                         .unwrap()
                 });
-                let i128_result = Int128::alloc(cs.ns(|| i128_name_unique), || {
-                    i128_option.ok_or(SynthesisError::AssignmentMissing)
-                })
-                .map_err(|_| IntegerError::missing_integer(i128_name, span))?;
+                let i128_result = alloc_gadget(cs.ns(|| i128_name_unique), i128_option, public)
+                    .map_err(|_| IntegerError::missing_integer(i128_name, span))?;
 
                 Integer::I128(i128_result)
             }
@@ -349,6 +386,7 @@ impl Integer {
         integer_type: IntegerType,
         name: String,
         integer_value: Option<InputValue>,
+        public: bool,
         span: Span,
     ) -> Result<Self, IntegerError> {
         // Check that the input value is the correct type
@@ -363,7 +401,7 @@ impl Integer {
             None => None,
         };
 
-        Self::allocate_type(cs, integer_type, name, option, span)
+        Self::allocate_type(cs, integer_type, name, option, public, span)
     }
 
     pub fn negate<F: Field + PrimeField, CS: ConstraintSystem<F>>(
@@ -465,6 +503,108 @@ impl Integer {
 
         result.ok_or(IntegerError::binary_operation(format!("**"), span))
     }
+
+    pub fn rem<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} % {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+        let s = span.clone();
+
+        let result = match_integers_span!((a, b), s => a.rem(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or(IntegerError::binary_operation(format!("%"), span))
+    }
+
+    pub fn bit_and<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} & {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+        let s = span.clone();
+
+        let result = match_integers_span!((a, b), s => a.bit_and(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or(IntegerError::binary_operation(format!("&"), span))
+    }
+
+    pub fn bit_or<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} | {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+        let s = span.clone();
+
+        let result = match_integers_span!((a, b), s => a.bit_or(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or(IntegerError::binary_operation(format!("|"), span))
+    }
+
+    pub fn bit_xor<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} ^ {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+        let s = span.clone();
+
+        let result = match_integers_span!((a, b), s => a.bit_xor(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or(IntegerError::binary_operation(format!("^"), span))
+    }
+
+    pub fn shl<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} << {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+        let s = span.clone();
+
+        let result = match_integers_span!((a, b), s => a.shl(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or(IntegerError::binary_operation(format!("<<"), span))
+    }
+
+    pub fn shr<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} >> {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+        let s = span.clone();
+
+        let result = match_integers_span!((a, b), s => a.shr(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or(IntegerError::binary_operation(format!(">>"), span))
+    }
 }
 
 impl<F: Field + PrimeField> EvaluateEqGadget<F> for Integer {
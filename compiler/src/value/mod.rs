@@ -19,6 +19,8 @@
 pub mod address;
 pub use self::address::*;
 
+pub mod allocate;
+
 pub mod boolean;
 
 pub mod field;
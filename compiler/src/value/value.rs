@@ -217,6 +217,18 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
         }
     }
 
+    /// Returns the name of a function value, qualified by its circuit for circuit functions.
+    pub(crate) fn function_name(&self) -> Option<String> {
+        match self {
+            ConstrainedValue::Function(Some(circuit_identifier), function) => {
+                Some(format!("{}::{}", circuit_identifier, function.get_name()))
+            }
+            ConstrainedValue::Function(None, function) => Some(function.get_name()),
+            ConstrainedValue::Import(_import_scope, function) => function.function_name(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn extract_circuit(self, span: Span) -> Result<Circuit, ExpressionError> {
         match self {
             ConstrainedValue::CircuitDefinition(circuit) => Ok(circuit),
@@ -241,7 +253,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
                 let option = boolean.get_value();
                 let name = option.map(|b| b.to_string()).unwrap_or(format!("[allocated]"));
 
-                *boolean = allocate_bool(&mut cs, name, option, span)?;
+                *boolean = allocate_bool(&mut cs, name, option, false, span)?;
             }
             ConstrainedValue::Field(field) => {
                 let gadget = field
@@ -258,7 +270,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
                 let option = integer.get_value();
                 let name = option.clone().unwrap_or(format!("[allocated]"));
 
-                *integer = Integer::allocate_type(&mut cs, integer_type, name, option, span)?;
+                *integer = Integer::allocate_type(&mut cs, integer_type, name, option, false, span)?;
             }
 
             // Data type wrappers
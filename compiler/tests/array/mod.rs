@@ -17,6 +17,7 @@
 use crate::{
     assert_satisfied,
     expect_compiler_error,
+    expect_type_inference_error,
     get_output,
     parse_program,
     parse_program_with_input,
@@ -150,17 +151,15 @@ fn test_input_tuple_3x2_fail() {
 #[test]
 fn test_multi_fail_initializer() {
     let program_bytes = include_bytes!("multi_fail_initializer.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
 fn test_multi_inline_fail() {
     let program_bytes = include_bytes!("multi_fail_inline.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -174,9 +173,8 @@ fn test_multi_initializer() {
 #[test]
 fn test_multi_initializer_fail() {
     let program_bytes = include_bytes!("multi_initializer_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -190,9 +188,8 @@ fn test_nested_3x2_value() {
 #[test]
 fn test_nested_3x2_value_fail() {
     let program_bytes = include_bytes!("nested_3x2_value_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -206,9 +203,8 @@ fn test_tuple_3x2_value() {
 #[test]
 fn test_tuple_3x2_value_fail() {
     let program_bytes = include_bytes!("tuple_3x2_value_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -250,9 +246,8 @@ fn test_type_nested_value_nested_3x2() {
 #[test]
 fn test_type_nested_value_nested_3x2_fail() {
     let program_bytes = include_bytes!("type_nested_value_nested_3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -266,9 +261,8 @@ fn test_type_nested_value_nested_4x3x2() {
 #[test]
 fn test_type_nested_value_nested_4x3x2_fail() {
     let program_bytes = include_bytes!("type_nested_value_nested_4x3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -282,9 +276,8 @@ fn test_type_nested_value_tuple_3x2() {
 #[test]
 fn test_type_nested_value_tuple_3x2_fail() {
     let program_bytes = include_bytes!("type_nested_value_tuple_3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -298,9 +291,8 @@ fn test_type_nested_value_tuple_4x3x2() {
 #[test]
 fn test_type_nested_value_tuple_4x3x2_fail() {
     let program_bytes = include_bytes!("type_nested_value_tuple_4x3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -314,9 +306,8 @@ fn test_type_tuple_value_nested_3x2() {
 #[test]
 fn test_type_tuple_value_nested_3x2_fail() {
     let program_bytes = include_bytes!("type_tuple_value_nested_3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -330,9 +321,8 @@ fn test_type_tuple_value_nested_4x3x2() {
 #[test]
 fn test_type_tuple_value_nested_4x3x2_fail() {
     let program_bytes = include_bytes!("type_tuple_value_nested_4x3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -346,9 +336,8 @@ fn test_type_tuple_value_tuple_3x2() {
 #[test]
 fn test_type_tuple_value_tuple_3x2_fail() {
     let program_bytes = include_bytes!("type_tuple_value_tuple_3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -362,9 +351,8 @@ fn test_type_tuple_value_tuple_4x3x2() {
 #[test]
 fn test_type_tuple_value_tuple_4x3x2_fail() {
     let program_bytes = include_bytes!("type_tuple_value_tuple_4x3x2_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 // Tests for nested multi-dimensional arrays as input to the program
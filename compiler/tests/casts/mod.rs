@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    expect_type_inference_error,
+    generate_main_input,
+    generate_test_input_u32,
+    parse_program,
+};
+use leo_input::types::{I16Type, I32Type, I8Type, IntegerType, SignedIntegerType};
+use leo_typed::InputValue;
+
+fn signed_input(integer_type: SignedIntegerType, number: i128) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Signed(integer_type),
+        number.to_string(),
+    ))
+}
+
+#[test]
+fn test_widen() {
+    let bytes = include_bytes!("widen.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_reinterpret() {
+    let bytes = include_bytes!("reinterpret.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_narrow() {
+    let bytes = include_bytes!("narrow.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", generate_test_input_u32(255)),
+        ("b", signed_input(SignedIntegerType::I32Type(I32Type {}), -128)),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_narrow_overflow() {
+    let bytes = include_bytes!("narrow_overflow.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![("a", generate_test_input_u32(256))]);
+
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_narrow_negative() {
+    let bytes = include_bytes!("narrow_negative.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![("a", signed_input(SignedIntegerType::I16Type(I16Type {}), -1))]);
+
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_field() {
+    let bytes = include_bytes!("field.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", generate_test_input_u32(10)),
+        ("b", signed_input(SignedIntegerType::I8Type(I8Type {}), -3)),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_bool() {
+    let bytes = include_bytes!("bool.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![("a", Some(InputValue::Boolean(true)))]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_precedence() {
+    let bytes = include_bytes!("precedence.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_invalid() {
+    let bytes = include_bytes!("invalid.leo");
+
+    expect_type_inference_error(bytes);
+}
@@ -14,17 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, parse_program, EdwardsTestCompiler};
-use leo_compiler::errors::{CompilerError, ExpressionError, FunctionError, StatementError};
-
-fn expect_fail(program: EdwardsTestCompiler) {
-    match expect_compiler_error(program) {
-        CompilerError::FunctionError(FunctionError::StatementError(StatementError::ExpressionError(
-            ExpressionError::Error(_string),
-        ))) => {}
-        error => panic!("Expected invalid circuit member error, got {}", error),
-    }
-}
+use crate::{assert_satisfied, expect_compiler_error, expect_type_inference_error, parse_program};
 
 // Expressions
 
@@ -39,17 +29,15 @@ fn test_inline() {
 #[test]
 fn test_inline_fail() {
     let bytes = include_bytes!("inline_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_inline_undefined() {
     let bytes = include_bytes!("inline_undefined.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program);
+    expect_type_inference_error(bytes);
 }
 
 // Members
@@ -65,9 +53,8 @@ fn test_member_variable() {
 #[test]
 fn test_member_variable_fail() {
     let bytes = include_bytes!("member_variable_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -89,17 +76,15 @@ fn test_member_function() {
 #[test]
 fn test_member_function_fail() {
     let bytes = include_bytes!("member_function_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_member_function_invalid() {
     let bytes = include_bytes!("member_function_invalid.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -129,17 +114,15 @@ fn test_member_static_function_nested() {
 #[test]
 fn test_member_static_function_invalid() {
     let bytes = include_bytes!("member_static_function_invalid.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program)
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_member_static_function_undefined() {
     let bytes = include_bytes!("member_static_function_undefined.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_fail(program)
+    expect_type_inference_error(bytes);
 }
 
 // Mutability
@@ -147,9 +130,8 @@ fn test_member_static_function_undefined() {
 #[test]
 fn test_mutate_function_fail() {
     let bytes = include_bytes!("mut_function_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -171,25 +153,22 @@ fn test_mutate_self_variable_fail() {
 #[test]
 fn test_mutate_self_function_fail() {
     let bytes = include_bytes!("mut_self_function_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_mutate_self_static_function_fail() {
     let bytes = include_bytes!("mut_self_static_function_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_mutate_static_function_fail() {
     let bytes = include_bytes!("mut_static_function_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -213,9 +192,8 @@ fn test_mutate_variable_fail() {
 #[test]
 fn test_self_fail() {
     let bytes = include_bytes!("self_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -229,17 +207,15 @@ fn test_self_member_pass() {
 #[test]
 fn test_self_member_invalid() {
     let bytes = include_bytes!("self_member_invalid.leo");
-    let program = parse_program(bytes).unwrap();
 
-    let _err = expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_self_member_undefined() {
     let bytes = include_bytes!("self_member_undefined.leo");
-    let program = parse_program(bytes).unwrap();
 
-    let _err = expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 // All
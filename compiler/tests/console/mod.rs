@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+use crate::{assert_satisfied, expect_compiler_error, expect_type_inference_error, generate_main_input, parse_program};
 use leo_typed::InputValue;
 
 #[test]
@@ -51,25 +51,22 @@ fn test_log_parameter_many() {
 #[test]
 fn test_log_parameter_fail_unknown() {
     let bytes = include_bytes!("log_parameter_fail_unknown.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_log_parameter_fail_empty() {
     let bytes = include_bytes!("log_parameter_fail_empty.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_log_parameter_fail_none() {
     let bytes = include_bytes!("log_parameter_fail_empty.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -16,38 +16,34 @@
 
 pub mod packages;
 
-use crate::{assert_satisfied, expect_compiler_error, parse_program};
+use crate::{assert_satisfied, expect_type_inference_error, parse_program};
 
 #[test]
 fn test_core_circuit_invalid() {
     let program_bytes = include_bytes!("core_package_invalid.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
 fn test_core_circuit_star_fail() {
     let program_bytes = include_bytes!("core_circuit_star_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
 fn test_core_package_invalid() {
     let program_bytes = include_bytes!("core_package_invalid.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
 fn test_core_unstable_package_invalid() {
     let program_bytes = include_bytes!("core_unstable_package_invalid.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
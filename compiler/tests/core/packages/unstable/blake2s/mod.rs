@@ -14,17 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::bytes_gadget_to_input;
 use crate::{
     assert_satisfied,
-    expect_compiler_error,
+    expect_type_inference_error,
     generate_main_input,
     get_output,
     parse_program,
     parse_program_with_input,
 };
 
-use leo_input::types::{IntegerType, U8Type, UnsignedIntegerType};
-use leo_typed::InputValue;
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use snarkos_algorithms::prf::blake2s::Blake2s as B2SPRF;
@@ -33,17 +32,15 @@ use snarkos_models::algorithms::PRF;
 #[test]
 fn test_arguments_length_fail() {
     let program_bytes = include_bytes!("arguments_length_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
 fn test_arguments_type_fail() {
     let program_bytes = include_bytes!("arguments_type_fail.leo");
-    let program = parse_program(program_bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(program_bytes);
 }
 
 #[test]
@@ -95,13 +92,3 @@ fn test_blake2s_random() {
 
     assert_satisfied(program);
 }
-
-fn bytes_gadget_to_input(bytes: Vec<u8>) -> InputValue {
-    let u8_type = IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {}));
-    let bytes = bytes
-        .into_iter()
-        .map(|byte| InputValue::Integer(u8_type.clone(), byte.to_string()))
-        .collect::<Vec<_>>();
-
-    InputValue::Array(bytes)
-}
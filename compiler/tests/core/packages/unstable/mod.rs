@@ -15,3 +15,20 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod blake2s;
+pub mod pedersen;
+pub mod poseidon;
+pub mod schnorr;
+pub mod sha256;
+
+use leo_input::types::{IntegerType, U8Type, UnsignedIntegerType};
+use leo_typed::InputValue;
+
+pub(crate) fn bytes_gadget_to_input(bytes: Vec<u8>) -> InputValue {
+    let u8_type = IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {}));
+    let bytes = bytes
+        .into_iter()
+        .map(|byte| InputValue::Integer(u8_type.clone(), byte.to_string()))
+        .collect::<Vec<_>>();
+
+    InputValue::Array(bytes)
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::bytes_gadget_to_input;
+use crate::{assert_satisfied, expect_type_inference_error, generate_main_input, parse_program};
+
+use leo_core::{pedersen_output_bytes, pedersen_parameters};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use snarkos_algorithms::commitment::PedersenCommitment;
+use snarkos_curves::edwards_bls12::Fr;
+use snarkos_models::algorithms::{CommitmentScheme, CRH};
+use snarkos_utilities::{bytes::ToBytes, rand::UniformRand, to_bytes};
+
+#[test]
+fn test_arguments_length_fail() {
+    let program_bytes = include_bytes!("arguments_length_fail.leo");
+
+    expect_type_inference_error(program_bytes);
+}
+
+#[test]
+fn test_pedersen_hash_random() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = pedersen_parameters();
+
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+
+    // Use snarkos-algorithms pedersen hash to get expected value
+    let expected = pedersen_output_bytes(parameters.crh.hash(&message).unwrap());
+
+    let bytes = include_bytes!("pedersen_hash_random.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("message", Some(bytes_gadget_to_input(message.to_vec()))),
+        ("expected", Some(bytes_gadget_to_input(expected))),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_pedersen_commitment_random() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let commitment = PedersenCommitment::from(pedersen_parameters());
+
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+
+    let randomness = Fr::rand(&mut rng);
+
+    // Use snarkos-algorithms pedersen commitment to get expected value
+    let expected = pedersen_output_bytes(commitment.commit(&message, &randomness).unwrap());
+
+    let bytes = include_bytes!("pedersen_commitment_random.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("message", Some(bytes_gadget_to_input(message.to_vec()))),
+        (
+            "randomness",
+            Some(bytes_gadget_to_input(to_bytes![randomness].unwrap())),
+        ),
+        ("expected", Some(bytes_gadget_to_input(expected))),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
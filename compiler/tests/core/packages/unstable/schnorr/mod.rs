@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::bytes_gadget_to_input;
+use crate::{assert_satisfied, expect_type_inference_error, generate_main_input, parse_program};
+
+use leo_core::{schnorr_parameters, SchnorrEdwards};
+use leo_typed::InputValue;
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use snarkos_models::algorithms::SignatureScheme;
+use snarkos_utilities::{bytes::ToBytes, to_bytes};
+
+#[test]
+fn test_arguments_type_fail() {
+    let program_bytes = include_bytes!("arguments_type_fail.leo");
+
+    expect_type_inference_error(program_bytes);
+}
+
+fn verify_program(public_key: Vec<u8>, message: [u8; 32], signature: Vec<u8>, expected: bool) {
+    let bytes = include_bytes!("schnorr_verify.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("public_key", Some(bytes_gadget_to_input(public_key))),
+        ("message", Some(bytes_gadget_to_input(message.to_vec()))),
+        ("signature", Some(bytes_gadget_to_input(signature))),
+        ("expected", Some(InputValue::Boolean(expected))),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_schnorr_verify() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let schnorr = SchnorrEdwards {
+        parameters: schnorr_parameters(),
+    };
+
+    let private_key = schnorr.generate_private_key(&mut rng).unwrap();
+    let public_key = schnorr.generate_public_key(&private_key).unwrap();
+
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+
+    let signature = schnorr.sign(&private_key, &message, &mut rng).unwrap();
+
+    // Use snarkos-algorithms schnorr verify to get expected value
+    assert!(schnorr.verify(&public_key, &message, &signature).unwrap());
+
+    verify_program(
+        to_bytes![public_key].unwrap(),
+        message,
+        to_bytes![signature].unwrap(),
+        true,
+    );
+}
+
+#[test]
+fn test_schnorr_verify_wrong_message() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let schnorr = SchnorrEdwards {
+        parameters: schnorr_parameters(),
+    };
+
+    let private_key = schnorr.generate_private_key(&mut rng).unwrap();
+    let public_key = schnorr.generate_public_key(&private_key).unwrap();
+
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+
+    let signature = schnorr.sign(&private_key, &message, &mut rng).unwrap();
+
+    let mut other_message = [0u8; 32];
+    rng.fill(&mut other_message);
+
+    // Use snarkos-algorithms schnorr verify to get expected value
+    assert!(!schnorr.verify(&public_key, &other_message, &signature).unwrap());
+
+    verify_program(
+        to_bytes![public_key].unwrap(),
+        other_message,
+        to_bytes![signature].unwrap(),
+        false,
+    );
+}
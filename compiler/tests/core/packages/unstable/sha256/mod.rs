@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::bytes_gadget_to_input;
+use crate::{assert_satisfied, expect_type_inference_error, generate_main_input, parse_program};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use snarkos_algorithms::crh::sha256::sha256;
+
+#[test]
+fn test_arguments_length_fail() {
+    let program_bytes = include_bytes!("arguments_length_fail.leo");
+
+    expect_type_inference_error(program_bytes);
+}
+
+#[test]
+fn test_sha256_random() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..5 {
+        let mut message = [0u8; 32];
+        rng.fill(&mut message);
+
+        // Use snarkos-algorithms sha256 to get expected value
+        let expected = sha256(&message);
+
+        let bytes = include_bytes!("sha256_random.leo");
+        let mut program = parse_program(bytes).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("message", Some(bytes_gadget_to_input(message.to_vec()))),
+            ("expected", Some(bytes_gadget_to_input(expected))),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
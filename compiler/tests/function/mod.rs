@@ -14,24 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{
-    assert_satisfied,
-    expect_compiler_error,
-    get_output,
-    parse_program,
-    parse_program_with_input,
-    EdwardsTestCompiler,
-};
-use leo_compiler::errors::{CompilerError, ExpressionError, FunctionError, StatementError};
-
-fn expect_undefined_identifier(program: EdwardsTestCompiler) {
-    match expect_compiler_error(program) {
-        CompilerError::FunctionError(FunctionError::StatementError(StatementError::ExpressionError(
-            ExpressionError::Error(_),
-        ))) => {}
-        error => panic!("Expected function undefined, got {}", error),
-    }
-}
+use crate::{assert_satisfied, expect_type_inference_error, get_output, parse_program, parse_program_with_input};
 
 #[test]
 fn test_empty() {
@@ -113,25 +96,15 @@ fn test_return() {
 #[test]
 fn test_scope_fail() {
     let bytes = include_bytes!("scope_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    match expect_compiler_error(program) {
-        CompilerError::FunctionError(FunctionError::StatementError(StatementError::ExpressionError(
-            ExpressionError::FunctionError(value),
-        ))) => match *value {
-            FunctionError::StatementError(StatementError::ExpressionError(ExpressionError::Error(_))) => {}
-            error => panic!("Expected function undefined, got {}", error),
-        },
-        error => panic!("Expected function undefined, got {}", error),
-    }
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_undefined() {
     let bytes = include_bytes!("undefined.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_undefined_identifier(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -147,9 +120,8 @@ fn test_value_unchanged() {
 #[test]
 fn test_return_array_nested_fail() {
     let bytes = include_bytes!("return_array_nested_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -163,9 +135,8 @@ fn test_return_array_nested_pass() {
 #[test]
 fn test_return_array_tuple_fail() {
     let bytes = include_bytes!("return_array_tuple_fail.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
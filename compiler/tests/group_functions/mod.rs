@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_type_inference_error,
+    get_output,
+    parse_program,
+    parse_program_with_input,
+    EdwardsTestCompiler,
+};
+
+use snarkos_curves::{
+    edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq},
+    templates::twisted_edwards_extended::GroupAffine,
+};
+use snarkos_models::curves::TEModelParameters;
+
+/// Returns `multiple` times the generator computed natively.
+fn generator_multiple(multiple: usize) -> GroupAffine<EdwardsParameters> {
+    let (x, y) = EdwardsParameters::AFFINE_GENERATOR_COEFFS;
+    let generator = EdwardsAffine::new(x, y);
+
+    let mut point = generator;
+    for _ in 1..multiple {
+        point += &generator;
+    }
+
+    point
+}
+
+/// Asserts that the program outputs the given field value in its registers.
+fn expect_field_output(program: EdwardsTestCompiler, expected: Fq) {
+    let output = get_output(program);
+    let output = String::from_utf8_lossy(output.bytes()).to_string();
+
+    assert!(output.contains(&format!("Some({:?})", expected)));
+}
+
+#[test]
+fn test_generator() {
+    let bytes = include_bytes!("generator.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_mul_constant() {
+    let bytes = include_bytes!("mul_constant.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_mul_input() {
+    let program_bytes = include_bytes!("mul_input.leo");
+    let input_bytes = include_bytes!("input/three.in");
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    expect_field_output(program, generator_multiple(3).x);
+}
+
+#[test]
+fn test_mul_allocated() {
+    let program_bytes = include_bytes!("mul_allocated.leo");
+    let input_bytes = include_bytes!("input/three.in");
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    expect_field_output(program, generator_multiple(6).y);
+}
+
+#[test]
+fn test_coordinates() {
+    let bytes = include_bytes!("coordinates.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_undefined_static() {
+    let bytes = include_bytes!("undefined_static.leo");
+    let error = expect_type_inference_error(bytes);
+
+    assert!(error.to_string().contains("has no static member `zero`"));
+}
+
+#[test]
+fn test_arguments_length() {
+    let bytes = include_bytes!("arguments_length.leo");
+    let error = expect_type_inference_error(bytes);
+
+    assert!(error.to_string().contains("expected 0 input variables, found 1"));
+}
@@ -17,6 +17,8 @@
 use crate::{assert_satisfied, expect_compiler_error, parse_program_with_input, EdwardsTestCompiler};
 use leo_compiler::errors::CompilerError;
 
+use snarkos_models::gadgets::r1cs::ConstraintCounter;
+
 fn expect_fail(program: EdwardsTestCompiler) {
     match expect_compiler_error(program) {
         CompilerError::FunctionError(_) => {}
@@ -63,3 +65,35 @@ fn test_input_multiple() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_input_public() {
+    let program_bytes = include_bytes!("main_public.leo");
+    let input_bytes = include_bytes!("input/main_public.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_input_public_allocation() {
+    let program_bytes = include_bytes!("main_public_allocation.leo");
+    let input_bytes = include_bytes!("input/main_public.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    // Only the eight bits of the public `u8` are allocated as public inputs
+    let mut cs = ConstraintCounter::new();
+    program.generate_constraints_helper(&mut cs).unwrap();
+
+    assert_eq!(8, cs.num_inputs);
+}
+
+#[test]
+fn test_input_public_fail_section() {
+    let program_bytes = include_bytes!("main_public.leo");
+    let input_bytes = include_bytes!("input/main_public_fail_section.in");
+
+    assert!(parse_program_with_input(program_bytes, input_bytes).is_err());
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, generate_main_input, parse_program};
+use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+use leo_typed::InputValue;
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+#[test]
+fn test_constant_fold() {
+    let bytes = include_bytes!("constant.leo");
+    let program = parse_program(bytes).unwrap();
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let _output = program.generate_constraints_helper(&mut cs).unwrap();
+
+    assert!(cs.is_satisfied());
+    assert_eq!(cs.num_constraints(), 0);
+}
+
+#[test]
+fn test_compound_assign() {
+    let bytes = include_bytes!("assign.leo");
+    let mut program = parse_program(bytes).unwrap();
+
+    let integer_type = IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {}));
+    let main_input = generate_main_input(vec![
+        ("a", Some(InputValue::Integer(integer_type.clone(), "10".to_string()))),
+        ("b", Some(InputValue::Integer(integer_type, "2".to_string()))),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_precedence() {
+    let bytes = include_bytes!("precedence.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
@@ -91,6 +91,36 @@ fn test_i16_pow() {
     TestI16::test_pow();
 }
 
+#[test]
+fn test_i16_rem() {
+    TestI16::test_rem();
+}
+
+#[test]
+fn test_i16_bit_and() {
+    TestI16::test_bit_and();
+}
+
+#[test]
+fn test_i16_bit_or() {
+    TestI16::test_bit_or();
+}
+
+#[test]
+fn test_i16_bit_xor() {
+    TestI16::test_bit_xor();
+}
+
+#[test]
+fn test_i16_shl() {
+    TestI16::test_shl();
+}
+
+#[test]
+fn test_i16_shr() {
+    TestI16::test_shr();
+}
+
 #[test]
 fn test_i16_eq() {
     TestI16::test_eq();
@@ -91,6 +91,36 @@ fn test_i8_pow() {
     TestI8::test_pow();
 }
 
+#[test]
+fn test_i8_rem() {
+    TestI8::test_rem();
+}
+
+#[test]
+fn test_i8_bit_and() {
+    TestI8::test_bit_and();
+}
+
+#[test]
+fn test_i8_bit_or() {
+    TestI8::test_bit_or();
+}
+
+#[test]
+fn test_i8_bit_xor() {
+    TestI8::test_bit_xor();
+}
+
+#[test]
+fn test_i8_shl() {
+    TestI8::test_shl();
+}
+
+#[test]
+fn test_i8_shr() {
+    TestI8::test_shr();
+}
+
 #[test]
 fn test_i8_eq() {
     TestI8::test_eq();
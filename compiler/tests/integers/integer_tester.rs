@@ -45,6 +45,24 @@ pub trait IntegerTester {
     /// Tests a non-wrapping exponentiation
     fn test_pow();
 
+    /// Tests a remainder
+    fn test_rem();
+
+    /// Tests a bitwise and
+    fn test_bit_and();
+
+    /// Tests a bitwise or
+    fn test_bit_or();
+
+    /// Tests a bitwise exclusive or
+    fn test_bit_xor();
+
+    /// Tests a left shift, including shifts by the bit width or more
+    fn test_shl();
+
+    /// Tests a right shift, including shifts by the bit width or more
+    fn test_shr();
+
     /// Tests == evaluation
     fn test_eq();
 
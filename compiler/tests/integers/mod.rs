@@ -23,6 +23,8 @@ pub mod uint_macro;
 pub mod integer_tester;
 pub use self::integer_tester::*;
 
+pub mod bitwise;
+
 // must be below macro definitions!
 pub mod u128;
 pub mod u16;
@@ -76,6 +76,36 @@ fn test_u128_pow() {
     TestU128::test_pow();
 }
 
+#[test]
+fn test_u128_rem() {
+    TestU128::test_rem();
+}
+
+#[test]
+fn test_u128_bit_and() {
+    TestU128::test_bit_and();
+}
+
+#[test]
+fn test_u128_bit_or() {
+    TestU128::test_bit_or();
+}
+
+#[test]
+fn test_u128_bit_xor() {
+    TestU128::test_bit_xor();
+}
+
+#[test]
+fn test_u128_shl() {
+    TestU128::test_shl();
+}
+
+#[test]
+fn test_u128_shr() {
+    TestU128::test_shr();
+}
+
 #[test]
 fn test_u128_eq() {
     TestU128::test_eq();
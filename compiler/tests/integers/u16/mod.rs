@@ -76,6 +76,36 @@ fn test_u16_pow() {
     TestU16::test_pow();
 }
 
+#[test]
+fn test_u16_rem() {
+    TestU16::test_rem();
+}
+
+#[test]
+fn test_u16_bit_and() {
+    TestU16::test_bit_and();
+}
+
+#[test]
+fn test_u16_bit_or() {
+    TestU16::test_bit_or();
+}
+
+#[test]
+fn test_u16_bit_xor() {
+    TestU16::test_bit_xor();
+}
+
+#[test]
+fn test_u16_shl() {
+    TestU16::test_shl();
+}
+
+#[test]
+fn test_u16_shr() {
+    TestU16::test_shr();
+}
+
 #[test]
 fn test_u16_eq() {
     TestU16::test_eq();
@@ -76,6 +76,36 @@ fn test_u64_pow() {
     TestU64::test_pow();
 }
 
+#[test]
+fn test_u64_rem() {
+    TestU64::test_rem();
+}
+
+#[test]
+fn test_u64_bit_and() {
+    TestU64::test_bit_and();
+}
+
+#[test]
+fn test_u64_bit_or() {
+    TestU64::test_bit_or();
+}
+
+#[test]
+fn test_u64_bit_xor() {
+    TestU64::test_bit_xor();
+}
+
+#[test]
+fn test_u64_shl() {
+    TestU64::test_shl();
+}
+
+#[test]
+fn test_u64_shr() {
+    TestU64::test_shr();
+}
+
 #[test]
 fn test_u64_eq() {
     TestU64::test_eq();
@@ -76,6 +76,36 @@ fn test_u8_pow() {
     TestU8::test_pow();
 }
 
+#[test]
+fn test_u8_rem() {
+    TestU8::test_rem();
+}
+
+#[test]
+fn test_u8_bit_and() {
+    TestU8::test_bit_and();
+}
+
+#[test]
+fn test_u8_bit_or() {
+    TestU8::test_bit_or();
+}
+
+#[test]
+fn test_u8_bit_xor() {
+    TestU8::test_bit_xor();
+}
+
+#[test]
+fn test_u8_shl() {
+    TestU8::test_shl();
+}
+
+#[test]
+fn test_u8_shr() {
+    TestU8::test_shr();
+}
+
 #[test]
 fn test_u8_eq() {
     TestU8::test_eq();
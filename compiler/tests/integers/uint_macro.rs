@@ -173,6 +173,143 @@ macro_rules! test_uint {
                 }
             }
 
+            fn test_rem() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = match a.checked_rem(b) {
+                        Some(valid) => valid,
+                        None => continue,
+                    };
+
+                    let bytes = include_bytes!("rem.leo");
+                    let mut program = parse_program(bytes).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_bit_and() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = a & b;
+
+                    let bytes = include_bytes!("bit_and.leo");
+                    let mut program = parse_program(bytes).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_bit_or() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = a | b;
+
+                    let bytes = include_bytes!("bit_or.leo");
+                    let mut program = parse_program(bytes).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_bit_xor() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = a ^ b;
+
+                    let bytes = include_bytes!("bit_xor.leo");
+                    let mut program = parse_program(bytes).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_shl() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = (rand::random::<u32>() % ((0 as $type_).count_zeros() + 2)) as $type_;
+
+                    // shifting by the bit width or more shifts out every bit
+                    let c = a.checked_shl(b as u32).unwrap_or(0);
+
+                    let bytes = include_bytes!("shl.leo");
+                    let mut program = parse_program(bytes).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_shr() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = (rand::random::<u32>() % ((0 as $type_).count_zeros() + 2)) as $type_;
+
+                    // shifting by the bit width or more shifts out every bit
+                    let c = a.checked_shr(b as u32).unwrap_or(0);
+
+                    let bytes = include_bytes!("shr.leo");
+                    let mut program = parse_program(bytes).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
             fn test_eq() {
                 for _ in 0..10 {
                     let a: $type_ = rand::random();
@@ -17,6 +17,7 @@
 pub mod address;
 pub mod array;
 pub mod boolean;
+pub mod casts;
 pub mod circuits;
 pub mod console;
 pub mod core;
@@ -24,12 +25,16 @@ pub mod definition;
 // pub mod field;
 pub mod function;
 // pub mod group;
+pub mod group_functions;
 pub mod import;
 pub mod input_files;
 pub mod integers;
 pub mod mutability;
+pub mod optimization;
+pub mod profiler;
 pub mod statements;
 pub mod syntax;
+pub mod test_functions;
 pub mod tuples;
 
 use leo_compiler::{
@@ -40,6 +45,7 @@ use leo_compiler::{
     OutputBytes,
 };
 use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+use leo_type_inference::TypeInferenceError;
 use leo_typed::{InputValue, MainInput};
 
 use snarkos_curves::edwards_bls12::Fq;
@@ -178,6 +184,14 @@ pub(crate) fn expect_compiler_error(program: EdwardsTestCompiler) -> CompilerErr
     program.generate_constraints_helper(&mut cs).unwrap_err()
 }
 
+pub(crate) fn expect_type_inference_error(bytes: &[u8]) -> TypeInferenceError {
+    match parse_program(bytes) {
+        Err(CompilerError::TypeInferenceError(error)) => error,
+        Err(error) => panic!("Expected type inference error, got {}", error),
+        Ok(_) => panic!("Expected type inference error, program type checked"),
+    }
+}
+
 // pub(crate) fn expect_synthesis_error(program: EdwardsTestCompiler) {
 //     let mut cs = TestConstraintSystem::<Fq>::new();
 //     let _output = program.generate_constraints_helper(&mut cs).unwrap();
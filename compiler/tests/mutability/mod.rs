@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+use crate::{assert_satisfied, expect_compiler_error, expect_type_inference_error, generate_main_input, parse_program};
 use leo_typed::InputValue;
 
 #[test]
@@ -92,17 +92,15 @@ fn test_circuit_variable_mut() {
 #[test]
 fn test_circuit_function_mut() {
     let bytes = include_bytes!("circuit_function_mut.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
 fn test_circuit_static_function_mut() {
     let bytes = include_bytes!("circuit_static_function_mut.leo");
-    let program = parse_program(bytes).unwrap();
 
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
 
 #[test]
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{parse_program_with_input, EdwardsTestCompiler};
+use leo_compiler::OutputBytes;
+use leo_optimizer::OptLevel;
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+fn synthesize(mut program: EdwardsTestCompiler, opt_level: OptLevel) -> (OutputBytes, usize) {
+    program.optimize(opt_level);
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let output = program.generate_constraints_helper(&mut cs).unwrap();
+
+    assert!(cs.is_satisfied());
+
+    (output, cs.num_constraints())
+}
+
+/// Asserts that optimizing the program removes constraints without changing its output.
+fn expect_fewer_constraints(bytes: &[u8]) {
+    let input_bytes = include_bytes!("input/main.in");
+    let program = parse_program_with_input(bytes, input_bytes).unwrap();
+
+    let (expected, unoptimized) = synthesize(program.clone(), OptLevel::None);
+    let (basic_output, basic) = synthesize(program.clone(), OptLevel::Basic);
+    let (full_output, full) = synthesize(program, OptLevel::Full);
+
+    assert_eq!(expected.bytes(), basic_output.bytes());
+    assert_eq!(expected.bytes(), full_output.bytes());
+
+    assert!(basic <= unoptimized);
+    assert!(full < unoptimized);
+}
+
+#[test]
+fn test_dead_branch() {
+    let bytes = include_bytes!("dead_branch.leo");
+
+    expect_fewer_constraints(bytes);
+}
+
+#[test]
+fn test_unrolled_loop() {
+    let bytes = include_bytes!("unrolled_loop.leo");
+
+    expect_fewer_constraints(bytes);
+}
+
+#[test]
+fn test_inlined_function() {
+    let bytes = include_bytes!("inlined_function.leo");
+
+    expect_fewer_constraints(bytes);
+}
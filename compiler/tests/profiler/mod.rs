@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{parse_program_with_input, EdwardsTestCompiler};
+use leo_compiler::{ConstraintProfile, ProfilingConstraintSystem};
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+fn profile_program() -> (TestConstraintSystem<Fq>, ConstraintProfile) {
+    let program_bytes = include_bytes!("program.leo");
+    let input_bytes = include_bytes!("input/main.in");
+    let program: EdwardsTestCompiler = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    let mut cs = ProfilingConstraintSystem::new(TestConstraintSystem::<Fq>::new());
+    let _output = program.generate_constraints_helper(&mut cs).unwrap();
+    let (cs, profile) = cs.into_parts();
+
+    assert!(cs.is_satisfied());
+
+    (cs, profile)
+}
+
+fn constraints_of_line(profile: &ConstraintProfile, function: &str, line: usize) -> usize {
+    profile
+        .lines()
+        .into_iter()
+        .find(|((name, line_), _)| name == function && *line_ == Some(line))
+        .map(|(_, cost)| cost.constraints)
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_total() {
+    let (cs, profile) = profile_program();
+
+    assert_eq!(profile.total().constraints, cs.num_constraints());
+    assert!(profile.total().aux_variables > 0);
+}
+
+#[test]
+fn test_functions() {
+    let (_cs, profile) = profile_program();
+    let functions = profile
+        .functions()
+        .into_iter()
+        .map(|(function, _)| function)
+        .collect::<Vec<_>>();
+
+    assert!(functions.contains(&"main".to_string()));
+    assert!(functions.contains(&"square".to_string()));
+    assert!(functions.contains(&"Point::scale".to_string()));
+}
+
+#[test]
+fn test_lines() {
+    let (_cs, profile) = profile_program();
+
+    assert!(constraints_of_line(&profile, "square", 10) > 0);
+    assert!(constraints_of_line(&profile, "Point::scale", 5) > 0);
+    assert!(constraints_of_line(&profile, "main", 17) > 0);
+}
+
+#[test]
+fn test_folded_stacks() {
+    let (_cs, profile) = profile_program();
+    let folded = profile.to_folded_stacks();
+
+    assert!(folded.lines().any(|line| line.starts_with("main;square;line 10 ")));
+    assert!(folded.lines().any(|line| line.starts_with("main;Point::scale;line 5 ")));
+
+    let total = folded
+        .lines()
+        .map(|line| line.rsplit(' ').next().unwrap().parse::<usize>().unwrap())
+        .sum::<usize>();
+
+    assert_eq!(total, profile.total().constraints);
+}
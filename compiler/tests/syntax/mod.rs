@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{expect_compiler_error, parse_input, parse_program};
+use crate::{expect_type_inference_error, parse_input, parse_program};
 use leo_ast::ParserError;
-use leo_compiler::errors::{CompilerError, ExpressionError, FunctionError, StatementError};
+use leo_compiler::errors::CompilerError;
 use leo_input::InputParserError;
+use leo_type_inference::TypeError;
 
 pub mod identifiers;
 
@@ -36,14 +37,11 @@ fn test_semicolon() {
 #[test]
 fn test_undefined() {
     let bytes = include_bytes!("undefined.leo");
-    let program = parse_program(bytes).unwrap();
 
-    let error = expect_compiler_error(program);
+    let error = expect_type_inference_error(bytes);
 
-    match error {
-        CompilerError::FunctionError(FunctionError::StatementError(StatementError::ExpressionError(
-            ExpressionError::Error(error),
-        ))) => {
+    match error.errors().as_slice() {
+        [TypeError::Error(error)] => {
             assert_eq!(
                 format!("{}", error),
                 vec![
@@ -76,8 +74,7 @@ fn input_syntax_error() {
 #[test]
 fn test_compare_mismatched_types() {
     let bytes = include_bytes!("compare_mismatched_types.leo");
-    let program = parse_program(bytes).unwrap();
 
     // previously this bug caused a stack overflow
-    expect_compiler_error(program);
+    expect_type_inference_error(bytes);
 }
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::parse_program;
+use leo_compiler::{TestOptions, TestReport};
+use leo_package::inputs::{InputPair, InputPairs};
+
+fn run_tests(options: TestOptions) -> TestReport {
+    let bytes = include_bytes!("failing.leo");
+    let program = parse_program(bytes).unwrap();
+
+    // Run every test with the default empty input
+    let mut pairs = InputPairs::new();
+    pairs.pairs.insert("test".to_string(), InputPair {
+        input_file: String::new(),
+        state_file: String::new(),
+    });
+
+    program.compile_test_constraints(pairs, &options).unwrap()
+}
+
+fn test_names(report: &TestReport) -> Vec<&str> {
+    report.results.iter().map(|result| result.name.as_str()).collect()
+}
+
+#[test]
+fn test_run_all() {
+    let report = run_tests(TestOptions::default());
+
+    assert_eq!(
+        vec!["test::other_c", "test::test_a", "test::test_b"],
+        test_names(&report)
+    );
+    assert_eq!(0, report.passed());
+    assert_eq!(3, report.failed());
+    assert_eq!(0, report.filtered_out);
+}
+
+#[test]
+fn test_filter() {
+    let report = run_tests(TestOptions {
+        filter: Some("test_".to_string()),
+        ..TestOptions::default()
+    });
+
+    assert_eq!(vec!["test::test_a", "test::test_b"], test_names(&report));
+    assert_eq!(1, report.filtered_out);
+}
+
+#[test]
+fn test_filter_exact() {
+    let report = run_tests(TestOptions {
+        filter: Some("test_a".to_string()),
+        exact: true,
+        ..TestOptions::default()
+    });
+
+    assert_eq!(vec!["test::test_a"], test_names(&report));
+    assert_eq!(2, report.filtered_out);
+}
+
+#[test]
+fn test_fail_fast() {
+    let report = run_tests(TestOptions {
+        fail_fast: true,
+        ..TestOptions::default()
+    });
+
+    assert_eq!(vec!["test::other_c"], test_names(&report));
+    assert_eq!(1, report.failed());
+}
@@ -62,6 +62,15 @@ impl CoreCircuitError {
         Self::new_from_span(message, span)
     }
 
+    pub fn invalid_field(circuit_name: String, span: Span) -> Self {
+        let message = format!(
+            "Core circuit `{}` is only defined over the Edwards BLS12 base field",
+            circuit_name
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn invalid_array(actual: Value, span: Span) -> Self {
         let message = format!("Core circuit expected an array argument, found `{}`", actual);
 
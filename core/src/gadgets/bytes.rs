@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CoreCircuitError, Value};
+use leo_typed::Span;
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        curves::FpGadget,
+        r1cs::{ConstraintSystem, ConstraintVar, LinearCombination},
+        utilities::{
+            boolean::Boolean,
+            uint::{UInt, UInt8},
+        },
+    },
+};
+
+/// Returns the bytes of an array argument with the given length.
+pub fn check_array_bytes(value: Value, size: usize, span: Span) -> Result<Vec<UInt8>, CoreCircuitError> {
+    let array_value = match value {
+        Value::Array(array) => array,
+        value => return Err(CoreCircuitError::invalid_array(value, span)),
+    };
+
+    if size != array_value.len() {
+        return Err(CoreCircuitError::array_length(size, array_value.len(), span));
+    }
+
+    let mut array_bytes = vec![];
+
+    for value in array_value {
+        let byte = match value {
+            Value::U8(u8) => u8,
+            value => return Err(CoreCircuitError::invalid_array_bytes(value, span)),
+        };
+
+        array_bytes.push(byte)
+    }
+
+    Ok(array_bytes)
+}
+
+/// Returns the little-endian bits of the given bytes.
+pub fn bytes_to_bits_le(bytes: &[UInt8]) -> Vec<Boolean> {
+    bytes.iter().flat_map(|byte| byte.to_bits_le()).collect()
+}
+
+/// Packs little-endian bits into a field element without adding constraints.
+/// The caller must ensure that the bits fit in the field.
+pub fn pack_bits_le<F: PrimeField, CS: ConstraintSystem<F>>(_cs: CS, bits: &[Boolean]) -> FpGadget<F> {
+    let mut lc = LinearCombination::zero();
+    let mut value = Some(F::zero());
+    let mut coeff = F::one();
+
+    for bit in bits {
+        lc = lc + &bit.lc(CS::one(), coeff);
+
+        value = match (value, bit.get_value()) {
+            (Some(value), Some(true)) => Some(value + &coeff),
+            (Some(value), Some(false)) => Some(value),
+            _ => None,
+        };
+
+        coeff.double_in_place();
+    }
+
+    FpGadget {
+        value,
+        variable: ConstraintVar::LC(lc),
+    }
+}
+
+/// Returns `true` if both lists of bits are equal.
+pub fn evaluate_bits_equal<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    first: &[Boolean],
+    second: &[Boolean],
+) -> Result<Boolean, SynthesisError> {
+    assert_eq!(first.len(), second.len());
+
+    let mut equal_bits = Vec::with_capacity(first.len());
+
+    for (i, (a, b)) in first.iter().zip(second.iter()).enumerate() {
+        let different = Boolean::xor(cs.ns(|| format!("compare bit {}", i)), a, b)?;
+
+        equal_bits.push(different.not());
+    }
+
+    Boolean::kary_and(cs.ns(|| "all bits equal"), &equal_bits)
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::Field,
+    gadgets::r1cs::{ConstraintSystem, LinearCombination, Variable},
+};
+use snarkos_utilities::bytes::ToBytes;
+
+use std::{any::TypeId, marker::PhantomData};
+
+/// A constraint system over the Edwards BLS12 base field that forwards to a constraint system over `F`.
+///
+/// The snarkOS Edwards BLS12 gadgets are only defined over `Fq`, while core circuits are generic over
+/// the field of the program. This wrapper lets a core circuit use those gadgets once it has checked
+/// that `F` is `Fq`.
+pub struct EdwardsConstraintSystem<'a, F: Field, CS: ConstraintSystem<F>> {
+    cs: &'a mut CS,
+    _field: PhantomData<F>,
+}
+
+impl<'a, F: Field, CS: ConstraintSystem<F>> EdwardsConstraintSystem<'a, F, CS> {
+    /// Returns `None` if `F` is not the Edwards BLS12 base field.
+    pub fn new(cs: &'a mut CS) -> Option<Self> {
+        if TypeId::of::<F>() != TypeId::of::<Fq>() {
+            return None;
+        }
+
+        Some(Self {
+            cs,
+            _field: PhantomData,
+        })
+    }
+}
+
+/// Converts an `Fq` element into `F`, which is the same field.
+fn to_field<F: Field>(value: Fq) -> F {
+    let mut bytes = vec![];
+
+    value.write(&mut bytes).expect("failed to write a field element");
+
+    F::read(&bytes[..]).expect("failed to read a field element")
+}
+
+fn to_linear_combination<F: Field>(lc: LinearCombination<Fq>) -> LinearCombination<F> {
+    lc.0.iter().fold(LinearCombination::zero(), |acc, (variable, coeff)| {
+        acc + (to_field(*coeff), *variable)
+    })
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<Fq> for EdwardsConstraintSystem<'_, F, CS> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<Fq, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.alloc(annotation, || f().map(to_field))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<Fq, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.alloc_input(annotation, || f().map(to_field))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<Fq>) -> LinearCombination<Fq>,
+        LB: FnOnce(LinearCombination<Fq>) -> LinearCombination<Fq>,
+        LC: FnOnce(LinearCombination<Fq>) -> LinearCombination<Fq>,
+    {
+        let a = to_linear_combination::<F>(a(LinearCombination::zero()));
+        let b = to_linear_combination::<F>(b(LinearCombination::zero()));
+        let c = to_linear_combination::<F>(c(LinearCombination::zero()));
+
+        self.cs.enforce(annotation, |lc| lc + &a, |lc| lc + &b, |lc| lc + &c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn)
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_curves::bls12_377::Fq as Bls12Fq;
+    use snarkos_models::gadgets::r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    #[test]
+    fn test_other_field_fail() {
+        let mut cs = TestConstraintSystem::<Bls12Fq>::new();
+
+        assert!(EdwardsConstraintSystem::new(&mut cs).is_none());
+    }
+
+    #[test]
+    fn test_forward_constraints() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        {
+            let mut edwards_cs = EdwardsConstraintSystem::new(&mut cs).unwrap();
+
+            let a = edwards_cs.alloc(|| "a", || Ok(Fq::from(3u64))).unwrap();
+            let b = edwards_cs.alloc(|| "b", || Ok(Fq::from(5u64))).unwrap();
+            let c = edwards_cs.alloc(|| "c", || Ok(Fq::from(15u64))).unwrap();
+
+            edwards_cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+
+        assert_eq!(cs.num_constraints(), 1);
+        assert!(cs.is_satisfied());
+    }
+}
@@ -23,6 +23,9 @@ pub use self::packages::*;
 pub mod errors;
 pub use self::errors::*;
 
+pub mod gadgets;
+pub use self::gadgets::*;
+
 pub mod types;
 pub use self::types::*;
 
@@ -44,6 +47,11 @@ pub fn call_core_circuit<F: Field + PrimeField, CS: ConstraintSystem<F>>(
     // Match core circuit name
     Ok(match circuit_name.as_str() {
         CORE_UNSTABLE_BLAKE2S_NAME => Blake2sCircuit::call(cs, arguments, span)?,
+        CORE_UNSTABLE_PEDERSEN_HASH_NAME => PedersenHashCircuit::call(cs, arguments, span)?,
+        CORE_UNSTABLE_PEDERSEN_COMMITMENT_NAME => PedersenCommitmentCircuit::call(cs, arguments, span)?,
+        CORE_UNSTABLE_POSEIDON_NAME => PoseidonCircuit::call(cs, arguments, span)?,
+        CORE_UNSTABLE_SCHNORR_NAME => SchnorrCircuit::call(cs, arguments, span)?,
+        CORE_UNSTABLE_SHA256_NAME => Sha256Circuit::call(cs, arguments, span)?,
         _ => return Err(LeoCoreError::undefined_core_circuit(circuit_name, span)),
     })
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CoreCircuit, CoreCircuitError, Value};
+use crate::{check_array_bytes, CoreCircuit, CoreCircuitError, Value};
 
 use leo_typed::{
     Circuit,
@@ -32,11 +32,7 @@ use leo_typed::{
 use snarkos_gadgets::algorithms::prf::Blake2sGadget;
 use snarkos_models::{
     curves::{Field, PrimeField},
-    gadgets::{
-        algorithms::PRFGadget,
-        r1cs::ConstraintSystem,
-        utilities::{uint::UInt8, ToBytesGadget},
-    },
+    gadgets::{algorithms::PRFGadget, r1cs::ConstraintSystem, utilities::ToBytesGadget},
 };
 
 pub const CORE_UNSTABLE_BLAKE2S_NAME: &str = "Blake2s";
@@ -148,30 +144,6 @@ impl CoreCircuit for Blake2sCircuit {
     }
 }
 
-fn check_array_bytes(value: Value, size: usize, span: Span) -> Result<Vec<UInt8>, CoreCircuitError> {
-    let array_value = match value {
-        Value::Array(array) => array,
-        value => return Err(CoreCircuitError::invalid_array(value, span)),
-    };
-
-    if size != array_value.len() {
-        return Err(CoreCircuitError::array_length(size, array_value.len(), span));
-    }
-
-    let mut array_bytes = vec![];
-
-    for value in array_value {
-        let byte = match value {
-            Value::U8(u8) => u8,
-            value => return Err(CoreCircuitError::invalid_array_bytes(value, span)),
-        };
-
-        array_bytes.push(byte)
-    }
-
-    Ok(array_bytes)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,3 +16,18 @@
 
 pub mod blake2s;
 pub use self::blake2s::*;
+
+pub mod pedersen;
+pub use self::pedersen::*;
+
+pub mod poseidon;
+pub use self::poseidon::*;
+
+pub mod schnorr;
+pub use self::schnorr::*;
+
+pub mod sha256;
+pub use self::sha256::*;
+
+pub(crate) mod utilities;
+pub(crate) use self::utilities::*;
@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    byte_array_type,
+    bytes_to_bits_le,
+    check_array_bytes,
+    core_static_function,
+    CoreCircuit,
+    CoreCircuitError,
+    EdwardsConstraintSystem,
+    Value,
+};
+use leo_typed::{Circuit, Identifier, Span};
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use snarkos_algorithms::{commitment::PedersenCommitmentParameters, crh::PedersenSize};
+use snarkos_curves::edwards_bls12::{EdwardsProjective, Fq};
+use snarkos_gadgets::curves::edwards_bls12::EdwardsBlsGadget;
+use snarkos_models::{
+    curves::{Field, PrimeField, ProjectiveCurve},
+    gadgets::{
+        curves::GroupGadget,
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, ToBytesGadget},
+    },
+};
+use snarkos_utilities::{bytes::ToBytes, to_bytes};
+
+pub const CORE_UNSTABLE_PEDERSEN_HASH_NAME: &str = "PedersenHash";
+pub const CORE_UNSTABLE_PEDERSEN_COMMITMENT_NAME: &str = "PedersenCommitment";
+
+/// The seed used to sample the Pedersen bases.
+pub const PEDERSEN_PARAMETERS_SEED: u64 = 0x4c656f5065646572;
+
+/// A single window of 256 bits, so that one call hashes 32 bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PedersenWindow;
+
+impl PedersenSize for PedersenWindow {
+    const NUM_WINDOWS: usize = 1;
+    const WINDOW_SIZE: usize = 256;
+}
+
+pub type PedersenParameters = PedersenCommitmentParameters<EdwardsProjective, PedersenWindow>;
+
+/// Returns the Pedersen parameters shared by `PedersenHash` and `PedersenCommitment`.
+pub fn pedersen_parameters() -> PedersenParameters {
+    let rng = &mut XorShiftRng::seed_from_u64(PEDERSEN_PARAMETERS_SEED);
+
+    PedersenParameters::setup(rng)
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PedersenHashCircuit {}
+
+impl CoreCircuit for PedersenHashCircuit {
+    fn name() -> String {
+        CORE_UNSTABLE_PEDERSEN_HASH_NAME.to_owned()
+    }
+
+    /* PedersenHash circuit ast
+     * circuit PedersenHash {
+     *     static function hash(message: [u8; 32]) -> [u8; 32] {
+     *         // the x-coordinate of the Pedersen hash of the message
+     *         return pedersen_hash(message)
+     *     }
+     */
+    fn ast(circuit_name: Identifier, span: Span) -> Circuit {
+        Circuit {
+            circuit_name,
+            members: vec![core_static_function(
+                Self::name(),
+                "hash",
+                vec![("message", byte_array_type(32))],
+                byte_array_type(32),
+                span,
+            )],
+        }
+    }
+
+    /// Hashes the message with the Pedersen bases and returns the x-coordinate of the result
+    fn call<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Result<Vec<Value>, CoreCircuitError> {
+        let expected_length = 1usize;
+        let actual_length = arguments.len();
+
+        if expected_length != actual_length {
+            return Err(CoreCircuitError::arguments_length(expected_length, actual_length, span));
+        }
+
+        let message = check_array_bytes(arguments[0].to_owned(), 32, span.clone())?;
+
+        let mut cs = EdwardsConstraintSystem::new(&mut cs)
+            .ok_or_else(|| CoreCircuitError::invalid_field(Self::name(), span.clone()))?;
+
+        let parameters = pedersen_parameters();
+        let message_bits = bytes_to_bits_le(&message);
+
+        let hash = pedersen_evaluate(
+            cs.ns(|| "pedersen hash"),
+            &parameters.bases[0],
+            &message_bits,
+            span.clone(),
+        )?;
+
+        let bytes = x_coordinate_bytes(cs.ns(|| "x-coordinate bytes"), &hash, span)?;
+
+        Ok(vec![Value::Array(bytes)])
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PedersenCommitmentCircuit {}
+
+impl CoreCircuit for PedersenCommitmentCircuit {
+    fn name() -> String {
+        CORE_UNSTABLE_PEDERSEN_COMMITMENT_NAME.to_owned()
+    }
+
+    /* PedersenCommitment circuit ast
+     * circuit PedersenCommitment {
+     *     static function commit(message: [u8; 32], randomness: [u8; 32]) -> [u8; 32] {
+     *         // the x-coordinate of the Pedersen commitment to the message
+     *         return pedersen_commitment(message, randomness)
+     *     }
+     */
+    fn ast(circuit_name: Identifier, span: Span) -> Circuit {
+        Circuit {
+            circuit_name,
+            members: vec![core_static_function(
+                Self::name(),
+                "commit",
+                vec![("message", byte_array_type(32)), ("randomness", byte_array_type(32))],
+                byte_array_type(32),
+                span,
+            )],
+        }
+    }
+
+    /// Commits to the message with the given randomness and returns the x-coordinate of the result.
+    /// The randomness is the little-endian encoding of an Edwards BLS12 scalar.
+    fn call<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Result<Vec<Value>, CoreCircuitError> {
+        let expected_length = 2usize;
+        let actual_length = arguments.len();
+
+        if expected_length != actual_length {
+            return Err(CoreCircuitError::arguments_length(expected_length, actual_length, span));
+        }
+
+        let message = check_array_bytes(arguments[0].to_owned(), 32, span.clone())?;
+        let randomness = check_array_bytes(arguments[1].to_owned(), 32, span.clone())?;
+
+        let mut cs = EdwardsConstraintSystem::new(&mut cs)
+            .ok_or_else(|| CoreCircuitError::invalid_field(Self::name(), span.clone()))?;
+
+        let parameters = pedersen_parameters();
+        let message_bits = bytes_to_bits_le(&message);
+        let randomness_bits = bytes_to_bits_le(&randomness);
+
+        let hash = pedersen_evaluate(
+            cs.ns(|| "pedersen hash"),
+            &parameters.bases[0],
+            &message_bits,
+            span.clone(),
+        )?;
+        let blinding = pedersen_evaluate(
+            cs.ns(|| "pedersen randomness"),
+            &parameters.random_base,
+            &randomness_bits,
+            span.clone(),
+        )?;
+
+        let commitment = <EdwardsBlsGadget as GroupGadget<EdwardsProjective, Fq>>::add(
+            &hash,
+            cs.ns(|| "pedersen commitment"),
+            &blinding,
+        )
+        .map_err(|e| CoreCircuitError::cannot_enforce("Pedersen commitment".to_owned(), e, span.clone()))?;
+
+        let bytes = x_coordinate_bytes(cs.ns(|| "x-coordinate bytes"), &commitment, span)?;
+
+        Ok(vec![Value::Array(bytes)])
+    }
+}
+
+/// Returns the sum of the bases selected by the given bits.
+fn pedersen_evaluate<CS: ConstraintSystem<Fq>>(
+    mut cs: CS,
+    bases: &[EdwardsProjective],
+    bits: &[Boolean],
+    span: Span,
+) -> Result<EdwardsBlsGadget, CoreCircuitError> {
+    let mut result = <EdwardsBlsGadget as GroupGadget<EdwardsProjective, Fq>>::zero(cs.ns(|| "zero"))
+        .map_err(|e| CoreCircuitError::cannot_enforce("Pedersen zero".to_owned(), e, span.clone()))?;
+
+    <EdwardsBlsGadget as GroupGadget<EdwardsProjective, Fq>>::precomputed_base_scalar_mul(
+        &mut result,
+        cs.ns(|| "scalar multiplication"),
+        bits.iter().zip(bases),
+    )
+    .map_err(|e| CoreCircuitError::cannot_enforce("Pedersen scalar multiplication".to_owned(), e, span))?;
+
+    Ok(result)
+}
+
+fn x_coordinate_bytes<CS: ConstraintSystem<Fq>>(
+    cs: CS,
+    point: &EdwardsBlsGadget,
+    span: Span,
+) -> Result<Vec<Value>, CoreCircuitError> {
+    let bytes = point
+        .x
+        .to_bytes_strict(cs)
+        .map_err(|e| CoreCircuitError::cannot_enforce("Pedersen x-coordinate ToBytes".to_owned(), e, span))?;
+
+    Ok(bytes.into_iter().map(Value::U8).collect::<Vec<_>>())
+}
+
+/// Returns the bytes of the native `x` coordinate, to compare against the output of the circuit.
+pub fn pedersen_output_bytes(output: EdwardsProjective) -> Vec<u8> {
+    to_bytes![output.into_affine().x].expect("failed to write a field element")
+}
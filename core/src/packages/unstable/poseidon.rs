@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    byte_array_type,
+    bytes_to_bits_le,
+    check_array_bytes,
+    core_static_function,
+    pack_bits_le,
+    CoreCircuit,
+    CoreCircuitError,
+    EdwardsConstraintSystem,
+    Value,
+};
+use leo_typed::{Circuit, Identifier, Span};
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        curves::{FieldGadget, FpGadget},
+        r1cs::ConstraintSystem,
+        utilities::ToBytesGadget,
+    },
+};
+
+pub const CORE_UNSTABLE_POSEIDON_NAME: &str = "Poseidon";
+
+/// The seed used to sample the Poseidon round constants.
+pub const POSEIDON_PARAMETERS_SEED: u64 = 0x4c656f506f736569;
+
+/// The number of field elements in the Poseidon state. One element is the capacity.
+pub const POSEIDON_WIDTH: usize = 3;
+
+/// The exponent of the S-box. `x^17` is a permutation of the Edwards BLS12 base field.
+pub const POSEIDON_ALPHA: u64 = 17;
+
+pub const POSEIDON_FULL_ROUNDS: usize = 8;
+pub const POSEIDON_PARTIAL_ROUNDS: usize = 57;
+
+/// The round constants and MDS matrix of the Poseidon permutation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParameters<F: PrimeField> {
+    pub round_constants: Vec<[F; POSEIDON_WIDTH]>,
+    pub mds: [[F; POSEIDON_WIDTH]; POSEIDON_WIDTH],
+}
+
+impl<F: PrimeField> PoseidonParameters<F> {
+    /// Samples the round constants from a fixed seed and builds the Cauchy matrix `1 / (i + j + WIDTH)`.
+    pub fn setup() -> Self {
+        let rng = &mut XorShiftRng::seed_from_u64(POSEIDON_PARAMETERS_SEED);
+
+        let round_constants = (0..POSEIDON_FULL_ROUNDS + POSEIDON_PARTIAL_ROUNDS)
+            .map(|_| [F::rand(rng), F::rand(rng), F::rand(rng)])
+            .collect();
+
+        let mut mds = [[F::zero(); POSEIDON_WIDTH]; POSEIDON_WIDTH];
+
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = F::from((i + j + POSEIDON_WIDTH) as u64)
+                    .inverse()
+                    .expect("the Cauchy matrix has no zero denominators");
+            }
+        }
+
+        Self { round_constants, mds }
+    }
+
+    fn is_full_round(round: usize) -> bool {
+        let half_full_rounds = POSEIDON_FULL_ROUNDS / 2;
+
+        round < half_full_rounds || round >= half_full_rounds + POSEIDON_PARTIAL_ROUNDS
+    }
+}
+
+/// Computes the Poseidon hash of two field elements natively.
+pub fn poseidon_hash<F: PrimeField>(parameters: &PoseidonParameters<F>, inputs: [F; 2]) -> F {
+    let mut state = [F::zero(), inputs[0], inputs[1]];
+
+    for (round, constants) in parameters.round_constants.iter().enumerate() {
+        for (element, constant) in state.iter_mut().zip(constants.iter()) {
+            *element += constant;
+        }
+
+        if PoseidonParameters::<F>::is_full_round(round) {
+            for element in state.iter_mut() {
+                *element = element.pow([POSEIDON_ALPHA]);
+            }
+        } else {
+            state[0] = state[0].pow([POSEIDON_ALPHA]);
+        }
+
+        let mut mixed = [F::zero(); POSEIDON_WIDTH];
+
+        for (mixed, row) in mixed.iter_mut().zip(parameters.mds.iter()) {
+            for (element, coeff) in state.iter().zip(row.iter()) {
+                *mixed += &(*element * coeff);
+            }
+        }
+
+        state = mixed;
+    }
+
+    state[1]
+}
+
+/// Enforces the Poseidon hash of two field element gadgets.
+pub fn poseidon_hash_gadget<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    parameters: &PoseidonParameters<F>,
+    inputs: [FpGadget<F>; 2],
+) -> Result<FpGadget<F>, SynthesisError> {
+    let [first, second] = inputs;
+    let mut state = vec![FpGadget::zero(cs.ns(|| "capacity"))?, first, second];
+
+    for (round, constants) in parameters.round_constants.iter().enumerate() {
+        let mut cs = cs.ns(|| format!("round {}", round));
+
+        for (i, (element, constant)) in state.iter_mut().zip(constants.iter()).enumerate() {
+            *element = element.add_constant(cs.ns(|| format!("add constant {}", i)), constant)?;
+        }
+
+        let sbox_width = if PoseidonParameters::<F>::is_full_round(round) {
+            POSEIDON_WIDTH
+        } else {
+            1
+        };
+
+        for (i, element) in state.iter_mut().take(sbox_width).enumerate() {
+            *element = sbox(cs.ns(|| format!("sbox {}", i)), element)?;
+        }
+
+        let mut mixed = Vec::with_capacity(POSEIDON_WIDTH);
+
+        for (i, row) in parameters.mds.iter().enumerate() {
+            let mut sum = FpGadget::zero(cs.ns(|| format!("mix {} zero", i)))?;
+
+            for (j, (element, coeff)) in state.iter().zip(row.iter()).enumerate() {
+                let product = element.mul_by_constant(cs.ns(|| format!("mix {} mul {}", i, j)), coeff)?;
+                sum = sum.add(cs.ns(|| format!("mix {} add {}", i, j)), &product)?;
+            }
+
+            mixed.push(sum);
+        }
+
+        state = mixed;
+    }
+
+    Ok(state.swap_remove(1))
+}
+
+/// Computes `x^17` with four squarings and one multiplication.
+fn sbox<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    element: &FpGadget<F>,
+) -> Result<FpGadget<F>, SynthesisError> {
+    let mut power = element.clone();
+
+    for i in 0..4 {
+        power = power.square(cs.ns(|| format!("square {}", i)))?;
+    }
+
+    power.mul(cs.ns(|| "multiply"), element)
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PoseidonCircuit {}
+
+impl CoreCircuit for PoseidonCircuit {
+    fn name() -> String {
+        CORE_UNSTABLE_POSEIDON_NAME.to_owned()
+    }
+
+    /* Poseidon circuit ast
+     * circuit Poseidon {
+     *     static function hash(message: [u8; 32]) -> [u8; 32] {
+     *         // the message is split into two 16 byte field elements
+     *         return poseidon_hash(message)
+     *     }
+     */
+    fn ast(circuit_name: Identifier, span: Span) -> Circuit {
+        Circuit {
+            circuit_name,
+            members: vec![core_static_function(
+                Self::name(),
+                "hash",
+                vec![("message", byte_array_type(32))],
+                byte_array_type(32),
+                span,
+            )],
+        }
+    }
+
+    /// Packs each half of the message into a field element and returns the bytes of the Poseidon hash
+    fn call<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Result<Vec<Value>, CoreCircuitError> {
+        let expected_length = 1usize;
+        let actual_length = arguments.len();
+
+        if expected_length != actual_length {
+            return Err(CoreCircuitError::arguments_length(expected_length, actual_length, span));
+        }
+
+        let message = check_array_bytes(arguments[0].to_owned(), 32, span.clone())?;
+
+        let mut cs = EdwardsConstraintSystem::new(&mut cs)
+            .ok_or_else(|| CoreCircuitError::invalid_field(Self::name(), span.clone()))?;
+
+        // Each half is 128 bits, so packing it cannot overflow the field
+        let first = pack_bits_le(cs.ns(|| "pack first half"), &bytes_to_bits_le(&message[..16]));
+        let second = pack_bits_le(cs.ns(|| "pack second half"), &bytes_to_bits_le(&message[16..]));
+
+        let parameters = PoseidonParameters::<Fq>::setup();
+
+        let hash = poseidon_hash_gadget(cs.ns(|| "poseidon hash"), &parameters, [first, second])
+            .map_err(|e| CoreCircuitError::cannot_enforce("Poseidon hash".to_owned(), e, span.clone()))?;
+
+        let bytes = hash
+            .to_bytes_strict(cs.ns(|| "hash bytes"))
+            .map_err(|e| CoreCircuitError::cannot_enforce("Poseidon hash ToBytes".to_owned(), e, span))?;
+
+        Ok(vec![Value::Array(bytes.into_iter().map(Value::U8).collect())])
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    byte_array_type,
+    bytes_to_bits_le,
+    check_array_bytes,
+    core_static_function,
+    evaluate_bits_equal,
+    pack_bits_le,
+    CoreCircuit,
+    CoreCircuitError,
+    EdwardsConstraintSystem,
+    Value,
+};
+use leo_typed::{Circuit, Identifier, Span, Type};
+
+use blake2::Blake2s;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use snarkos_algorithms::signature::{SchnorrParameters, SchnorrSignature};
+use snarkos_curves::edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq, Fr};
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_gadgets::{algorithms::prf::blake2s_gadget, curves::edwards_bls12::EdwardsBlsGadget};
+use snarkos_models::{
+    algorithms::SignatureScheme,
+    curves::{Field, FpParameters, One, PrimeField, TEModelParameters},
+    gadgets::{
+        curves::{FieldGadget, FpGadget, GroupGadget},
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, uint::UInt8, ToBytesGadget},
+    },
+};
+
+pub const CORE_UNSTABLE_SCHNORR_NAME: &str = "Schnorr";
+
+/// The seed used to sample the Schnorr generator powers and salt.
+pub const SCHNORR_PARAMETERS_SEED: u64 = 0x4c656f5363686e6f;
+
+pub type SchnorrEdwards = SchnorrSignature<EdwardsAffine, Blake2s>;
+
+/// Returns the Schnorr signature parameters used by the `Schnorr` core circuit.
+pub fn schnorr_parameters() -> SchnorrParameters<EdwardsAffine, Blake2s> {
+    let rng = &mut XorShiftRng::seed_from_u64(SCHNORR_PARAMETERS_SEED);
+
+    SchnorrEdwards::setup(rng)
+        .expect("failed to set up the Schnorr signature scheme")
+        .parameters
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct SchnorrCircuit {}
+
+impl CoreCircuit for SchnorrCircuit {
+    fn name() -> String {
+        CORE_UNSTABLE_SCHNORR_NAME.to_owned()
+    }
+
+    /* Schnorr circuit ast
+     * circuit Schnorr {
+     *     static function verify(public_key: [u8; 64], message: [u8; 32], signature: [u8; 64]) -> bool {
+     *         // verify a Schnorr signature over the Edwards BLS12 group
+     *         return schnorr_verify(public_key, message, signature)
+     *     }
+     */
+    fn ast(circuit_name: Identifier, span: Span) -> Circuit {
+        Circuit {
+            circuit_name,
+            members: vec![core_static_function(
+                Self::name(),
+                "verify",
+                vec![
+                    ("public_key", byte_array_type(64)),
+                    ("message", byte_array_type(32)),
+                    ("signature", byte_array_type(64)),
+                ],
+                Type::Boolean,
+                span,
+            )],
+        }
+    }
+
+    /// Returns `true` if the signature of the message is valid for the public key.
+    /// The public key must be the encoding of a point on the Edwards BLS12 curve.
+    fn call<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Result<Vec<Value>, CoreCircuitError> {
+        let expected_length = 3usize;
+        let actual_length = arguments.len();
+
+        if expected_length != actual_length {
+            return Err(CoreCircuitError::arguments_length(expected_length, actual_length, span));
+        }
+
+        let public_key = check_array_bytes(arguments[0].to_owned(), 64, span.clone())?;
+        let message = check_array_bytes(arguments[1].to_owned(), 32, span.clone())?;
+        let signature = check_array_bytes(arguments[2].to_owned(), 64, span.clone())?;
+
+        let mut cs = EdwardsConstraintSystem::new(&mut cs)
+            .ok_or_else(|| CoreCircuitError::invalid_field(Self::name(), span.clone()))?;
+
+        let verified = schnorr_verify(cs.ns(|| "schnorr verify"), &public_key, &message, &signature)
+            .map_err(|e| CoreCircuitError::cannot_enforce("Schnorr verify".to_owned(), e, span))?;
+
+        Ok(vec![Value::Boolean(verified)])
+    }
+}
+
+/// Enforces the native `SchnorrSignature::verify` algorithm.
+fn schnorr_verify<CS: ConstraintSystem<Fq>>(
+    mut cs: CS,
+    public_key: &[UInt8],
+    message: &[UInt8],
+    signature: &[UInt8],
+) -> Result<Boolean, SynthesisError> {
+    let parameters = schnorr_parameters();
+
+    let public_key = alloc_point(cs.ns(|| "public key"), public_key)?;
+
+    let response_bits = bytes_to_bits_le(&signature[..32]);
+    let challenge_bits = bytes_to_bits_le(&signature[32..]);
+
+    // The claimed prover commitment is `response · g + challenge · public_key`
+    let mut commitment = <EdwardsBlsGadget as GroupGadget<EdwardsAffine, Fq>>::zero(cs.ns(|| "zero"))?;
+
+    <EdwardsBlsGadget as GroupGadget<EdwardsAffine, Fq>>::precomputed_base_scalar_mul(
+        &mut commitment,
+        cs.ns(|| "response times generator"),
+        response_bits.iter().zip(&parameters.generator_powers),
+    )?;
+
+    let zero = <EdwardsBlsGadget as GroupGadget<EdwardsAffine, Fq>>::zero(cs.ns(|| "mul zero"))?;
+    let public_key_times_challenge = <EdwardsBlsGadget as GroupGadget<EdwardsAffine, Fq>>::mul_bits(
+        &public_key,
+        cs.ns(|| "challenge times public key"),
+        &zero,
+        challenge_bits.iter(),
+    )?;
+
+    let commitment = <EdwardsBlsGadget as GroupGadget<EdwardsAffine, Fq>>::add(
+        &commitment,
+        cs.ns(|| "commitment"),
+        &public_key_times_challenge,
+    )?;
+
+    // Hash `salt || commitment || message`
+    let mut hash_input = UInt8::constant_vec(&parameters.salt);
+    hash_input.extend(commitment.x.to_bytes_strict(cs.ns(|| "commitment x bytes"))?);
+    hash_input.extend(commitment.y.to_bytes_strict(cs.ns(|| "commitment y bytes"))?);
+    hash_input.extend_from_slice(message);
+
+    let digest = blake2s_gadget(cs.ns(|| "blake2s"), &bytes_to_bits_le(&hash_input))?;
+
+    let mut digest_bits = vec![];
+
+    for (i, word) in digest.iter().enumerate() {
+        digest_bits.extend(bytes_to_bits_le(
+            &word.to_bytes(cs.ns(|| format!("digest bytes {}", i)))?,
+        ));
+    }
+
+    // Clear the bits that `Fr::from_random_bytes` shaves off
+    let shave_bits = <Fr as PrimeField>::Parameters::REPR_SHAVE_BITS as usize;
+    let length = digest_bits.len();
+
+    for bit in digest_bits.iter_mut().skip(length - shave_bits) {
+        *bit = Boolean::constant(false);
+    }
+
+    evaluate_bits_equal(cs.ns(|| "compare challenge"), &challenge_bits, &digest_bits)
+}
+
+/// Packs the canonical encoding of an affine point and checks that it is on the curve.
+fn alloc_point<CS: ConstraintSystem<Fq>>(mut cs: CS, bytes: &[UInt8]) -> Result<EdwardsBlsGadget, SynthesisError> {
+    let x = alloc_coordinate(cs.ns(|| "x"), &bytes[..32])?;
+    let y = alloc_coordinate(cs.ns(|| "y"), &bytes[32..])?;
+
+    // Check that ax^2 + y^2 = 1 + dx^2y^2, as ax^2 - 1 = y^2 * (dx^2 - 1)
+    let x2 = x.square(cs.ns(|| "x^2"))?;
+    let y2 = y.square(cs.ns(|| "y^2"))?;
+
+    let one = Fq::one();
+    let d_x2_minus_one = x2
+        .mul_by_constant(cs.ns(|| "d * x^2"), &EdwardsParameters::COEFF_D)?
+        .add_constant(cs.ns(|| "d * x^2 - 1"), &-one)?;
+    let a_x2_minus_one = x2
+        .mul_by_constant(cs.ns(|| "a * x^2"), &EdwardsParameters::COEFF_A)?
+        .add_constant(cs.ns(|| "a * x^2 - 1"), &-one)?;
+
+    d_x2_minus_one.mul_equals(cs.ns(|| "on curve check"), &y2, &a_x2_minus_one)?;
+
+    Ok(EdwardsBlsGadget::new(x, y))
+}
+
+fn alloc_coordinate<CS: ConstraintSystem<Fq>>(mut cs: CS, bytes: &[UInt8]) -> Result<FpGadget<Fq>, SynthesisError> {
+    let bits = bytes_to_bits_le(bytes);
+
+    // `enforce_in_field` expects big-endian bits
+    let bits_be = bits.iter().rev().cloned().collect::<Vec<_>>();
+    Boolean::enforce_in_field::<_, _, Fq>(cs.ns(|| "in field"), &bits_be)?;
+
+    Ok(pack_bits_le(cs.ns(|| "pack"), &bits))
+}
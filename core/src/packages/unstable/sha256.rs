@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{byte_array_type, check_array_bytes, core_static_function, CoreCircuit, CoreCircuitError, Value};
+use leo_typed::{Circuit, Identifier, Span};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{
+            boolean::Boolean,
+            uint::{UInt, UInt32, UInt8},
+        },
+    },
+};
+
+pub const CORE_UNSTABLE_SHA256_NAME: &str = "Sha256";
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Sha256Circuit {}
+
+impl CoreCircuit for Sha256Circuit {
+    fn name() -> String {
+        CORE_UNSTABLE_SHA256_NAME.to_owned()
+    }
+
+    /* Sha256 circuit ast
+     * circuit Sha256 {
+     *     static function hash(message: [u8; 32]) -> [u8; 32] {
+     *         // the message fits in a single padded block
+     *         return sha256(message)
+     *     }
+     */
+    fn ast(circuit_name: Identifier, span: Span) -> Circuit {
+        Circuit {
+            circuit_name,
+            members: vec![core_static_function(
+                Self::name(),
+                "hash",
+                vec![("message", byte_array_type(32))],
+                byte_array_type(32),
+                span,
+            )],
+        }
+    }
+
+    /// Computes the SHA-256 digest of a 32 byte message
+    fn call<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Result<Vec<Value>, CoreCircuitError> {
+        let expected_length = 1usize;
+        let actual_length = arguments.len();
+
+        if expected_length != actual_length {
+            return Err(CoreCircuitError::arguments_length(expected_length, actual_length, span));
+        }
+
+        let message = check_array_bytes(arguments[0].to_owned(), 32, span.clone())?;
+
+        // Pad the 256 bit message into a single 512 bit block
+        let mut block = message.chunks(4).map(uint32_from_be_bytes).collect::<Vec<_>>();
+        block.push(UInt32::constant(0x80000000));
+        block.extend((0..6).map(|_| UInt32::constant(0)));
+        block.push(UInt32::constant(256));
+
+        let state = INITIAL_STATE
+            .iter()
+            .map(|word| UInt32::constant(*word))
+            .collect::<Vec<_>>();
+
+        let digest = sha256_compress(cs.ns(|| "sha256 compression"), &state, &block)
+            .map_err(|e| CoreCircuitError::cannot_enforce("SHA-256 compression".to_owned(), e, span))?;
+
+        let bytes = digest.iter().flat_map(uint32_to_be_bytes).map(Value::U8).collect();
+
+        Ok(vec![Value::Array(bytes)])
+    }
+}
+
+/// Applies the SHA-256 compression function to one block of sixteen words.
+pub fn sha256_compress<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    state: &[UInt32],
+    block: &[UInt32],
+) -> Result<Vec<UInt32>, SynthesisError> {
+    assert_eq!(state.len(), 8);
+    assert_eq!(block.len(), 16);
+
+    // Message schedule
+    let mut w = block.to_vec();
+
+    for i in 16..64 {
+        let mut cs = cs.ns(|| format!("schedule {}", i));
+
+        let s0 = sigma(cs.ns(|| "s0"), &w[i - 15], 7, 18, Shift::Right(3))?;
+        let s1 = sigma(cs.ns(|| "s1"), &w[i - 2], 17, 19, Shift::Right(10))?;
+
+        let word = UInt32::addmany(cs.ns(|| "add"), &[w[i - 16].clone(), s0, w[i - 7].clone(), s1])?;
+
+        w.push(word);
+    }
+
+    // Compression
+    let mut working = state.to_vec();
+
+    for i in 0..64 {
+        let mut cs = cs.ns(|| format!("round {}", i));
+
+        let (a, b, c, d) = (&working[0], &working[1], &working[2], &working[3]);
+        let (e, f, g, h) = (&working[4], &working[5], &working[6], &working[7]);
+
+        let s1 = sigma(cs.ns(|| "S1"), e, 6, 11, Shift::Rotate(25))?;
+        let ch = ch(cs.ns(|| "ch"), e, f, g)?;
+        let temp1 = UInt32::addmany(cs.ns(|| "temp1"), &[
+            h.clone(),
+            s1,
+            ch,
+            UInt32::constant(ROUND_CONSTANTS[i]),
+            w[i].clone(),
+        ])?;
+
+        let s0 = sigma(cs.ns(|| "S0"), a, 2, 13, Shift::Rotate(22))?;
+        let maj = maj(cs.ns(|| "maj"), a, b, c)?;
+        let temp2 = UInt32::addmany(cs.ns(|| "temp2"), &[s0, maj])?;
+
+        let new_e = UInt32::addmany(cs.ns(|| "new e"), &[d.clone(), temp1.clone()])?;
+        let new_a = UInt32::addmany(cs.ns(|| "new a"), &[temp1, temp2])?;
+
+        working = vec![
+            new_a,
+            a.clone(),
+            b.clone(),
+            c.clone(),
+            new_e,
+            e.clone(),
+            f.clone(),
+            g.clone(),
+        ];
+    }
+
+    state
+        .iter()
+        .zip(working.iter())
+        .enumerate()
+        .map(|(i, (state, working))| {
+            UInt32::addmany(cs.ns(|| format!("final add {}", i)), &[state.clone(), working.clone()])
+        })
+        .collect()
+}
+
+/// The last term of a SHA-256 sigma function.
+enum Shift {
+    Rotate(usize),
+    Right(usize),
+}
+
+/// Returns `rotr(x, first) ^ rotr(x, second) ^ shift(x)`.
+fn sigma<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &UInt32,
+    first: usize,
+    second: usize,
+    third: Shift,
+) -> Result<UInt32, SynthesisError> {
+    let third = match third {
+        Shift::Rotate(by) => x.rotr(by),
+        Shift::Right(by) => shr(x, by),
+    };
+
+    let xor = x.rotr(first).xor(cs.ns(|| "first xor"), &x.rotr(second))?;
+
+    xor.xor(cs.ns(|| "second xor"), &third)
+}
+
+fn shr(x: &UInt32, by: usize) -> UInt32 {
+    let bits = x
+        .to_bits_le()
+        .into_iter()
+        .skip(by)
+        .chain((0..by).map(|_| Boolean::constant(false)))
+        .collect::<Vec<_>>();
+
+    UInt32::from_bits_le(&bits)
+}
+
+/// Returns `(e & f) ^ (!e & g)`.
+fn ch<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    e: &UInt32,
+    f: &UInt32,
+    g: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let mut bits = Vec::with_capacity(32);
+
+    for (i, ((e, f), g)) in e.bits.iter().zip(f.bits.iter()).zip(g.bits.iter()).enumerate() {
+        let mut cs = cs.ns(|| format!("bit {}", i));
+
+        let e_and_f = Boolean::and(cs.ns(|| "e and f"), e, f)?;
+        let not_e_and_g = Boolean::and(cs.ns(|| "not e and g"), &e.not(), g)?;
+
+        bits.push(Boolean::xor(cs.ns(|| "xor"), &e_and_f, &not_e_and_g)?);
+    }
+
+    Ok(UInt32::from_bits_le(&bits))
+}
+
+/// Returns `(a & b) ^ (a & c) ^ (b & c)`.
+fn maj<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let mut bits = Vec::with_capacity(32);
+
+    for (i, ((a, b), c)) in a.bits.iter().zip(b.bits.iter()).zip(c.bits.iter()).enumerate() {
+        let mut cs = cs.ns(|| format!("bit {}", i));
+
+        let a_and_b = Boolean::and(cs.ns(|| "a and b"), a, b)?;
+        let a_and_c = Boolean::and(cs.ns(|| "a and c"), a, c)?;
+        let b_and_c = Boolean::and(cs.ns(|| "b and c"), b, c)?;
+        let xor = Boolean::xor(cs.ns(|| "first xor"), &a_and_b, &a_and_c)?;
+
+        bits.push(Boolean::xor(cs.ns(|| "second xor"), &xor, &b_and_c)?);
+    }
+
+    Ok(UInt32::from_bits_le(&bits))
+}
+
+fn uint32_from_be_bytes(bytes: &[UInt8]) -> UInt32 {
+    let bits = bytes
+        .iter()
+        .rev()
+        .flat_map(|byte| byte.to_bits_le())
+        .collect::<Vec<_>>();
+
+    UInt32::from_bits_le(&bits)
+}
+
+fn uint32_to_be_bytes(word: &UInt32) -> Vec<UInt8> {
+    word.to_bits_le().chunks(8).rev().map(UInt8::from_bits_le).collect()
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_typed::{
+    CircuitMember,
+    Expression,
+    Function,
+    FunctionInput,
+    Identifier,
+    InputVariable,
+    IntegerType,
+    Span,
+    Statement,
+    Type,
+};
+
+/// Returns the type of a `[u8; length]` array.
+pub(crate) fn byte_array_type(length: usize) -> Type {
+    Type::Array(Box::new(Type::IntegerType(IntegerType::U8)), vec![length])
+}
+
+/// Returns a static circuit function that passes its inputs to the core circuit with the given name.
+///
+/// function <function_name>(<inputs>) -> <returns> {
+///     return <core_circuit_name>(<inputs>)
+/// }
+pub(crate) fn core_static_function(
+    core_circuit_name: String,
+    function_name: &str,
+    inputs: Vec<(&str, Type)>,
+    returns: Type,
+    span: Span,
+) -> CircuitMember {
+    let identifier = |name: &str| Identifier {
+        name: name.to_owned(),
+        span: span.clone(),
+    };
+
+    let arguments = inputs
+        .iter()
+        .map(|(name, _type)| Expression::Identifier(identifier(name)))
+        .collect();
+
+    let input = inputs
+        .into_iter()
+        .map(|(name, type_)| {
+            InputVariable::FunctionInput(FunctionInput {
+                identifier: identifier(name),
+                mutable: false,
+                type_,
+                span: span.clone(),
+            })
+        })
+        .collect();
+
+    CircuitMember::CircuitFunction(
+        true, // static function
+        Function {
+            identifier: identifier(function_name),
+            input,
+            returns: Some(returns),
+            statements: vec![Statement::Return(
+                Expression::CoreFunctionCall(core_circuit_name, arguments, span.clone()),
+                span.clone(),
+            )],
+            span,
+        },
+    )
+}
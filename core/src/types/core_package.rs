@@ -15,7 +15,18 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    unstable::blake2s::{Blake2sCircuit, CORE_UNSTABLE_BLAKE2S_NAME},
+    unstable::{
+        blake2s::{Blake2sCircuit, CORE_UNSTABLE_BLAKE2S_NAME},
+        pedersen::{
+            PedersenCommitmentCircuit,
+            PedersenHashCircuit,
+            CORE_UNSTABLE_PEDERSEN_COMMITMENT_NAME,
+            CORE_UNSTABLE_PEDERSEN_HASH_NAME,
+        },
+        poseidon::{PoseidonCircuit, CORE_UNSTABLE_POSEIDON_NAME},
+        schnorr::{SchnorrCircuit, CORE_UNSTABLE_SCHNORR_NAME},
+        sha256::{Sha256Circuit, CORE_UNSTABLE_SHA256_NAME},
+    },
     CoreCircuit,
     CoreCircuitStructList,
     CorePackageError,
@@ -78,6 +89,13 @@ impl CorePackage {
                 // match unstable core circuit
                 match circuit_name {
                     CORE_UNSTABLE_BLAKE2S_NAME => Blake2sCircuit::ast(circuit.symbol.clone(), span),
+                    CORE_UNSTABLE_PEDERSEN_HASH_NAME => PedersenHashCircuit::ast(circuit.symbol.clone(), span),
+                    CORE_UNSTABLE_PEDERSEN_COMMITMENT_NAME => {
+                        PedersenCommitmentCircuit::ast(circuit.symbol.clone(), span)
+                    }
+                    CORE_UNSTABLE_POSEIDON_NAME => PoseidonCircuit::ast(circuit.symbol.clone(), span),
+                    CORE_UNSTABLE_SCHNORR_NAME => SchnorrCircuit::ast(circuit.symbol.clone(), span),
+                    CORE_UNSTABLE_SHA256_NAME => Sha256Circuit::ast(circuit.symbol.clone(), span),
                     name => {
                         return Err(CorePackageError::undefined_unstable_core_circuit(
                             name.to_string(),
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A `//` or `/* */` comment recovered from the source text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl Comment {
+    pub fn is_line_comment(&self) -> bool {
+        self.text.starts_with("//")
+    }
+}
+
+/// Returns every comment in the source in order of appearance, skipping string literals.
+pub fn collect_comments(source: &str) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+    let mut comments = vec![];
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let next = bytes.get(i + 1);
+
+        let end = match bytes[i] {
+            b'"' => {
+                in_string = !in_string;
+                i += 1;
+                continue;
+            }
+            b'/' if !in_string && next == Some(&b'/') => source[i..]
+                .find(&['\n', '\r'][..])
+                .map(|offset| i + offset)
+                .unwrap_or_else(|| source.len()),
+            b'/' if !in_string && next == Some(&b'*') => source[i + 2..]
+                .find("*/")
+                .map(|offset| i + 2 + offset + 2)
+                .unwrap_or_else(|| source.len()),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+
+        let text = source[i..end].trim_end();
+
+        comments.push(Comment {
+            start: i,
+            end: i + text.len(),
+            text: text.to_string(),
+        });
+
+        i = end;
+    }
+
+    comments
+}
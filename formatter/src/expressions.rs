@@ -0,0 +1,389 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::printer::{INDENT, MAX_WIDTH};
+use leo_ast::{
+    access::{Access, AssigneeAccess},
+    circuits::CircuitVariable,
+    common::{
+        Assignee,
+        Identifier,
+        KeywordOrIdentifier,
+        RangeOrExpression,
+        SelfKeywordOrIdentifier,
+        SpreadOrExpression,
+        VariableName,
+        Variables,
+    },
+    expressions::{CircuitName, Expression},
+    operations::{AssignOperation, BinaryOperation, UnaryOperation},
+    types::{ArrayDimensions, DataType, IntegerType, Type},
+    values::{GroupRepresentation, Value},
+};
+
+/// Returns the binding power of a binary operation, mirroring the precedence climber of `leo-ast`.
+fn precedence(operation: &BinaryOperation) -> u8 {
+    match operation {
+        BinaryOperation::Or => 1,
+        BinaryOperation::And => 2,
+        BinaryOperation::Eq
+        | BinaryOperation::Ne
+        | BinaryOperation::Ge
+        | BinaryOperation::Gt
+        | BinaryOperation::Le
+        | BinaryOperation::Lt => 3,
+        BinaryOperation::BitOr => 4,
+        BinaryOperation::BitXor => 5,
+        BinaryOperation::BitAnd => 6,
+        BinaryOperation::Shl | BinaryOperation::Shr => 7,
+        BinaryOperation::Add | BinaryOperation::Sub => 8,
+        BinaryOperation::Mul | BinaryOperation::Div | BinaryOperation::Rem => 9,
+        BinaryOperation::Pow => 10,
+    }
+}
+
+fn format_binary_operation(operation: &BinaryOperation) -> &'static str {
+    match operation {
+        BinaryOperation::Or => "||",
+        BinaryOperation::And => "&&",
+        BinaryOperation::Eq => "==",
+        BinaryOperation::Ne => "!=",
+        BinaryOperation::Ge => ">=",
+        BinaryOperation::Gt => ">",
+        BinaryOperation::Le => "<=",
+        BinaryOperation::Lt => "<",
+        BinaryOperation::Add => "+",
+        BinaryOperation::Sub => "-",
+        BinaryOperation::Mul => "*",
+        BinaryOperation::Div => "/",
+        BinaryOperation::Pow => "**",
+        BinaryOperation::Rem => "%",
+        BinaryOperation::BitAnd => "&",
+        BinaryOperation::BitOr => "|",
+        BinaryOperation::BitXor => "^",
+        BinaryOperation::Shl => "<<",
+        BinaryOperation::Shr => ">>",
+    }
+}
+
+pub fn format_assign_operation(operation: &AssignOperation) -> &'static str {
+    match operation {
+        AssignOperation::Assign(_) => "=",
+        AssignOperation::AddAssign(_) => "+=",
+        AssignOperation::SubAssign(_) => "-=",
+        AssignOperation::MulAssign(_) => "*=",
+        AssignOperation::DivAssign(_) => "/=",
+        AssignOperation::PowAssign(_) => "**=",
+        AssignOperation::RemAssign(_) => "%=",
+        AssignOperation::BitAndAssign(_) => "&=",
+        AssignOperation::BitOrAssign(_) => "|=",
+        AssignOperation::BitXorAssign(_) => "^=",
+        AssignOperation::ShlAssign(_) => "<<=",
+        AssignOperation::ShrAssign(_) => ">>=",
+    }
+}
+
+/// Joins `items` between `open` and `close`, or puts one item per line if they do not fit.
+pub fn format_list(open: &str, items: Vec<String>, close: &str, trailing_comma: bool, indent: usize) -> String {
+    let flat = format!("{}{}{}", open, items.join(", "), close);
+
+    if items.is_empty() || (INDENT.len() * indent + flat.len() <= MAX_WIDTH && !flat.contains('\n')) {
+        return flat;
+    }
+
+    let mut list = format!("{}\n", open.trim_end());
+    let last = items.len() - 1;
+
+    for (i, item) in items.iter().enumerate() {
+        list.push_str(&INDENT.repeat(indent + 1));
+        list.push_str(item);
+        if i < last || trailing_comma {
+            list.push(',');
+        }
+        list.push('\n');
+    }
+
+    list.push_str(&INDENT.repeat(indent));
+    list.push_str(close.trim_start());
+    list
+}
+
+pub fn format_value(value: &Value) -> String {
+    match value {
+        Value::Group(group) => match &group.value {
+            GroupRepresentation::Tuple(tuple) => format!("{}group", tuple),
+            GroupRepresentation::Single(_) => group.span.as_str().to_string(),
+        },
+        value => value.span().as_str().to_string(),
+    }
+}
+
+fn format_identifier(identifier: &Identifier) -> String {
+    identifier.value.clone()
+}
+
+fn format_self_keyword_or_identifier(name: &SelfKeywordOrIdentifier) -> String {
+    match name {
+        SelfKeywordOrIdentifier::SelfKeyword(keyword) => keyword.keyword.clone(),
+        SelfKeywordOrIdentifier::Identifier(identifier) => format_identifier(identifier),
+    }
+}
+
+fn format_operand(expression: &Expression, parent: u8, right: bool, indent: usize) -> String {
+    let parenthesize = match expression {
+        Expression::Binary(binary) => {
+            let precedence = precedence(&binary.operation);
+            precedence < parent || (right && precedence == parent)
+        }
+        Expression::Ternary(_) => true,
+        _ => false,
+    };
+
+    let operand = format_expression(expression, indent);
+
+    if parenthesize {
+        format!("({})", operand)
+    } else {
+        operand
+    }
+}
+
+fn format_range_or_expression(range_or_expression: &RangeOrExpression, indent: usize) -> String {
+    match range_or_expression {
+        RangeOrExpression::Range(range) => {
+            let from = range.from.as_ref().map(|from| format_expression(from, indent));
+            let to = range.to.as_ref().map(|to| format_expression(to, indent));
+
+            format!("{}..{}", from.unwrap_or_default(), to.unwrap_or_default())
+        }
+        RangeOrExpression::Expression(expression) => format_expression(expression, indent),
+    }
+}
+
+fn format_spread_or_expression(spread_or_expression: &SpreadOrExpression, indent: usize) -> String {
+    match spread_or_expression {
+        SpreadOrExpression::Spread(spread) => format!("...{}", format_expression(&spread.expression, indent)),
+        SpreadOrExpression::Expression(expression) => format_expression(expression, indent),
+    }
+}
+
+fn format_circuit_variable(variable: &CircuitVariable, indent: usize) -> String {
+    format!(
+        "{}: {}",
+        format_identifier(&variable.identifier),
+        format_expression(&variable.expression, indent)
+    )
+}
+
+fn format_expressions(expressions: &[Expression], indent: usize) -> String {
+    expressions
+        .iter()
+        .map(|expression| format_expression(expression, indent))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Prints an expression, reinserting the parentheses that the parser drops.
+pub fn format_expression(expression: &Expression, indent: usize) -> String {
+    match expression {
+        Expression::Value(value) => format_value(value),
+        Expression::Identifier(identifier) => format_identifier(identifier),
+        Expression::Unary(unary) => {
+            let operand = format_expression(&unary.expression, indent);
+            let parenthesize = match *unary.expression {
+                Expression::Binary(_) | Expression::Cast(_) | Expression::Ternary(_) => true,
+                // `-(1)` must not turn into the negative literal `-1`
+                _ => operand.starts_with(|c: char| c.is_ascii_digit() || c == '-'),
+            };
+            let operand = if parenthesize {
+                format!("({})", operand)
+            } else {
+                operand
+            };
+
+            match unary.operation {
+                UnaryOperation::Negate(_) => format!("-{}", operand),
+                UnaryOperation::Not(_) => format!("!{}", operand),
+            }
+        }
+        Expression::Binary(binary) => {
+            let precedence = precedence(&binary.operation);
+
+            format!(
+                "{} {} {}",
+                format_operand(&binary.left, precedence, false, indent),
+                format_binary_operation(&binary.operation),
+                format_operand(&binary.right, precedence, true, indent)
+            )
+        }
+        Expression::Cast(cast) => {
+            let operand = format_expression(&cast.expression, indent);
+            let operand = match *cast.expression {
+                Expression::Binary(_) | Expression::Ternary(_) => format!("({})", operand),
+                _ => operand,
+            };
+
+            format!("{} as {}", operand, format_data_type(&cast.type_))
+        }
+        Expression::Ternary(ternary) => format!(
+            "if {} ? {} : {}",
+            format_expression(&ternary.first, indent),
+            format_expression(&ternary.second, indent),
+            format_expression(&ternary.third, indent)
+        ),
+        Expression::ArrayInline(array) => {
+            let items = array
+                .expressions
+                .iter()
+                .map(|expression| format_spread_or_expression(expression, indent + 1))
+                .collect();
+
+            format_list("[", items, "]", false, indent)
+        }
+        Expression::ArrayInitializer(array) => format!(
+            "[{}; {}]",
+            format_expression(&array.expression, indent),
+            format_array_dimensions(&array.dimensions)
+        ),
+        Expression::CircuitInline(circuit) => {
+            let name = match &circuit.name {
+                CircuitName::SelfType(self_type) => self_type.keyword.clone(),
+                CircuitName::Identifier(identifier) => format_identifier(identifier),
+            };
+
+            if circuit.members.is_empty() {
+                return format!("{} {{}}", name);
+            }
+
+            let members = circuit
+                .members
+                .iter()
+                .map(|member| format_circuit_variable(member, indent + 1))
+                .collect();
+
+            format!("{} {}", name, format_list("{ ", members, " }", true, indent))
+        }
+        Expression::Postfix(postfix) => {
+            let mut string = match &postfix.name {
+                KeywordOrIdentifier::SelfType(self_type) => self_type.keyword.clone(),
+                KeywordOrIdentifier::Input(input) => input.keyword.clone(),
+                KeywordOrIdentifier::Group(group) => group.keyword.clone(),
+                KeywordOrIdentifier::SelfKeywordOrIdentifier(name) => format_self_keyword_or_identifier(name),
+            };
+
+            for access in &postfix.accesses {
+                match access {
+                    Access::Array(array) => {
+                        string.push_str(&format!("[{}]", format_range_or_expression(&array.expression, indent)))
+                    }
+                    Access::Tuple(tuple) => string.push_str(&format!(".{}", tuple.number.value)),
+                    Access::Call(call) => string.push_str(&format!(
+                        "({})",
+                        format_expressions(&call.expressions.expressions, indent)
+                    )),
+                    Access::Object(member) => string.push_str(&format!(".{}", format_identifier(&member.identifier))),
+                    Access::StaticObject(member) => {
+                        string.push_str(&format!("::{}", format_identifier(&member.identifier)))
+                    }
+                }
+            }
+
+            string
+        }
+        Expression::Tuple(tuple) => format!("({})", format_expressions(&tuple.expressions, indent)),
+    }
+}
+
+pub fn format_assignee(assignee: &Assignee, indent: usize) -> String {
+    let mut string = format_self_keyword_or_identifier(&assignee.name);
+
+    for access in &assignee.accesses {
+        match access {
+            AssigneeAccess::Array(array) => {
+                string.push_str(&format!("[{}]", format_range_or_expression(&array.expression, indent)))
+            }
+            AssigneeAccess::Tuple(tuple) => string.push_str(&format!(".{}", tuple.number.value)),
+            AssigneeAccess::Member(member) => string.push_str(&format!(".{}", format_identifier(&member.identifier))),
+        }
+    }
+
+    string
+}
+
+fn format_variable_name(name: &VariableName) -> String {
+    match name.mutable {
+        Some(_) => format!("mut {}", format_identifier(&name.identifier)),
+        None => format_identifier(&name.identifier),
+    }
+}
+
+pub fn format_variables(variables: &Variables) -> String {
+    let names = variables.names.iter().map(format_variable_name).collect::<Vec<_>>();
+
+    let names = if names.len() == 1 {
+        names[0].clone()
+    } else {
+        format!("({})", names.join(", "))
+    };
+
+    match &variables.type_ {
+        Some(type_) => format!("{}: {}", names, format_type(type_)),
+        None => names,
+    }
+}
+
+pub fn format_array_dimensions(dimensions: &ArrayDimensions) -> String {
+    match dimensions {
+        ArrayDimensions::Single(single) => single.number.value.clone(),
+        ArrayDimensions::Multiple(multiple) => {
+            let numbers = multiple
+                .numbers
+                .iter()
+                .map(|number| number.value.clone())
+                .collect::<Vec<_>>();
+
+            format!("({})", numbers.join(", "))
+        }
+    }
+}
+
+fn format_data_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Address(_) => "address".to_string(),
+        DataType::Boolean(_) => "bool".to_string(),
+        DataType::Field(_) => "field".to_string(),
+        DataType::Group(_) => "group".to_string(),
+        DataType::Integer(IntegerType::Signed(integer)) => integer.to_string(),
+        DataType::Integer(IntegerType::Unsigned(integer)) => integer.to_string(),
+    }
+}
+
+pub fn format_type(type_: &Type) -> String {
+    match type_ {
+        Type::Basic(data_type) => format_data_type(data_type),
+        Type::Array(array) => format!(
+            "[{}; {}]",
+            format_type(&array.type_),
+            format_array_dimensions(&array.dimensions)
+        ),
+        Type::Tuple(tuple) => format!(
+            "({})",
+            tuple.types.iter().map(format_type).collect::<Vec<_>>().join(", ")
+        ),
+        Type::Circuit(circuit) => format_identifier(&circuit.identifier),
+        Type::SelfType(self_type) => self_type.keyword.clone(),
+    }
+}
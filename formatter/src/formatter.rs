@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FormatterError, InputFormatter, ProgramFormatter};
+use leo_ast::LeoAst;
+use leo_input::LeoInputParser;
+
+use std::path::PathBuf;
+
+pub struct LeoFormatter;
+
+impl LeoFormatter {
+    /// Returns the formatted source of a `.leo` file.
+    pub fn format_program(file_path: &PathBuf, source: &str) -> Result<String, FormatterError> {
+        let ast = LeoAst::new(file_path, source)?;
+
+        Ok(ProgramFormatter::format(source, ast.as_repr()))
+    }
+
+    /// Returns the formatted source of a `.in` or `.state` file.
+    pub fn format_input(file_path: &PathBuf, source: &str) -> Result<String, FormatterError> {
+        let file = LeoInputParser::parse_file(source).map_err(|mut error| {
+            error.set_path(file_path.clone());
+            error
+        })?;
+
+        Ok(InputFormatter::format(source, &file))
+    }
+}
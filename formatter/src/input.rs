@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expressions::format_list, Printer};
+use leo_input::{
+    definitions::Definition,
+    expressions::Expression,
+    files::{File, TableOrSection},
+    sections::Section,
+    types::{ArrayDimensions, Type},
+    values::{GroupRepresentation, Value},
+};
+
+/// Prints a `.in` or `.state` file in the canonical layout.
+pub struct InputFormatter<'a> {
+    printer: Printer<'a>,
+}
+
+impl<'a> InputFormatter<'a> {
+    pub fn format(source: &'a str, file: &File) -> String {
+        let mut formatter = Self {
+            printer: Printer::new(source),
+        };
+
+        for (i, entry) in file.entries.iter().enumerate() {
+            if i > 0 {
+                formatter.printer.separate();
+            }
+
+            match entry {
+                TableOrSection::Section(section) => formatter.section(section),
+                TableOrSection::Table(table) => {
+                    let end = formatter.printer.find(table.span.start(), ']') + 2;
+
+                    formatter.printer.begin_item(table.span.start(), end);
+                    formatter.printer.write(&format!("[[{}]]", table.visibility));
+                    formatter.printer.end_item(end);
+
+                    for section in &table.sections {
+                        formatter.printer.separate();
+                        formatter.section(section);
+                    }
+                }
+            }
+        }
+
+        formatter.printer.finish()
+    }
+
+    fn section(&mut self, section: &Section) {
+        let end = self.printer.find(section.span.start(), ']') + 1;
+
+        self.printer.begin_item(section.span.start(), end);
+        self.printer.write(&format!("[{}]", section.header));
+        self.printer.end_item(end);
+
+        for definition in &section.definitions {
+            self.definition(definition);
+        }
+    }
+
+    fn definition(&mut self, definition: &Definition) {
+        let end = self.printer.code_end(definition.span.start(), definition.span.end());
+
+        self.printer.begin_item(definition.span.start(), end);
+        self.printer.write(&format!(
+            "{}: {} = {};",
+            definition.parameter.variable.value,
+            format_input_type(&definition.parameter.type_),
+            format_input_expression(&definition.expression, self.printer.indent())
+        ));
+        self.printer.end_item(end);
+    }
+}
+
+fn format_dimensions(dimensions: &ArrayDimensions) -> String {
+    match dimensions {
+        ArrayDimensions::Single(single) => single.number.value.clone(),
+        ArrayDimensions::Multiple(multiple) => {
+            let numbers = multiple
+                .numbers
+                .iter()
+                .map(|number| number.value.clone())
+                .collect::<Vec<_>>();
+
+            format!("({})", numbers.join(", "))
+        }
+    }
+}
+
+pub fn format_input_type(type_: &Type) -> String {
+    match type_ {
+        Type::Basic(data_type) => data_type.to_string(),
+        Type::Array(array) => format!(
+            "[{}; {}]",
+            format_input_type(&array.type_),
+            format_dimensions(&array.dimensions)
+        ),
+        Type::Tuple(tuple) => format!(
+            "({})",
+            tuple
+                .types_
+                .iter()
+                .map(format_input_type)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+pub fn format_input_expression(expression: &Expression, indent: usize) -> String {
+    match expression {
+        Expression::ArrayInitializer(array) => format!(
+            "[{}; {}]",
+            format_input_expression(&array.expression, indent),
+            format_dimensions(&array.dimensions)
+        ),
+        Expression::ArrayInline(array) => {
+            let items = array
+                .expressions
+                .iter()
+                .map(|expression| format_input_expression(expression, indent + 1))
+                .collect();
+
+            format_list("[", items, "]", false, indent)
+        }
+        Expression::Tuple(tuple) => {
+            let items = tuple
+                .expressions
+                .iter()
+                .map(|expression| format_input_expression(expression, indent))
+                .collect::<Vec<_>>();
+
+            format!("({})", items.join(", "))
+        }
+        Expression::Value(Value::Group(group)) => match &group.value {
+            GroupRepresentation::Tuple(tuple) => format!("{}group", tuple),
+            GroupRepresentation::Single(_) => group.span.as_str().to_string(),
+        },
+        Expression::Value(value) => value.span().as_str().to_string(),
+    }
+}
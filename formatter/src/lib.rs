@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo formatter.
+//!
+//! Pretty-prints `.leo` programs and `.in`/`.state` input files into a canonical layout. Comments are
+//! dropped by the pest grammars, so they are recovered from the source text and reattached to the
+//! nearest printed item.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod comments;
+pub use self::comments::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod expressions;
+pub use self::expressions::*;
+
+pub mod formatter;
+pub use self::formatter::*;
+
+pub mod input;
+pub use self::input::*;
+
+pub mod printer;
+pub use self::printer::*;
+
+pub mod program;
+pub use self::program::*;
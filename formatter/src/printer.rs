@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{collect_comments, Comment};
+
+/// The width after which lists are split over several lines.
+pub const MAX_WIDTH: usize = 100;
+
+/// One level of indentation.
+pub const INDENT: &str = "    ";
+
+/// Writes formatted lines and interleaves the comments of the original source between them.
+///
+/// Items are printed with `begin_item` and `end_item`, passing the source offsets of the item so that
+/// the comments before it, trailing comments on its line and blank lines between items are kept.
+pub struct Printer<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    next_comment: usize,
+    last_end: usize,
+    indent: usize,
+    block_start: bool,
+    separate: bool,
+    output: String,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            comments: collect_comments(source),
+            next_comment: 0,
+            last_end: 0,
+            indent: 0,
+            block_start: true,
+            separate: false,
+            output: String::new(),
+        }
+    }
+
+    pub fn indent(&self) -> usize {
+        self.indent
+    }
+
+    /// Returns the end of the code in `start..end`, without trailing whitespace and comments.
+    pub fn code_end(&self, start: usize, end: usize) -> usize {
+        let mut end = end;
+
+        loop {
+            end = start + self.source[start..end].trim_end().len();
+
+            match self
+                .comments
+                .iter()
+                .find(|comment| comment.end == end && comment.start >= start)
+            {
+                Some(comment) => end = comment.start,
+                None => return end,
+            }
+        }
+    }
+
+    /// Returns the offset of the first `token` at or after `from` that is not part of a comment.
+    pub fn find(&self, from: usize, token: char) -> usize {
+        let mut offset = from;
+
+        while offset < self.source.len() {
+            if let Some(comment) = self.comments.iter().find(|comment| comment.start == offset) {
+                offset = comment.end;
+                continue;
+            }
+
+            let next = self.source[offset..].chars().next().unwrap();
+            if next == token {
+                return offset;
+            }
+
+            offset += next.len_utf8();
+        }
+
+        offset
+    }
+
+    /// Separates the next item from the previous one by a blank line.
+    pub fn separate(&mut self) {
+        self.separate = true;
+    }
+
+    /// Starts the line of the item spanning `start..end`, after the comments that precede its end.
+    pub fn begin_item(&mut self, start: usize, end: usize) {
+        self.leading_comments(end);
+        self.blank_line(start);
+        self.write_indent();
+    }
+
+    /// Ends the line of the item ending at `end`, keeping a comment on the same source line.
+    pub fn end_item(&mut self, end: usize) {
+        self.last_end = self.last_end.max(end);
+
+        if let Some(comment) = self.comments.get(self.next_comment) {
+            if comment.start >= end && !self.source[end..comment.start].contains('\n') {
+                self.output.push(' ');
+                self.output.push_str(&comment.text);
+                self.last_end = comment.end;
+                self.next_comment += 1;
+            }
+        }
+
+        self.output.push('\n');
+    }
+
+    /// Ends the current line with the `{` at `brace` and indents the lines that follow.
+    pub fn open_block(&mut self, brace: usize) {
+        self.end_item(brace + 1);
+        self.indent += 1;
+        self.block_start = true;
+    }
+
+    /// Writes the `}` at `brace` after the comments left inside the block.
+    pub fn close_block(&mut self, brace: usize) {
+        self.leading_comments(brace);
+        self.indent -= 1;
+        self.write_indent();
+        self.output.push('}');
+        self.last_end = brace + 1;
+        self.block_start = false;
+        self.separate = false;
+    }
+
+    pub fn write(&mut self, text: &str) {
+        self.output.push_str(text);
+    }
+
+    /// Returns the output after the remaining comments, ending with a single newline.
+    pub fn finish(mut self) -> String {
+        self.leading_comments(usize::MAX);
+
+        let output = self.output.trim();
+
+        if output.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", output)
+        }
+    }
+
+    fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.output.push_str(INDENT);
+        }
+    }
+
+    fn leading_comments(&mut self, before: usize) {
+        while let Some(comment) = self.comments.get(self.next_comment).cloned() {
+            if comment.start >= before {
+                break;
+            }
+
+            self.blank_line(comment.start);
+            self.write_indent();
+            self.output.push_str(&comment.text);
+            self.output.push('\n');
+            self.last_end = comment.end;
+            self.next_comment += 1;
+        }
+    }
+
+    /// Keeps a blank line before `next` if one was requested or the source has one.
+    fn blank_line(&mut self, next: usize) {
+        let gap = if self.last_end < next {
+            &self.source[self.last_end..next]
+        } else {
+            ""
+        };
+
+        let blank = self.separate || gap.matches('\n').count() >= 2;
+
+        if blank && !self.block_start && !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+
+        self.block_start = false;
+        self.separate = false;
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    expressions::{
+        format_assign_operation,
+        format_assignee,
+        format_expression,
+        format_list,
+        format_type,
+        format_variables,
+    },
+    Printer,
+};
+use leo_ast::{
+    annotations::{Annotation, AnnotationName},
+    circuits::{Circuit, CircuitMember},
+    common::Declare,
+    console::{ConsoleFunction, ConsoleFunctionCall, FormattedString},
+    definitions::Definition,
+    files::File,
+    functions::{Function, Input},
+    imports::PackageAccess,
+    statements::{ConditionalNestedOrEndStatement, ConditionalStatement, Statement},
+};
+
+use pest::Span;
+
+/// Prints a `.leo` file in the canonical layout.
+pub struct ProgramFormatter<'a> {
+    printer: Printer<'a>,
+}
+
+impl<'a> ProgramFormatter<'a> {
+    pub fn format(source: &'a str, file: &File) -> String {
+        let mut formatter = Self {
+            printer: Printer::new(source),
+        };
+
+        let mut previous_import = false;
+
+        for (i, definition) in file.definitions.iter().enumerate() {
+            let import = matches!(definition, Definition::Import(_));
+
+            // Imports are grouped, every other definition is separated by a blank line.
+            if i > 0 && !(import && previous_import) {
+                formatter.printer.separate();
+            }
+
+            formatter.definition(definition);
+            previous_import = import;
+        }
+
+        formatter.printer.finish()
+    }
+
+    fn code_end(&self, span: &Span) -> usize {
+        self.printer.code_end(span.start(), span.end())
+    }
+
+    fn expression(&self, expression: &leo_ast::expressions::Expression) -> String {
+        format_expression(expression, self.printer.indent())
+    }
+
+    fn definition(&mut self, definition: &Definition) {
+        match definition {
+            Definition::Annotated(annotated) => {
+                let end = annotated.annotation.span.end();
+
+                self.printer.begin_item(annotated.annotation.span.start(), end);
+                self.printer.write(&format_annotation(&annotated.annotation));
+                self.printer.end_item(end);
+
+                self.definition(&annotated.definition);
+            }
+            Definition::Import(import) => {
+                let end = self.code_end(&import.span);
+                let package = &import.package;
+                let prefix = format!("import {}.", package.name.value);
+
+                self.printer.begin_item(import.span.start(), end);
+                self.printer
+                    .write(&format_package_access(&package.access, prefix, self.printer.indent()));
+                self.printer.write(";");
+                self.printer.end_item(end);
+            }
+            Definition::Circuit(circuit) => self.circuit(circuit),
+            Definition::Function(function) => {
+                self.function(function, "function ", function.span.start());
+            }
+            Definition::TestFunction(test) => {
+                self.function(&test.function, "test function ", test.span.start());
+            }
+        }
+    }
+
+    fn circuit(&mut self, circuit: &Circuit) {
+        let brace = self.printer.find(circuit.identifier.span.end(), '{');
+
+        self.printer.begin_item(circuit.span.start(), brace);
+        self.printer.write(&format!("circuit {} {{", circuit.identifier.value));
+        self.printer.open_block(brace);
+
+        let mut end = brace + 1;
+        let mut previous_function = false;
+
+        for (i, member) in circuit.members.iter().enumerate() {
+            match member {
+                CircuitMember::CircuitVariableDefinition(variable) => {
+                    if previous_function {
+                        self.printer.separate();
+                    }
+
+                    end = self.code_end(&variable.span);
+
+                    let mutable = variable.mutable.as_ref().map(|_| "mut ").unwrap_or_default();
+
+                    self.printer.begin_item(variable.span.start(), end);
+                    self.printer.write(&format!(
+                        "{}{}: {},",
+                        mutable,
+                        variable.identifier.value,
+                        format_type(&variable.type_)
+                    ));
+                    self.printer.end_item(end);
+
+                    previous_function = false;
+                }
+                CircuitMember::CircuitFunction(function) => {
+                    if i > 0 {
+                        self.printer.separate();
+                    }
+
+                    let keyword = match function._static {
+                        Some(_) => "static function ",
+                        None => "function ",
+                    };
+
+                    end = self.function(&function.function, keyword, function.span.start());
+                    previous_function = true;
+                }
+            }
+        }
+
+        let close = self.printer.find(end, '}');
+
+        self.printer.close_block(close);
+        self.printer.end_item(close + 1);
+    }
+
+    /// Prints a function and returns the end of its closing brace.
+    fn function(&mut self, function: &Function, keyword: &str, start: usize) -> usize {
+        let brace = self.printer.find(function.identifier.span.end(), '{');
+
+        let parameters = function
+            .parameters
+            .iter()
+            .map(|parameter| match parameter {
+                Input::InputKeyword(keyword) => keyword.keyword.clone(),
+                Input::FunctionInput(input) => format!(
+                    "{}{}: {}",
+                    input.mutable.as_ref().map(|_| "mut ").unwrap_or_default(),
+                    input.identifier.value,
+                    format_type(&input.type_)
+                ),
+            })
+            .collect();
+
+        let open = format!("{}{}(", keyword, function.identifier.value);
+        let mut header = format_list(&open, parameters, ")", true, self.printer.indent());
+
+        if let Some(returns) = &function.returns {
+            header.push_str(&format!(" -> {}", format_type(returns)));
+        }
+
+        header.push_str(" {");
+
+        self.printer.begin_item(start, brace);
+        self.printer.write(&header);
+
+        let close = self.block(brace, &function.statements);
+        self.printer.end_item(close + 1);
+
+        close + 1
+    }
+
+    /// Prints the statements of the block opened at `brace` and returns the offset of its `}`.
+    fn block(&mut self, brace: usize, statements: &[Statement]) -> usize {
+        self.printer.open_block(brace);
+
+        let mut end = brace + 1;
+
+        for statement in statements {
+            self.statement(statement);
+            end = self.code_end(statement_span(statement));
+        }
+
+        let close = self.printer.find(end, '}');
+        self.printer.close_block(close);
+
+        close
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        let span = statement_span(statement);
+        let end = self.code_end(span);
+
+        let line = match statement {
+            Statement::Conditional(conditional) => return self.conditional(conditional, false),
+            Statement::Iteration(iteration) => {
+                let brace = self.printer.find(iteration.stop.span().end(), '{');
+
+                self.printer.begin_item(span.start(), brace);
+                self.printer.write(&format!(
+                    "for {} in {}..{} {{",
+                    iteration.index.value,
+                    self.expression(&iteration.start),
+                    self.expression(&iteration.stop)
+                ));
+
+                let close = self.block(brace, &iteration.statements);
+                return self.printer.end_item(close + 1);
+            }
+            Statement::Return(statement) => format!("return {}", self.expression(&statement.expression)),
+            Statement::Definition(definition) => {
+                let declare = match definition.declare {
+                    Declare::Const(_) => "const",
+                    Declare::Let(_) => "let",
+                };
+
+                let expressions = definition
+                    .expressions
+                    .iter()
+                    .map(|expression| self.expression(expression))
+                    .collect::<Vec<_>>();
+
+                format!(
+                    "{} {} = {};",
+                    declare,
+                    format_variables(&definition.variables),
+                    expressions.join(", ")
+                )
+            }
+            Statement::Assign(statement) => format!(
+                "{} {} {};",
+                format_assignee(&statement.assignee, self.printer.indent()),
+                format_assign_operation(&statement.assign),
+                self.expression(&statement.expression)
+            ),
+            Statement::Console(console) => format!("console.{};", self.console_function(console)),
+            Statement::Expression(statement) => format!("{};", self.expression(&statement.expression)),
+        };
+
+        self.printer.begin_item(span.start(), end);
+        self.printer.write(&line);
+        self.printer.end_item(end);
+    }
+
+    fn conditional(&mut self, conditional: &ConditionalStatement, nested: bool) {
+        let brace = self.printer.find(conditional.condition.span().end(), '{');
+
+        if !nested {
+            self.printer.begin_item(conditional.span.start(), brace);
+        }
+
+        self.printer
+            .write(&format!("if {} {{", self.expression(&conditional.condition)));
+
+        let close = self.block(brace, &conditional.statements);
+
+        match &conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => {
+                self.printer.write(" else ");
+                self.conditional(nested, true);
+            }
+            Some(ConditionalNestedOrEndStatement::End(statements)) => {
+                let brace = self.printer.find(close + 1, '{');
+
+                self.printer.write(" else {");
+
+                let close = self.block(brace, statements);
+                self.printer.end_item(close + 1);
+            }
+            None => self.printer.end_item(close + 1),
+        }
+    }
+
+    fn console_function(&self, console: &ConsoleFunctionCall) -> String {
+        match &console.function {
+            ConsoleFunction::Assert(assert) => format!("assert({})", self.expression(&assert.expression)),
+            ConsoleFunction::Debug(debug) => format!("debug({})", self.formatted_string(&debug.string)),
+            ConsoleFunction::Error(error) => format!("error({})", self.formatted_string(&error.string)),
+            ConsoleFunction::Log(log) => format!("log({})", self.formatted_string(&log.string)),
+        }
+    }
+
+    fn formatted_string(&self, string: &FormattedString) -> String {
+        // The span covers the string literal and its parameters, the literal is kept verbatim.
+        let text = string.span.as_str();
+        let literal_end = text[1..].find('"').map(|i| i + 2).unwrap_or_else(|| text.len());
+
+        let mut formatted = text[..literal_end].to_string();
+
+        for parameter in &string.parameters {
+            formatted.push_str(", ");
+            formatted.push_str(&self.expression(&parameter.expression));
+        }
+
+        formatted
+    }
+}
+
+fn statement_span<'a, 'ast>(statement: &'a Statement<'ast>) -> &'a Span<'ast> {
+    match statement {
+        Statement::Return(statement) => &statement.span,
+        Statement::Definition(statement) => &statement.span,
+        Statement::Assign(statement) => &statement.span,
+        Statement::Conditional(statement) => &statement.span,
+        Statement::Iteration(statement) => &statement.span,
+        Statement::Console(statement) => &statement.span,
+        Statement::Expression(statement) => &statement.span,
+    }
+}
+
+fn format_annotation(annotation: &Annotation) -> String {
+    let name = match annotation.name {
+        AnnotationName::Context(_) => "context",
+    };
+
+    let arguments = annotation
+        .arguments
+        .arguments
+        .iter()
+        .map(|argument| argument.value.clone())
+        .collect::<Vec<_>>();
+
+    format!("@{}({})", name, arguments.join(", "))
+}
+
+fn format_package_access(access: &PackageAccess, prefix: String, indent: usize) -> String {
+    match access {
+        PackageAccess::Star(_) => format!("{}*", prefix),
+        PackageAccess::SubPackage(package) => {
+            format_package_access(&package.access, format!("{}{}.", prefix, package.name.value), indent)
+        }
+        PackageAccess::Symbol(symbol) => match &symbol.alias {
+            Some(alias) => format!("{}{} as {}", prefix, symbol.value.value, alias.value),
+            None => format!("{}{}", prefix, symbol.value.value),
+        },
+        PackageAccess::Multiple(accesses) => {
+            let accesses = accesses
+                .iter()
+                .map(|access| format_package_access(access, String::new(), indent + 1))
+                .collect();
+
+            format_list(&format!("{}(", prefix), accesses, ")", true, indent)
+        }
+    }
+}
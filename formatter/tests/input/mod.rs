@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::format_input;
+
+#[test]
+fn test_format_input() {
+    let input_bytes = include_bytes!("unformatted.in");
+    let expected = include_str!("formatted.in");
+
+    assert_eq!(format_input(input_bytes), expected);
+}
+
+#[test]
+fn test_format_state() {
+    let state_bytes = include_bytes!("unformatted.state");
+    let expected = include_str!("formatted.state");
+
+    assert_eq!(format_input(state_bytes), expected);
+}
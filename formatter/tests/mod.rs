@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod input;
+pub mod program;
+pub mod round_trip;
+
+use leo_formatter::LeoFormatter;
+
+use std::path::PathBuf;
+
+const TEST_PROGRAM_PATH: &str = "";
+
+pub(crate) fn format_program(bytes: &[u8]) -> String {
+    let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+    let program_string = String::from_utf8_lossy(bytes);
+
+    LeoFormatter::format_program(&file_path, &program_string).unwrap()
+}
+
+pub(crate) fn format_input(bytes: &[u8]) -> String {
+    let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+    let input_string = String::from_utf8_lossy(bytes);
+
+    LeoFormatter::format_input(&file_path, &input_string).unwrap()
+}
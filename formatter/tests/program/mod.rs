@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::format_program;
+
+#[test]
+fn test_format() {
+    let program_bytes = include_bytes!("unformatted.leo");
+    let expected = include_str!("formatted.leo");
+
+    assert_eq!(format_program(program_bytes), expected);
+}
+
+#[test]
+fn test_format_idempotent() {
+    let expected = include_str!("formatted.leo");
+
+    assert_eq!(format_program(expected.as_bytes()), expected);
+}
+
+#[test]
+fn test_comments() {
+    let program_bytes = include_bytes!("comments.leo");
+    let expected = include_str!("comments_formatted.leo");
+
+    assert_eq!(format_program(program_bytes), expected);
+}
+
+#[test]
+fn test_long_array() {
+    let program_bytes = include_bytes!("long_array.leo");
+    let expected = include_str!("long_array_formatted.leo");
+
+    assert_eq!(format_program(program_bytes), expected);
+}
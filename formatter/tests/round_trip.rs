@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Formats every program and input file of the compiler tests and examples and checks that the
+//! result parses to the same syntax tree, keeps every comment and is stable under reformatting.
+
+use leo_ast::LeoAst;
+use leo_formatter::{collect_comments, LeoFormatter};
+use leo_input::LeoInputParser;
+
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn collect_files(directory: &Path, extensions: &[&str], files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            collect_files(&path, extensions, files);
+        } else if let Some(extension) = path.extension() {
+            if extensions.iter().any(|expected| extension == *expected) {
+                files.push(path);
+            }
+        }
+    }
+}
+
+fn test_files(extensions: &[&str]) -> Vec<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut files = vec![];
+
+    collect_files(&root.join("compiler").join("tests"), extensions, &mut files);
+    collect_files(&root.join("examples"), extensions, &mut files);
+
+    files.sort();
+    files
+}
+
+/// Removes the spans from a serialized syntax tree, along with the whitespace in formatted strings.
+fn strip_spans(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            object.remove("span");
+
+            if let Some(Value::String(string)) = object.get_mut("string") {
+                string.retain(|c| !c.is_whitespace());
+            }
+
+            object.values_mut().for_each(strip_spans);
+        }
+        Value::Array(array) => array.iter_mut().for_each(strip_spans),
+        _ => {}
+    }
+}
+
+fn program_json(file_path: &PathBuf, source: &str) -> Value {
+    let ast = LeoAst::new(file_path, source).unwrap();
+    let mut json = serde_json::from_str(&ast.to_json_string().unwrap()).unwrap();
+
+    strip_spans(&mut json);
+    json
+}
+
+/// Returns the source without comments and whitespace.
+fn tokens(source: &str) -> String {
+    let mut tokens = source.to_string();
+
+    for comment in collect_comments(source).iter().rev() {
+        tokens.replace_range(comment.start..comment.end, "");
+    }
+
+    tokens.retain(|c| !c.is_whitespace());
+    tokens
+}
+
+#[test]
+fn test_round_trip_programs() {
+    let mut formatted_files = 0;
+
+    for file_path in test_files(&["leo"]) {
+        let source = fs::read_to_string(&file_path).unwrap();
+
+        // Skip the fixtures that are expected to fail parsing.
+        if LeoAst::new(&file_path, &source).is_err() {
+            continue;
+        }
+
+        let formatted = LeoFormatter::format_program(&file_path, &source)
+            .unwrap_or_else(|error| panic!("{}: {}", file_path.display(), error));
+
+        assert_eq!(
+            program_json(&file_path, &source),
+            program_json(&file_path, &formatted),
+            "{}",
+            file_path.display()
+        );
+        assert_eq!(
+            collect_comments(&source).len(),
+            collect_comments(&formatted).len(),
+            "{}",
+            file_path.display()
+        );
+        assert_eq!(
+            LeoFormatter::format_program(&file_path, &formatted).unwrap(),
+            formatted,
+            "{}",
+            file_path.display()
+        );
+
+        formatted_files += 1;
+    }
+
+    assert!(formatted_files > 0);
+}
+
+#[test]
+fn test_round_trip_inputs() {
+    let mut formatted_files = 0;
+
+    for file_path in test_files(&["in", "state"]) {
+        let source = fs::read_to_string(&file_path).unwrap();
+
+        if LeoInputParser::parse_file(&source).is_err() {
+            continue;
+        }
+
+        let formatted = LeoFormatter::format_input(&file_path, &source)
+            .unwrap_or_else(|error| panic!("{}: {}", file_path.display(), error));
+
+        assert_eq!(tokens(&source), tokens(&formatted), "{}", file_path.display());
+        assert_eq!(
+            collect_comments(&source).len(),
+            collect_comments(&formatted).len(),
+            "{}",
+            file_path.display()
+        );
+        assert_eq!(
+            LeoFormatter::format_input(&file_path, &formatted).unwrap(),
+            formatted,
+            "{}",
+            file_path.display()
+        );
+
+        formatted_files += 1;
+    }
+
+    assert!(formatted_files > 0);
+}
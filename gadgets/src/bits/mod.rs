@@ -24,5 +24,8 @@ pub use self::comparator::*;
 pub mod rca;
 pub use self::rca::*;
 
+pub mod shifter;
+pub use self::shifter::*;
+
 pub mod sign_extend;
 pub use self::sign_extend::*;
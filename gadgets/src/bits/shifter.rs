@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, select::CondSelectGadget},
+    },
+};
+
+/// Shifts a n-bit number by the unsigned amount in `amount`.
+/// Expects least significant bit first. Shifting by n bits or more shifts out every bit.
+pub trait BarrelShifter<F: Field>
+where
+    Self: std::marker::Sized,
+{
+    /// Shifts towards the most significant bit, filling with zeros.
+    #[must_use]
+    fn shift_left_bits<CS: ConstraintSystem<F>>(&self, cs: CS, amount: &[Boolean]) -> Result<Self, SynthesisError>;
+
+    /// Shifts towards the least significant bit, filling with `fill`.
+    #[must_use]
+    fn shift_right_bits<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        amount: &[Boolean],
+        fill: &Boolean,
+    ) -> Result<Self, SynthesisError>;
+}
+
+impl<F: Field + PrimeField> BarrelShifter<F> for Vec<Boolean> {
+    fn shift_left_bits<CS: ConstraintSystem<F>>(&self, cs: CS, amount: &[Boolean]) -> Result<Self, SynthesisError> {
+        shift_bits(cs, self, amount, &Boolean::constant(false), true)
+    }
+
+    fn shift_right_bits<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        amount: &[Boolean],
+        fill: &Boolean,
+    ) -> Result<Self, SynthesisError> {
+        shift_bits(cs, self, amount, fill, false)
+    }
+}
+
+fn shift_bits<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+    amount: &[Boolean],
+    fill: &Boolean,
+    left: bool,
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let size = bits.len();
+    let stages = size.trailing_zeros() as usize;
+    let mut result = bits.to_vec();
+
+    // Shift by 2^i when bit i of the amount is set.
+    // Constant amount bits select without adding constraints.
+    for (i, bit) in amount.iter().take(stages).enumerate() {
+        let by = 1 << i;
+        let shifted = (0..size)
+            .map(|j| {
+                let from = if left {
+                    j.checked_sub(by)
+                } else {
+                    Some(j + by).filter(|from| *from < size)
+                };
+
+                from.map(|from| result[from]).unwrap_or(*fill)
+            })
+            .collect::<Vec<_>>();
+
+        result = shifted
+            .iter()
+            .zip(result.iter())
+            .enumerate()
+            .map(|(j, (shifted, same))| {
+                Boolean::conditionally_select(cs.ns(|| format!("shift by {} bit {}", by, j)), bit, shifted, same)
+            })
+            .collect::<Result<_, _>>()?;
+    }
+
+    // Any higher amount bit shifts out every bit.
+    let mut overflow = Boolean::constant(false);
+    for (i, bit) in amount.iter().enumerate().skip(stages) {
+        overflow = Boolean::or(cs.ns(|| format!("overflow bit {}", i)), &overflow, bit)?;
+    }
+
+    result
+        .iter()
+        .enumerate()
+        .map(|(j, bit)| Boolean::conditionally_select(cs.ns(|| format!("fill bit {}", j)), &overflow, fill, bit))
+        .collect()
+}
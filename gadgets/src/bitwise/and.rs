@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_models::{curves::Field, gadgets::r1cs::ConstraintSystem};
+
+/// Returns the bitwise and of `self` & `other` in the constraint system.
+pub trait BitAnd<F: Field, Rhs = Self>
+where
+    Self: std::marker::Sized,
+{
+    type ErrorType;
+
+    #[must_use]
+    fn bit_and<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType>;
+}
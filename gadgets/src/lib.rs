@@ -21,7 +21,11 @@ pub mod arithmetic;
 
 pub mod bits;
 
+pub mod bitwise;
+
 pub mod errors;
 
 pub mod signed_integer;
 pub use self::signed_integer::*;
+
+pub mod unsigned_integer;
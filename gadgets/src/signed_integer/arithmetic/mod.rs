@@ -30,5 +30,8 @@ pub use self::neg::*;
 pub mod pow;
 pub use self::pow::*;
 
+pub mod rem;
+pub use self::rem::*;
+
 pub mod sub;
 pub use self::sub::*;
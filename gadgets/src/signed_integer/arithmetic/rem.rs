@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    arithmetic::{Div, Mul, Rem, Sub},
+    errors::SignedIntegerError,
+    Int,
+    Int128,
+    Int16,
+    Int32,
+    Int64,
+    Int8,
+};
+use snarkos_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+macro_rules! rem_int_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> Rem<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn rem<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                // N % D pseudocode:
+                //
+                // N - (N / D) * D      -- the result takes the sign of N
+
+                if Self::result_is_constant(&self, &other) {
+                    if let (Some(a), Some(b)) = (self.value, other.value) {
+                        if b == 0 {
+                            return Err(SignedIntegerError::DivisionByZero);
+                        }
+
+                        let result = a.checked_rem(b).ok_or(SignedIntegerError::Overflow)?;
+
+                        return Ok(Self::constant(result));
+                    }
+                }
+
+                let quotient = self.div(cs.ns(|| "quotient"), other)?;
+                let product = quotient.mul(cs.ns(|| "product"), other)?;
+
+                self.sub(cs.ns(|| "remainder"), &product)
+            }
+        }
+    )*)
+}
+
+rem_int_impl!(Int8, Int16, Int32, Int64, Int128);
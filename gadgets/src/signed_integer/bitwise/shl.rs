@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bits::BarrelShifter, bitwise::Shl, errors::SignedIntegerError, Int128, Int16, Int32, Int64, Int8};
+
+use snarkos_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+macro_rules! shl_int_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> Shl<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn shl<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                // The shift amount is read as an unsigned integer.
+                let size = self.bits.len() as u128;
+                let value = match (self.value, other.value.map(|b| b as u128)) {
+                    (Some(a), Some(b)) if b < size => Some(a << b),
+                    (Some(_), Some(_)) => Some(0),
+                    _ => None,
+                };
+
+                let bits = self.bits.shift_left_bits(cs, &other.bits)?;
+
+                Ok(Self { bits, value })
+            }
+        }
+    )*)
+}
+
+shl_int_impl!(Int8, Int16, Int32, Int64, Int128);
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bits::BarrelShifter, bitwise::Shr, errors::SignedIntegerError, Int128, Int16, Int32, Int64, Int8};
+
+use snarkos_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+macro_rules! shr_int_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> Shr<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn shr<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                // The shift amount is read as an unsigned integer.
+                let size = self.bits.len() as u128;
+                let value = match (self.value, other.value.map(|b| b as u128)) {
+                    (Some(a), Some(b)) if b < size => Some(a >> b),
+                    (Some(a), Some(_)) => Some(if a < 0 { -1 } else { 0 }),
+                    _ => None,
+                };
+
+                // Arithmetic shift: fill with the sign bit.
+                let fill = self.bits[self.bits.len() - 1].clone();
+
+                let bits = self.bits.shift_right_bits(cs, &other.bits, &fill)?;
+
+                Ok(Self { bits, value })
+            }
+        }
+    )*)
+}
+
+shr_int_impl!(Int8, Int16, Int32, Int64, Int128);
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bitwise::BitXor, errors::SignedIntegerError, Int128, Int16, Int32, Int64, Int8};
+
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+};
+
+macro_rules! bit_xor_int_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> BitXor<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn bit_xor<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some(a ^ b),
+                    _ => None,
+                };
+
+                // Constant bits fold without adding constraints.
+                let bits = self
+                    .bits
+                    .iter()
+                    .zip(other.bits.iter())
+                    .enumerate()
+                    .map(|(i, (a, b))| Boolean::xor(cs.ns(|| format!("xor of bit {}", i)), a, b))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Self { bits, value })
+            }
+        }
+    )*)
+}
+
+bit_xor_int_impl!(Int8, Int16, Int32, Int64, Int128);
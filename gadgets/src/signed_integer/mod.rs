@@ -19,6 +19,9 @@
 pub mod arithmetic;
 pub use self::arithmetic::*;
 
+pub mod bitwise;
+pub use self::bitwise::*;
+
 pub mod int_impl;
 pub use self::int_impl::*;
 
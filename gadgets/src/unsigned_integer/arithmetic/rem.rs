@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::Rem;
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::uint::{UInt, UInt128, UInt16, UInt32, UInt64, UInt8},
+    },
+};
+
+macro_rules! rem_uint_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> Rem<F> for $gadget {
+            type ErrorType = SynthesisError;
+
+            fn rem<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                // N % D pseudocode:
+                //
+                // N - (N / D) * D
+
+                if Self::result_is_constant(&self, &other) {
+                    if let (Some(a), Some(b)) = (self.value, other.value) {
+                        let result = a.checked_rem(b).ok_or(SynthesisError::DivisionByZero)?;
+
+                        return Ok(Self::constant(result));
+                    }
+                }
+
+                let quotient = UInt::div(self, cs.ns(|| "quotient"), other)?;
+                let product = UInt::mul(&quotient, cs.ns(|| "product"), other)?;
+
+                UInt::sub(self, cs.ns(|| "remainder"), &product)
+            }
+        }
+    )*)
+}
+
+rem_uint_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
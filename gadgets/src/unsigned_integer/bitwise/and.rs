@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::bitwise::BitAnd;
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{
+            boolean::Boolean,
+            uint::{UInt128, UInt16, UInt32, UInt64, UInt8},
+        },
+    },
+};
+
+macro_rules! bit_and_uint_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> BitAnd<F> for $gadget {
+            type ErrorType = SynthesisError;
+
+            fn bit_and<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some(a & b),
+                    _ => None,
+                };
+
+                // Constant bits fold without adding constraints.
+                let bits = self
+                    .bits
+                    .iter()
+                    .zip(other.bits.iter())
+                    .enumerate()
+                    .map(|(i, (a, b))| Boolean::and(cs.ns(|| format!("and of bit {}", i)), a, b))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Self {
+                    bits,
+                    negated: false,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+bit_and_uint_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
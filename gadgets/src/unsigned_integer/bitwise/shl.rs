@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bits::BarrelShifter, bitwise::Shl};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::uint::{UInt128, UInt16, UInt32, UInt64, UInt8},
+    },
+};
+
+macro_rules! shl_uint_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> Shl<F> for $gadget {
+            type ErrorType = SynthesisError;
+
+            fn shl<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+                // The shift amount is read as an unsigned integer.
+                let size = self.bits.len() as u128;
+                let value = match (self.value, other.value.map(|b| b as u128)) {
+                    (Some(a), Some(b)) if b < size => Some(a << b),
+                    (Some(_), Some(_)) => Some(0),
+                    _ => None,
+                };
+
+                let bits = self.bits.shift_left_bits(cs, &other.bits)?;
+
+                Ok(Self {
+                    bits,
+                    negated: false,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+shl_uint_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_gadgets::{arithmetic::*, Int128};
+use leo_gadgets::{arithmetic::*, bitwise::*, Int128};
 
 use snarkos_models::{
     curves::{One, Zero},
@@ -358,3 +358,140 @@ fn test_int128_div() {
         check_all_allocated_bits(expected, r);
     }
 }
+
+#[test]
+fn test_int128_rem_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i128 = rng.gen();
+        let b: i128 = rng.gen();
+
+        let expected = match a.checked_rem(b) {
+            Some(valid) => valid,
+            None => continue,
+        };
+
+        let a_bit = Int128::constant(a);
+        let b_bit = Int128::constant(b);
+
+        let r = a_bit.rem(cs.ns(|| "remainder"), &b_bit).unwrap();
+
+        assert!(r.value == Some(expected));
+        assert_eq!(cs.num_constraints(), 0);
+
+        check_all_constant_bits(expected, r);
+    }
+}
+
+#[ignore]
+#[test]
+fn test_int128_rem() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i128 = rng.gen();
+        let b: i128 = rng.gen();
+
+        if a.checked_neg().is_none() {
+            continue;
+        }
+
+        let expected = match a.checked_rem(b) {
+            Some(valid) => valid,
+            None => continue,
+        };
+
+        let a_bit = Int128::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int128::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.rem(cs.ns(|| "remainder"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected));
+    }
+}
+
+#[test]
+fn test_int128_bitwise() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i128 = rng.gen();
+        let b: i128 = rng.gen();
+
+        let a_bit = Int128::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int128::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let and = a_bit.bit_and(cs.ns(|| "and"), &b_bit).unwrap();
+        let or = a_bit.bit_or(cs.ns(|| "or"), &b_bit).unwrap();
+        let xor = a_bit.bit_xor(cs.ns(|| "xor"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        check_all_allocated_bits(a & b, and);
+        check_all_allocated_bits(a | b, or);
+        check_all_allocated_bits(a ^ b, xor);
+    }
+}
+
+#[test]
+fn test_int128_shift_constant_amount() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i128 = rng.gen();
+        let b: i128 = rng.gen_range(0, 128);
+
+        let a_bit = Int128::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int128::constant(b);
+
+        let num_constraints = cs.num_constraints();
+
+        let shl = a_bit.shl(cs.ns(|| "shl"), &b_bit).unwrap();
+        let shr = a_bit.shr(cs.ns(|| "shr"), &b_bit).unwrap();
+
+        // a constant shift amount only rewires bits
+        assert_eq!(cs.num_constraints(), num_constraints);
+        assert!(shl.value == Some(a << b));
+        assert!(shr.value == Some(a >> b));
+    }
+}
+
+#[test]
+fn test_int128_shift() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i128 = rng.gen();
+        let b: i128 = rng.gen_range(0, 128 + 2);
+
+        let expected_shl = a.checked_shl(b as u32).unwrap_or(0);
+        let expected_shr = a.checked_shr(b as u32).unwrap_or(if a < 0 { -1 } else { 0 });
+
+        let a_bit = Int128::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int128::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let shl = a_bit.shl(cs.ns(|| "shl"), &b_bit).unwrap();
+        let shr = a_bit.shr(cs.ns(|| "shr"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(shl.value == Some(expected_shl));
+        assert!(shr.value == Some(expected_shr));
+
+        check_all_allocated_bits(expected_shl, shl);
+        check_all_allocated_bits(expected_shr, shr);
+    }
+}
@@ -137,6 +137,16 @@ impl InputParserError {
         Self::new_from_span(message, span)
     }
 
+    pub fn input_table_section(header: Header) -> Self {
+        let message = format!(
+            "the section header `{}` cannot be declared in a `[[public]]` or `[[private]]` table, only `[main]` inputs can be public or private",
+            header
+        );
+        let span = header.span();
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn public_section(header: Header) -> Self {
         let message = format!("the section header `{}` is not a public section", header);
         let span = header.span();
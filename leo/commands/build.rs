@@ -20,10 +20,11 @@ use crate::{
     errors::CLIError,
     synthesizer::{CircuitSynthesizer, SerializedCircuit},
 };
-use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType, ProfilingConstraintSystem};
+use leo_optimizer::OptLevel;
 use leo_package::{
     inputs::*,
-    outputs::{ChecksumFile, CircuitFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    outputs::{ChecksumFile, CircuitFile, OutputsDirectory, ProfileFile, OUTPUTS_DIRECTORY_NAME},
     root::Manifest,
     source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
@@ -34,27 +35,57 @@ use snarkos_models::gadgets::r1cs::ConstraintSystem;
 use clap::ArgMatches;
 use std::{convert::TryFrom, env::current_dir, time::Instant};
 
+/// The options of `leo build`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuildOptions {
+    pub opt_level: OptLevel,
+    /// Prints the constraints synthesized by each function and source line.
+    pub profile: bool,
+    /// Writes the constraint profile to the outputs directory as folded stacks.
+    pub flamegraph: bool,
+}
+
 #[derive(Debug)]
 pub struct BuildCommand;
 
 impl CLI for BuildCommand {
-    type Options = ();
+    type Options = BuildOptions;
     type Output = Option<(Compiler<Fq, EdwardsGroupType>, bool)>;
 
     const ABOUT: AboutType = "Compile the current package as a program";
     const ARGUMENTS: &'static [ArgumentType] = &[];
-    const FLAGS: &'static [FlagType] = &[];
+    const FLAGS: &'static [FlagType] = &[
+        "[profile] --profile 'Prints the constraints and private variables of each function and source line'",
+        "[flamegraph] --flamegraph 'Writes the constraint profile to the outputs directory as folded stacks for flamegraph tools'",
+    ];
     const NAME: NameType = "build";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[opt-level] --opt-level=[level] 'Optimizes the program before synthesis: 0 (none), 1 (constant folding and branch pruning), 2 (also loop unrolling and inlining)'",
+            &[],
+            &["0", "1", "2"],
+            &[],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let opt_level = arguments
+            .value_of("opt-level")
+            .and_then(OptLevel::from_name)
+            .unwrap_or_default();
+
+        Ok(BuildOptions {
+            opt_level,
+            profile: arguments.is_present("profile"),
+            flamegraph: arguments.is_present("flamegraph"),
+        })
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
         // Begin "Compiling" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Compiling");
         let enter = span.enter();
@@ -119,7 +150,7 @@ impl CLI for BuildCommand {
             tracing::info!("Compiling main program... ({:?})", main_file_path);
 
             // Load the program at `main_file_path`
-            let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
+            let mut program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
                 package_name.clone(),
                 main_file_path.clone(),
                 output_directory,
@@ -129,12 +160,15 @@ impl CLI for BuildCommand {
                 state_path,
             )?;
 
+            // Optimize the program before any constraints are generated
+            program.optimize(options.opt_level);
+
             // Compute the current program checksum
             let program_checksum = program.checksum()?;
 
             // Generate the program on the constraint system and verify correctness
             {
-                let mut cs = CircuitSynthesizer::<Bls12_377> {
+                let cs = CircuitSynthesizer::<Bls12_377> {
                     at: vec![],
                     bt: vec![],
                     ct: vec![],
@@ -142,7 +176,30 @@ impl CLI for BuildCommand {
                     aux_assignment: vec![],
                 };
                 let temporary_program = program.clone();
-                let output = temporary_program.compile_constraints(&mut cs)?;
+
+                let (output, cs) = if options.profile || options.flamegraph {
+                    // Attribute each constraint to the function and line it was synthesized for
+                    let mut cs = ProfilingConstraintSystem::new(cs);
+                    let output = temporary_program.compile_constraints(&mut cs)?;
+                    let (cs, profile) = cs.into_parts();
+
+                    if options.profile {
+                        tracing::info!("Constraint profile\n\n{}\n", profile.to_table());
+                    }
+
+                    if options.flamegraph {
+                        ProfileFile::new(&package_name).write_to(&path, profile.to_folded_stacks())?;
+
+                        tracing::info!("Constraint profile saved ({:?})", path);
+                    }
+
+                    (output, cs)
+                } else {
+                    let mut cs = cs;
+                    let output = temporary_program.compile_constraints(&mut cs)?;
+
+                    (output, cs)
+                };
 
                 tracing::debug!("Compiled constraints - {:#?}", output);
                 tracing::debug!("Number of constraints - {:#?}", cs.num_constraints());
@@ -16,7 +16,7 @@
 
 use crate::{cli::*, cli_types::*, errors::CLIError};
 use leo_package::{
-    outputs::{ChecksumFile, ProofFile, ProvingKeyFile, VerificationKeyFile},
+    outputs::{ChecksumFile, ProfileFile, ProofFile, ProvingKeyFile, PublicInputsFile, VerificationKeyFile},
     root::Manifest,
 };
 
@@ -69,9 +69,15 @@ impl CLI for CleanCommand {
         // Remove the verification key from the output directory
         VerificationKeyFile::new(&package_name).remove(&path)?;
 
+        // Remove the constraint profile from the output directory
+        ProfileFile::new(&package_name).remove(&path)?;
+
         // Remove the proof from the output directory
         ProofFile::new(&package_name).remove(&path)?;
 
+        // Remove the public inputs from the output directory
+        PublicInputsFile::new(&package_name).remove(&path)?;
+
         // Drop "Compiling" context for console logging
         drop(enter);
 
@@ -14,65 +14,82 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{
-    cli::*,
-    cli_types::*,
-    commands::BuildCommand,
-    errors::{CLIError, RunError},
-};
+use crate::{cli::*, cli_types::*, commands::SetupCommand, config::LEO_NODE_PATH, errors::CLIError};
 use leo_package::{
+    deploy::{DeployBackend, DeploymentArtifact, LocalBackend},
+    outputs::{ChecksumFile, VerificationKeyFile},
     root::Manifest,
-    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
 use clap::ArgMatches;
-use std::{convert::TryFrom, env::current_dir};
+use std::{convert::TryFrom, env::current_dir, path::PathBuf};
 
 #[derive(Debug)]
 pub struct DeployCommand;
 
+impl DeployCommand {
+    /// Packages the program of the current package and submits it to the given backend.
+    /// Returns the identifier of the deployment.
+    pub fn deploy<B: DeployBackend>(backend: &mut B) -> Result<String, CLIError> {
+        let path = current_dir()?;
+
+        // Compile the program and generate its keys
+        let (program, _proving_system, _proving_key, _verification_key) = SetupCommand::output(None)?;
+
+        // Begin "Deploy" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Deploying");
+        let _enter = span.enter();
+
+        let manifest = Manifest::try_from(&path)?;
+        let package_name = manifest.get_package_name();
+
+        let artifact = DeploymentArtifact::new(
+            &package_name,
+            &manifest.get_package_version(),
+            &ChecksumFile::new(&package_name).read_from(&path)?,
+            program.to_bytes()?,
+            VerificationKeyFile::new(&package_name).read_from(&path)?,
+        );
+
+        tracing::info!("Submitting {} to {}...", artifact.id(), backend.name());
+
+        let id = backend.deploy(&artifact)?;
+
+        tracing::info!("Program deployed with id: {}", id);
+
+        Ok(id)
+    }
+}
+
 impl CLI for DeployCommand {
-    type Options = ();
-    type Output = ();
+    type Options = Option<PathBuf>;
+    type Output = String;
 
-    const ABOUT: AboutType = "Deploy the current package as a program to the network (*)";
+    const ABOUT: AboutType = "Deploy the current package as a program to a local node";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "deploy";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[node] --node=[directory] 'Deploys to a local node storing programs in the given directory'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.value_of("node").map(PathBuf::from))
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
-        // Begin "Deploy" context for console logging
-        let span = tracing::span!(tracing::Level::INFO, "Deploying");
-        let _enter = span.enter();
-
-        let path = current_dir()?;
+        // There is no network node to deploy to yet, so deploy to a local one
+        let directory = options.unwrap_or_else(|| LEO_NODE_PATH.to_path_buf());
 
-        match BuildCommand::output(options)? {
-            Some((_program, _checksum_differs)) => {
-                // Get the package name
-                let _package_name = Manifest::try_from(&path)?.get_package_name();
-
-                tracing::error!("Unimplemented - `leo deploy`");
-
-                Ok(())
-            }
-            None => {
-                let mut main_file_path = path.clone();
-                main_file_path.push(SOURCE_DIRECTORY_NAME);
-                main_file_path.push(MAIN_FILENAME);
-
-                Err(CLIError::RunError(RunError::MainFileDoesNotExist(
-                    main_file_path.into_os_string(),
-                )))
-            }
-        }
+        Self::deploy(&mut LocalBackend::new(directory))
     }
 }
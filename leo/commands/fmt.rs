@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, FmtError},
+};
+use leo_formatter::LeoFormatter;
+use leo_package::{
+    inputs::{InputsDirectory, INPUTS_DIRECTORY_NAME, INPUT_FILE_EXTENSION, STATE_FILE_EXTENSION},
+    root::Manifest,
+    source::SourceDirectory,
+};
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, fs, time::Instant};
+
+#[derive(Debug)]
+pub struct FmtCommand;
+
+impl CLI for FmtCommand {
+    type Options = bool;
+    type Output = ();
+
+    const ABOUT: AboutType = "Formats the Leo and input files in the package (*)";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &["[check] --check 'Exits with an error if any file is not formatted'"];
+    const NAME: NameType = "fmt";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.is_present("check"))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(check: Self::Options) -> Result<Self::Output, CLIError> {
+        let path = current_dir()?;
+
+        // Verify that the current directory is a package
+        Manifest::try_from(&path)?;
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        // Collect the program files and the input files, if any
+        let program_paths = SourceDirectory::files(&package_path)?;
+        let input_paths = match package_path.join(INPUTS_DIRECTORY_NAME).exists() {
+            true => InputsDirectory::files(&package_path)?
+                .into_iter()
+                .filter(|file_path| {
+                    let file_name = file_path.to_string_lossy();
+                    file_name.ends_with(INPUT_FILE_EXTENSION) || file_name.ends_with(STATE_FILE_EXTENSION)
+                })
+                .collect(),
+            false => vec![],
+        };
+
+        // Begin "Formatting" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Formatting");
+        let enter = span.enter();
+
+        // Start the timer
+        let start = Instant::now();
+
+        let mut changed = 0;
+
+        for (file_path, is_program) in program_paths
+            .iter()
+            .map(|file_path| (file_path, true))
+            .chain(input_paths.iter().map(|file_path| (file_path, false)))
+        {
+            let source =
+                fs::read_to_string(file_path).map_err(|_| FmtError::FileReadError(file_path.clone().into()))?;
+
+            let formatted = match is_program {
+                true => LeoFormatter::format_program(file_path, &source),
+                false => LeoFormatter::format_input(file_path, &source),
+            }
+            .map_err(FmtError::from)?;
+
+            if formatted == source {
+                continue;
+            }
+
+            changed += 1;
+
+            if check {
+                tracing::error!("{:?} is not formatted", file_path);
+            } else {
+                fs::write(file_path, formatted).map_err(FmtError::Writing)?;
+                tracing::info!("Formatted {:?}", file_path);
+            }
+        }
+
+        // Drop "Formatting" context for console logging
+        drop(enter);
+
+        if check && changed > 0 {
+            return Err(FmtError::UnformattedFiles(changed).into());
+        }
+
+        // Begin "Done" context for console logging
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!(
+                "Finished in {} milliseconds, {} file(s) changed\n",
+                start.elapsed().as_millis(),
+                changed
+            );
+        });
+
+        Ok(())
+    }
+}
@@ -17,16 +17,20 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::BuildCommand,
-    errors::{CLIError, RunError},
+    errors::{CLIError, LintError},
 };
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_linter::{LintConfig, Linter};
 use leo_package::{
+    outputs::OUTPUTS_DIRECTORY_NAME,
     root::Manifest,
-    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
+use snarkos_curves::edwards_bls12::Fq;
+
 use clap::ArgMatches;
-use std::{convert::TryFrom, env::current_dir};
+use std::{convert::TryFrom, env::current_dir, time::Instant};
 
 #[derive(Debug)]
 pub struct LintCommand;
@@ -48,31 +52,90 @@ impl CLI for LintCommand {
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+        let path = current_dir()?;
+
+        // Get the package name and the configured lint levels
+        let manifest = Manifest::try_from(&path)?;
+        let package_name = manifest.get_package_name();
+        let config = LintConfig::from_levels(&manifest.get_lint_levels()).map_err(LintError::from)?;
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path.clone();
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        // Construct the path to the output directory
+        let mut output_directory = package_path.clone();
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        // Lint the library file and the main file, if they exist
+        let mut file_names = vec![];
+        if LibraryFile::exists_at(&package_path) {
+            file_names.push(LIBRARY_FILENAME);
+        }
+        if MainFile::exists_at(&package_path) {
+            file_names.push(MAIN_FILENAME);
+        }
+        if file_names.is_empty() {
+            return Err(LintError::ProgramFileDoesNotExist(package_path.into()).into());
+        }
+
         // Begin "Linting" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Linting");
-        let _enter = span.enter();
+        let enter = span.enter();
 
-        let path = current_dir()?;
+        // Start the timer
+        let start = Instant::now();
 
-        match BuildCommand::output(options)? {
-            Some((_program, _checksum_differs)) => {
-                // Get the package name
-                let _package_name = Manifest::try_from(&path)?.get_package_name();
+        let linter = Linter::new(config);
+        let mut warnings = 0;
+        let mut denied = 0;
 
-                tracing::error!("Unimplemented - `leo lint`");
+        for file_name in file_names {
+            let mut file_path = package_path.clone();
+            file_path.push(SOURCE_DIRECTORY_NAME);
+            file_path.push(file_name);
 
-                Ok(())
-            }
-            None => {
-                let mut main_file_path = path.clone();
-                main_file_path.push(SOURCE_DIRECTORY_NAME);
-                main_file_path.push(MAIN_FILENAME);
-
-                Err(CLIError::RunError(RunError::MainFileDoesNotExist(
-                    main_file_path.into_os_string(),
-                )))
+            tracing::info!("Linting {:?}", file_path);
+
+            // Parse and type check the program file
+            let program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
+                package_name.clone(),
+                file_path.clone(),
+                output_directory.clone(),
+            )?;
+
+            for mut lint in linter.lint(program.program()) {
+                lint.set_path(file_path.clone());
+
+                if lint.is_denied() {
+                    denied += 1;
+                    tracing::error!("{}\n", lint);
+                } else {
+                    warnings += 1;
+                    tracing::warn!("{}\n", lint);
+                }
             }
         }
+
+        // Drop "Linting" context for console logging
+        drop(enter);
+
+        if denied > 0 {
+            return Err(LintError::DeniedLints(denied).into());
+        }
+
+        // Begin "Done" context for console logging
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!(
+                "Finished in {} milliseconds with {} warning(s)\n",
+                start.elapsed().as_millis(),
+                warnings
+            );
+        });
+
+        Ok(())
     }
 }
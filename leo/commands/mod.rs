@@ -26,6 +26,9 @@ pub use self::clean::*;
 pub mod deploy;
 pub use self::deploy::*;
 
+pub mod fmt;
+pub use self::fmt::*;
+
 pub mod init;
 pub use self::init::*;
 
@@ -59,5 +62,8 @@ pub use self::remove::*;
 pub mod update;
 pub use self::update::*;
 
+pub mod verify;
+pub use self::verify::*;
+
 pub mod watch;
 pub use self::watch::*;
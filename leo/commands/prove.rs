@@ -14,39 +14,54 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli::*, cli_types::*, commands::SetupCommand, errors::CLIError};
-use leo_package::{outputs::ProofFile, root::Manifest};
-
-use snarkos_algorithms::snark::groth16::{Groth16, PreparedVerifyingKey, Proof};
-use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::SNARK;
+use crate::{
+    cli::*,
+    cli_types::*,
+    commands::{SetupCommand, VerifyCommand},
+    errors::CLIError,
+    proving::{proving_backend, proving_rng, resolve_seed, PROVE_RNG_STREAM},
+};
+use leo_package::{
+    outputs::{ProofFile, PublicInputsFile},
+    root::{Manifest, ProvingSystem},
+};
+
+use snarkos_curves::bls12_377::Fr;
 
 use clap::ArgMatches;
-use rand::thread_rng;
 use std::{convert::TryFrom, env::current_dir, time::Instant};
 
 #[derive(Debug)]
 pub struct ProveCommand;
 
 impl CLI for ProveCommand {
-    type Options = ();
-    type Output = (Proof<Bls12_377>, PreparedVerifyingKey<Bls12_377>);
+    type Options = Option<String>;
+    type Output = (ProvingSystem, Vec<u8>, Vec<u8>, Vec<Fr>);
 
     const ABOUT: AboutType = "Run the program and produce a proof";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "prove";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[seed] --seed=[hex] 'Seeds a deterministic rng with the given hex value, overrides LEO_RNG_SEED'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.value_of("seed").map(String::from))
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
-        let (program, parameters, prepared_verifying_key) = SetupCommand::output(options)?;
+        let seed = resolve_seed(options.clone())?;
+        let (program, proving_system, proving_key, verification_key) = SetupCommand::output(options)?;
 
         // Begin "Proving" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Proving");
@@ -61,16 +76,21 @@ impl CLI for ProveCommand {
         // Start the timer
         let start = Instant::now();
 
-        let rng = &mut thread_rng();
-        let program_proof = Groth16::<Bls12_377, _, Vec<Fr>>::prove(&parameters, program, rng)?;
+        // Compute the public inputs the proof is verified against
+        let public_inputs = VerifyCommand::public_inputs(program.clone())?;
+
+        let rng = &mut proving_rng(seed, PROVE_RNG_STREAM);
+        let proof = proving_backend(proving_system)?.prove(&proving_key, program, rng)?;
 
         // Finish the timer
         let end = start.elapsed().as_millis();
 
         // Write the proof file to the output directory
-        let mut proof = vec![];
-        program_proof.write(&mut proof)?;
-        ProofFile::new(&package_name).write_to(&path, &proof)?;
+        ProofFile::new(&package_name).write_to(&path, &proving_system.write_header(&proof))?;
+
+        // Write the public inputs file to the output directory
+        let public_inputs_bytes = VerifyCommand::public_inputs_to_bytes(&public_inputs)?;
+        PublicInputsFile::new(&package_name).write_to(&path, &public_inputs_bytes)?;
 
         // Drop "Proving" context for console logging
         drop(enter);
@@ -80,6 +100,6 @@ impl CLI for ProveCommand {
             tracing::info!("Finished in {:?} milliseconds\n", end);
         });
 
-        Ok((program_proof, prepared_verifying_key))
+        Ok((proving_system, verification_key, proof, public_inputs))
     }
 }
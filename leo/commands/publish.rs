@@ -17,7 +17,7 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::{BuildCommand, LoginCommand},
+    commands::{BuildCommand, BuildOptions, LoginCommand},
     config::{read_token, PACKAGE_MANAGER_URL},
     errors::{
         commands::PublishError::{ConnectionUnavalaible, PackageNotPublished},
@@ -67,7 +67,7 @@ impl CLI for PublishCommand {
     #[cfg_attr(tarpaulin, skip)]
     fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
         // Build all program files.
-        let _output = BuildCommand::output(())?;
+        let _output = BuildCommand::output(BuildOptions::default())?;
 
         // Begin "Publishing" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Publishing");
@@ -17,8 +17,9 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::BuildCommand,
+    commands::{BuildCommand, BuildOptions},
     errors::{CLIError, RunError},
+    proving::{proving_backend, proving_rng, resolve_seed, SETUP_RNG_STREAM},
 };
 use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
 use leo_package::{
@@ -27,114 +28,121 @@ use leo_package::{
     source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
-use snarkos_algorithms::snark::groth16::{Groth16, Parameters, PreparedVerifyingKey, VerifyingKey};
-use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::snark::SNARK;
+use leo_package::root::ProvingSystem;
+
+use snarkos_curves::bls12_377::Fr;
 
 use clap::ArgMatches;
-use rand::thread_rng;
 use std::{convert::TryFrom, env::current_dir, time::Instant};
 
 #[derive(Debug)]
 pub struct SetupCommand;
 
 impl CLI for SetupCommand {
-    type Options = ();
-    type Output = (
-        Compiler<Fr, EdwardsGroupType>,
-        Parameters<Bls12_377>,
-        PreparedVerifyingKey<Bls12_377>,
-    );
+    type Options = Option<String>;
+    type Output = (Compiler<Fr, EdwardsGroupType>, ProvingSystem, Vec<u8>, Vec<u8>);
 
     const ABOUT: AboutType = "Run a program setup";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "setup";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[seed] --seed=[hex] 'Seeds a deterministic rng with the given hex value, overrides LEO_RNG_SEED'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.value_of("seed").map(String::from))
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
         // Get the package name
         let path = current_dir()?;
-        let package_name = Manifest::try_from(&path)?.get_package_name();
+        let manifest = Manifest::try_from(&path)?;
+        let package_name = manifest.get_package_name();
+        let proving_system = manifest.get_proving_system();
+        let backend = proving_backend(proving_system)?;
+        let seed = resolve_seed(options)?;
 
-        match BuildCommand::output(options)? {
+        match BuildCommand::output(BuildOptions::default())? {
             Some((program, checksum_differs)) => {
                 // Begin "Setup" context for console logging
                 let span = tracing::span!(tracing::Level::INFO, "Setup");
                 let enter = span.enter();
 
                 // Check if a proving key and verification key already exists
-                let keys_exist = ProvingKeyFile::new(&package_name).exists_at(&path)
-                    && VerificationKeyFile::new(&package_name).exists_at(&path);
-
-                // If keys do not exist or the checksum differs, run the program setup
-                // If keys do not exist or the checksum differs, run the program setup
-                let (end, proving_key, prepared_verifying_key) = if !keys_exist || checksum_differs {
-                    tracing::info!("Starting...");
-
-                    // Start the timer for setup
-                    let setup_start = Instant::now();
-
-                    // Run the program setup operation
-                    let rng = &mut thread_rng();
-                    let (proving_key, prepared_verifying_key) =
-                        Groth16::<Bls12_377, Compiler<Fr, _>, Vec<Fr>>::setup(program.clone(), rng).unwrap();
-
-                    // End the timer
-                    let end = setup_start.elapsed().as_millis();
-
-                    // TODO (howardwu): Convert parameters to a 'proving key' struct for serialization.
-                    // Write the proving key file to the output directory
-                    let proving_key_file = ProvingKeyFile::new(&package_name);
-                    tracing::info!("Saving proving key ({:?})", proving_key_file.full_path(&path));
-                    let mut proving_key_bytes = vec![];
-                    proving_key.write(&mut proving_key_bytes)?;
-                    let _ = proving_key_file.write_to(&path, &proving_key_bytes)?;
-                    tracing::info!("Complete");
-
-                    // Write the verification key file to the output directory
-                    let verification_key_file = VerificationKeyFile::new(&package_name);
-                    tracing::info!("Saving verification key ({:?})", verification_key_file.full_path(&path));
-                    let mut verification_key = vec![];
-                    proving_key.vk.write(&mut verification_key)?;
-                    let _ = verification_key_file.write_to(&path, &verification_key)?;
-                    tracing::info!("Complete");
-
-                    (end, proving_key, prepared_verifying_key)
+                let proving_key_file = ProvingKeyFile::new(&package_name);
+                let verification_key_file = VerificationKeyFile::new(&package_name);
+                let keys_exist = proving_key_file.exists_at(&path) && verification_key_file.exists_at(&path);
+
+                // Start the timer for setup
+                let setup_start = Instant::now();
+
+                // Load the saved keys if they were produced by the configured proving system,
+                // a seeded setup always runs so that its keys can be reproduced
+                let saved_keys = if keys_exist && !checksum_differs && seed.is_none() {
+                    let proving_key_bytes = proving_key_file.read_from(&path)?;
+                    let verification_key_bytes = verification_key_file.read_from(&path)?;
+
+                    match (
+                        ProvingSystem::read_header(&proving_key_bytes),
+                        ProvingSystem::read_header(&verification_key_bytes),
+                    ) {
+                        (
+                            Some((proving_key_system, proving_key)),
+                            Some((verification_key_system, verification_key)),
+                        ) if proving_key_system == proving_system && verification_key_system == proving_system => {
+                            Some((proving_key.to_vec(), verification_key.to_vec()))
+                        }
+                        _ => {
+                            tracing::info!("Detected saved setup for another proving system");
+                            None
+                        }
+                    }
                 } else {
-                    tracing::info!("Detected saved setup");
-
-                    // Start the timer for setup
-                    let setup_start = Instant::now();
-
-                    // Read the proving key file from the output directory
-                    tracing::info!("Loading proving key...");
-                    let proving_key_bytes = ProvingKeyFile::new(&package_name).read_from(&path)?;
-                    let proving_key = Parameters::<Bls12_377>::read(proving_key_bytes.as_slice(), true)?;
-                    tracing::info!("Complete");
-
-                    // Read the verification key file from the output directory
-                    tracing::info!("Loading verification key...");
-                    let verifying_key_bytes = VerificationKeyFile::new(&package_name).read_from(&path)?;
-                    let verifying_key = VerifyingKey::<Bls12_377>::read(verifying_key_bytes.as_slice())?;
-
-                    // Derive the prepared verifying key file from the verifying key
-                    let prepared_verifying_key = PreparedVerifyingKey::<Bls12_377>::from(verifying_key);
-                    tracing::info!("Complete");
-
-                    // End the timer
-                    let end = setup_start.elapsed().as_millis();
+                    None
+                };
 
-                    (end, proving_key, prepared_verifying_key)
+                // If keys do not exist, the checksum differs, or the proving system changed, run the program setup
+                let (proving_key, verification_key) = match saved_keys {
+                    Some(keys) => {
+                        tracing::info!("Detected saved setup");
+
+                        keys
+                    }
+                    None => {
+                        tracing::info!("Starting {} setup...", proving_system);
+
+                        // Run the program setup operation
+                        let rng = &mut proving_rng(seed, SETUP_RNG_STREAM);
+                        let (proving_key, verification_key) = backend.setup(program.clone(), rng)?;
+
+                        // Write the proving key file to the output directory
+                        tracing::info!("Saving proving key ({:?})", proving_key_file.full_path(&path));
+                        let _ = proving_key_file.write_to(&path, &proving_system.write_header(&proving_key))?;
+                        tracing::info!("Complete");
+
+                        // Write the verification key file to the output directory
+                        tracing::info!("Saving verification key ({:?})", verification_key_file.full_path(&path));
+                        let _ =
+                            verification_key_file.write_to(&path, &proving_system.write_header(&verification_key))?;
+                        tracing::info!("Complete");
+
+                        (proving_key, verification_key)
+                    }
                 };
 
+                // End the timer
+                let end = setup_start.elapsed().as_millis();
+
                 // Drop "Setup" context for console logging
                 drop(enter);
 
@@ -143,7 +151,7 @@ impl CLI for SetupCommand {
                     tracing::info!("Finished in {:?} milliseconds\n", end);
                 });
 
-                Ok((program, proving_key, prepared_verifying_key))
+                Ok((program, proving_system, proving_key, verification_key))
             }
             None => {
                 let mut main_file_path = path.clone();
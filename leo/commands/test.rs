@@ -18,8 +18,11 @@ use crate::{
     cli::*,
     cli_types::*,
     errors::{CLIError, TestError::ProgramFileDoesNotExist},
+    logger,
+    reporter::TestFormat,
+    updater::Updater,
 };
-use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType, TestOptions};
 use leo_package::{
     inputs::*,
     outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
@@ -36,23 +39,71 @@ use std::{convert::TryFrom, env::current_dir, time::Instant};
 pub struct TestCommand;
 
 impl CLI for TestCommand {
-    type Options = ();
+    type Options = (TestOptions, TestFormat);
     type Output = ();
 
     const ABOUT: AboutType = "Compile and run all tests in the current package";
-    const ARGUMENTS: &'static [ArgumentType] = &[];
-    const FLAGS: &'static [FlagType] = &[];
+    const ARGUMENTS: &'static [ArgumentType] = &[
+        // (name, description, possible_values, required, index)
+        (
+            "FILTER",
+            "Only run tests whose name contains the given filter",
+            &[],
+            false,
+            1u64,
+        ),
+    ];
+    const FLAGS: &'static [FlagType] = &[
+        "[exact] --exact 'Only run the test whose name is exactly the filter'",
+        "[fail-fast] --fail-fast 'Stop running tests after the first failure'",
+    ];
     const NAME: NameType = "test";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[format] --format=[format] 'Writes the test results to stdout in the given format'",
+            &[],
+            &["pretty", "json", "junit"],
+            &[],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn process(arguments: &ArgMatches) -> Result<(), CLIError> {
+        let options = Self::parse(arguments)?;
+
+        // Set logging environment, logging to stderr if stdout is used for the test report
+        let verbosity = if arguments.is_present("debug") { 2 } else { 1 };
+        match options.1.is_machine_readable() {
+            true => logger::init_stderr_logger("leo", verbosity),
+            false => logger::init_logger("leo", verbosity),
+        }
+
+        Updater::print_cli();
+
+        Self::output(options)
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let options = TestOptions {
+            filter: arguments.value_of("FILTER").map(String::from),
+            exact: arguments.is_present("exact"),
+            fail_fast: arguments.is_present("fail-fast"),
+        };
+        let format = arguments
+            .value_of("format")
+            .and_then(TestFormat::from_name)
+            .unwrap_or(TestFormat::Pretty);
+
+        Ok((options, format))
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let (test_options, format) = options;
+
         let path = current_dir()?;
 
         // Get the package name
@@ -103,7 +154,8 @@ impl CLI for TestCommand {
 
         // Run tests
         let temporary_program = program.clone();
-        let (passed, failed) = temporary_program.compile_test_constraints(pairs)?;
+        let report = temporary_program.compile_test_constraints(pairs, &test_options)?;
+        let (passed, failed) = (report.passed(), report.failed());
 
         // Drop "Test" context for console logging
         drop(enter);
@@ -113,24 +165,31 @@ impl CLI for TestCommand {
             // Begin "Done" context for console logging
             tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
                 tracing::info!(
-                    "Tests passed in {} milliseconds. {} passed; {} failed;\n",
+                    "Tests passed in {} milliseconds. {} passed; {} failed; {} filtered out;\n",
                     start.elapsed().as_millis(),
                     passed,
-                    failed
+                    failed,
+                    report.filtered_out
                 );
             });
         } else {
             // Begin "Done" context for console logging
             tracing::span!(tracing::Level::ERROR, "Done").in_scope(|| {
                 tracing::error!(
-                    "Tests failed in {} milliseconds. {} passed; {} failed;\n",
+                    "Tests failed in {} milliseconds. {} passed; {} failed; {} filtered out;\n",
                     start.elapsed().as_millis(),
                     passed,
-                    failed
+                    failed,
+                    report.filtered_out
                 );
             });
         };
 
+        // Write the machine-readable report to stdout
+        if let Some(report) = format.report(&package_name, &report) {
+            println!("{}", report);
+        }
+
         Ok(())
     }
 }
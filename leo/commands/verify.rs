@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, ProvingError, VerifyError},
+    proving::{proving_backend, read_header},
+    synthesizer::CircuitSynthesizer,
+};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::{
+    errors::PublicInputsFileError,
+    inputs::{InputFile, StateFile},
+    outputs::{ProofFile, PublicInputsFile, VerificationKeyFile, OUTPUTS_DIRECTORY_NAME},
+    root::{Manifest, ProvingSystem},
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use snarkos_curves::bls12_377::{Bls12_377, Fr};
+use snarkos_utilities::{FromBytes, ToBytes};
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, path::PathBuf, time::Instant};
+
+#[derive(Debug)]
+pub struct VerifyCommand;
+
+impl VerifyCommand {
+    /// Returns the public inputs of the program, in the order the circuit allocates them.
+    pub fn public_inputs(program: Compiler<Fr, EdwardsGroupType>) -> Result<Vec<Fr>, CLIError> {
+        let mut cs = CircuitSynthesizer::<Bls12_377> {
+            at: vec![],
+            bt: vec![],
+            ct: vec![],
+            input_assignment: vec![],
+            aux_assignment: vec![],
+        };
+        program.compile_constraints(&mut cs)?;
+
+        Ok(cs.input_assignment)
+    }
+
+    /// Serializes the public inputs to bytes, in the order they are allocated.
+    pub fn public_inputs_to_bytes(public_inputs: &Vec<Fr>) -> Result<Vec<u8>, CLIError> {
+        let mut bytes = vec![];
+        for input in public_inputs {
+            input.write(&mut bytes)?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Deserializes public inputs written by `public_inputs_to_bytes`.
+    pub fn public_inputs_from_bytes(mut bytes: &[u8]) -> Result<Vec<Fr>, CLIError> {
+        let mut public_inputs = vec![];
+        while !bytes.is_empty() {
+            let input = Fr::read(&mut bytes).map_err(|_| PublicInputsFileError::IncorrectPublicInputs)?;
+            public_inputs.push(input);
+        }
+
+        Ok(public_inputs)
+    }
+
+    /// Loads the public inputs saved by the prover, or computes them from the input files.
+    fn load_public_inputs(path: &PathBuf, package_name: String, main_file_path: PathBuf) -> Result<Vec<Fr>, CLIError> {
+        let public_inputs_file = PublicInputsFile::new(&package_name);
+        if public_inputs_file.exists_at(path) {
+            return Self::public_inputs_from_bytes(&public_inputs_file.read_from(path)?);
+        }
+
+        let (input_string, input_path) = InputFile::new(&package_name).read_from(path)?;
+        let (state_string, state_path) = StateFile::new(&package_name).read_from(path)?;
+
+        let program = Compiler::<Fr, EdwardsGroupType>::parse_program_with_input(
+            package_name,
+            main_file_path,
+            path.join(OUTPUTS_DIRECTORY_NAME),
+            &input_string,
+            input_path,
+            &state_string,
+            state_path,
+        )?;
+
+        Self::public_inputs(program)
+    }
+
+    /// Checks the proof against the public inputs with the given proving system,
+    /// returning an error if it is invalid.
+    pub fn verify(
+        proving_system: ProvingSystem,
+        verification_key: &[u8],
+        public_inputs: &[Fr],
+        proof: &[u8],
+    ) -> Result<(), CLIError> {
+        // Begin "Verifying" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Verifying");
+        let enter = span.enter();
+
+        tracing::info!("Starting...");
+
+        // Start the timer
+        let start = Instant::now();
+
+        // Run the verifier
+        let is_success = proving_backend(proving_system)?.verify(verification_key, public_inputs, proof)?;
+
+        // End the timer
+        let end = start.elapsed().as_millis();
+
+        // Log the verifier output
+        if !is_success {
+            return Err(VerifyError::InvalidProof.into());
+        }
+        tracing::info!("Proof is valid");
+
+        // Drop "Verifying" context for console logging
+        drop(enter);
+
+        // Begin "Done" context for console logging
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Finished in {:?} milliseconds\n", end);
+        });
+
+        Ok(())
+    }
+}
+
+impl CLI for VerifyCommand {
+    type Options = ();
+    type Output = ();
+
+    const ABOUT: AboutType = "Verify the saved proof of the program";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "verify";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(())
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+        let path = current_dir()?;
+        let package_name = Manifest::try_from(&path)?.get_package_name();
+
+        // Construct the path to the main file in the source directory
+        let mut main_file_path = path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        if !MainFile::exists_at(&path) {
+            return Err(VerifyError::MainFileDoesNotExist(main_file_path.into_os_string()).into());
+        }
+
+        // Load the proof and the verification key from the output directory
+        let proof_file = ProofFile::new(&package_name);
+        if !proof_file.exists_at(&path) {
+            let proof_file_path = proof_file.full_path(&path);
+            return Err(VerifyError::ProofFileDoesNotExist(proof_file_path.into_os_string()).into());
+        }
+
+        let verification_key_file = VerificationKeyFile::new(&package_name);
+        if !verification_key_file.exists_at(&path) {
+            let verification_key_path = verification_key_file.full_path(&path);
+            return Err(VerifyError::VerificationKeyFileDoesNotExist(verification_key_path.into_os_string()).into());
+        }
+
+        let proof_bytes = proof_file.read_from(&path)?;
+        let verification_key_bytes = verification_key_file.read_from(&path)?;
+
+        // Check that the proof and the verification key were produced by the same proving system
+        let (proof_system, proof) = read_header("proof file", &proof_bytes)?;
+        let (verification_key_system, verification_key) =
+            read_header("verification key file", &verification_key_bytes)?;
+        if proof_system != verification_key_system {
+            return Err(ProvingError::MismatchedFiles(
+                "proof file",
+                proof_system,
+                "verification key file",
+                verification_key_system,
+            )
+            .into());
+        }
+
+        // Load the public input values, without running the setup or the prover
+        let public_inputs = Self::load_public_inputs(&path, package_name, main_file_path)?;
+
+        Self::verify(proof_system, verification_key, &public_inputs, proof)
+    }
+}
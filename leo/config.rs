@@ -30,6 +30,7 @@ pub const PACKAGE_MANAGER_URL: &str = "https://api.aleo.pm/";
 
 pub const LEO_CREDENTIALS_FILE: &str = "credentials";
 pub const LEO_CONFIG_FILE: &str = "config.toml";
+pub const LEO_NODE_DIRECTORY: &str = "node";
 
 lazy_static! {
     pub static ref LEO_CONFIG_DIRECTORY: PathBuf = {
@@ -47,6 +48,11 @@ lazy_static! {
         path.push(LEO_CONFIG_FILE);
         path
     };
+    pub static ref LEO_NODE_PATH: PathBuf = {
+        let mut path = LEO_CONFIG_DIRECTORY.to_path_buf();
+        path.push(LEO_NODE_DIRECTORY);
+        path
+    };
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -38,6 +38,12 @@ pub enum CLIError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}", _0)]
+    DeployError(DeployError),
+
+    #[error("{}", _0)]
+    FmtError(FmtError),
+
     #[error("{}", _0)]
     GitignoreError(GitignoreError),
 
@@ -56,6 +62,9 @@ pub enum CLIError {
     #[error("{}", _0)]
     LibraryFileError(LibraryFileError),
 
+    #[error("{}", _0)]
+    LintError(LintError),
+
     #[error("{}", _0)]
     LoginError(LoginError),
 
@@ -77,12 +86,21 @@ pub enum CLIError {
     #[error("{}", _0)]
     PackageError(PackageError),
 
+    #[error("{}", _0)]
+    ProfileFileError(ProfileFileError),
+
     #[error("{}", _0)]
     ProofFileError(ProofFileError),
 
+    #[error("{}", _0)]
+    ProvingError(ProvingError),
+
     #[error("{}", _0)]
     ProvingKeyFileError(ProvingKeyFileError),
 
+    #[error("{}", _0)]
+    PublicInputsFileError(PublicInputsFileError),
+
     #[error("{}", _0)]
     PublishError(PublishError),
 
@@ -112,6 +130,9 @@ pub enum CLIError {
 
     #[error("{}", _0)]
     VerificationKeyFileError(VerificationKeyFileError),
+
+    #[error("{}", _0)]
+    VerifyError(VerifyError),
 }
 
 macro_rules! impl_cli_error {
@@ -131,12 +152,15 @@ impl_cli_error!(
     BuildError,
     CircuitFileError,
     ChecksumFileError,
+    DeployError,
+    FmtError,
     GitignoreError,
     ImportsDirectoryError,
     InitError,
     InputsDirectoryError,
     InputFileError,
     LibraryFileError,
+    LintError,
     LoginError,
     MainFileError,
     ManifestError,
@@ -144,8 +168,11 @@ impl_cli_error!(
     OutputFileError,
     OutputsDirectoryError,
     PackageError,
+    ProfileFileError,
     ProofFileError,
+    ProvingError,
     ProvingKeyFileError,
+    PublicInputsFileError,
     PublishError,
     READMEError,
     RunError,
@@ -153,6 +180,7 @@ impl_cli_error!(
     StateFileError,
     TestError,
     VerificationKeyFileError,
+    VerifyError,
     ZipFileError
 );
 
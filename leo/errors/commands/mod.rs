@@ -20,9 +20,15 @@ pub use self::add::*;
 pub mod build;
 pub use self::build::*;
 
+pub mod fmt;
+pub use self::fmt::*;
+
 pub mod init;
 pub use self::init::*;
 
+pub mod lint;
+pub use self::lint::*;
+
 pub mod login;
 pub use self::login::*;
 
@@ -37,3 +43,6 @@ pub use self::run::*;
 
 pub mod test;
 pub use self::test::*;
+
+pub mod verify;
+pub use self::verify::*;
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_package::errors::ManifestError;
+
+use std::ffi::OsString;
+
+#[derive(Debug, Error)]
+pub enum VerifyError {
+    #[error("proof is invalid")]
+    InvalidProof,
+
+    #[error("main file {:?} does not exist", _0)]
+    MainFileDoesNotExist(OsString),
+
+    #[error("{}", _0)]
+    ManifestError(#[from] ManifestError),
+
+    #[error("proof file {:?} does not exist, run `leo prove` to create it", _0)]
+    ProofFileDoesNotExist(OsString),
+
+    #[error("verification key file {:?} does not exist, run `leo setup` to create it", _0)]
+    VerificationKeyFileDoesNotExist(OsString),
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_package::root::ProvingSystem;
+
+#[derive(Debug, Error)]
+pub enum ProvingError {
+    #[error("invalid seed `{}`, expected a hex value of at most 32 bytes", _0)]
+    InvalidSeed(String),
+
+    #[error("the {} was produced by `{}`, but the {} was produced by `{}`", _0, _1, _2, _3)]
+    MismatchedFiles(&'static str, ProvingSystem, &'static str, ProvingSystem),
+
+    #[error(
+        "the {} was not produced by a known proving system, run `leo clean` and try again",
+        _0
+    )]
+    MissingHeader(&'static str),
+
+    #[error(
+        "the `{}` proving system is not supported yet, use `groth16` or `gm17` in the `[proving]` section",
+        _0
+    )]
+    UnsupportedSystem(ProvingSystem),
+}
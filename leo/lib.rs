@@ -24,5 +24,7 @@ pub mod commands;
 pub mod config;
 pub mod errors;
 pub mod logger;
+pub mod proving;
+pub mod reporter;
 pub mod synthesizer;
 pub mod updater;
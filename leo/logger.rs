@@ -18,7 +18,7 @@ use colored::Colorize;
 use std::fmt;
 use tracing::{event::Event, subscriber::Subscriber};
 use tracing_subscriber::{
-    fmt::{format::*, time::*, FmtContext, FormattedFields},
+    fmt::{format::*, time::*, FmtContext, FormattedFields, MakeWriter},
     registry::LookupSpan,
     FmtSubscriber,
 };
@@ -195,16 +195,30 @@ where
 }
 
 /// Initialize logger with custom format and verbosity.
-pub fn init_logger(_app_name: &'static str, verbosity: usize) {
+pub fn init_logger(app_name: &'static str, verbosity: usize) {
+    init_logger_with_writer(app_name, verbosity, std::io::stdout)
+}
+
+/// Initializes the logger writing to stderr, to keep stdout free for machine-readable output.
+pub fn init_stderr_logger(app_name: &'static str, verbosity: usize) {
+    init_logger_with_writer(app_name, verbosity, std::io::stderr)
+}
+
+fn init_logger_with_writer<W: MakeWriter + Send + Sync + 'static>(
+    _app_name: &'static str,
+    verbosity: usize,
+    writer: W,
+) {
     let subscriber = FmtSubscriber::builder()
         // all spans/events with a level higher than TRACE (e.g, debug, info, warn, etc.)
-        // will be written to stdout.
+        // will be written to the writer.
         .with_max_level(match verbosity {
             0 => tracing::Level::WARN,
             1 => tracing::Level::INFO,
             2 => tracing::Level::DEBUG,
             _ => tracing::Level::TRACE
         })
+        .with_writer(writer)
         .without_time()
         .with_target(false)
         .event_format(Format::default())
@@ -44,14 +44,16 @@ fn main() -> Result<(), CLIError> {
             SetupCommand::new().display_order(5),
             ProveCommand::new().display_order(6),
             RunCommand::new().display_order(7),
-            LoginCommand::new().display_order(8),
-            AddCommand::new().display_order(9),
-            RemoveCommand::new().display_order(10),
-            PublishCommand::new().display_order(11),
-            DeployCommand::new().display_order(12),
-            CleanCommand::new().display_order(13),
-            LintCommand::new().display_order(14),
-            UpdateCommand::new().display_order(15),
+            VerifyCommand::new().display_order(8),
+            LoginCommand::new().display_order(9),
+            AddCommand::new().display_order(10),
+            RemoveCommand::new().display_order(11),
+            PublishCommand::new().display_order(12),
+            DeployCommand::new().display_order(13),
+            CleanCommand::new().display_order(14),
+            LintCommand::new().display_order(15),
+            FmtCommand::new().display_order(16),
+            UpdateCommand::new().display_order(17),
         ])
         .set_term_width(0);
 
@@ -67,6 +69,7 @@ fn main() -> Result<(), CLIError> {
         ("setup", Some(arguments)) => SetupCommand::process(arguments),
         ("prove", Some(arguments)) => ProveCommand::process(arguments),
         ("run", Some(arguments)) => RunCommand::process(arguments),
+        ("verify", Some(arguments)) => VerifyCommand::process(arguments),
         ("login", Some(arguments)) => LoginCommand::process(arguments),
         ("add", Some(arguments)) => AddCommand::process(arguments),
         ("remove", Some(arguments)) => RemoveCommand::process(arguments),
@@ -74,6 +77,7 @@ fn main() -> Result<(), CLIError> {
         ("deploy", Some(arguments)) => DeployCommand::process(arguments),
         ("clean", Some(arguments)) => CleanCommand::process(arguments),
         ("lint", Some(arguments)) => LintCommand::process(arguments),
+        ("fmt", Some(arguments)) => FmtCommand::process(arguments),
         ("update", Some(arguments)) => UpdateCommand::process(arguments),
         _ => {
             // Set logging environment
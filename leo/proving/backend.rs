@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::CLIError;
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::root::ProvingSystem;
+
+use snarkos_curves::bls12_377::Fr;
+
+use rand_core::RngCore;
+
+/// A proving system that sets up, proves and verifies compiled Leo programs.
+///
+/// Keys and proofs are passed around serialized, without the proving system header.
+pub trait ProvingBackend {
+    /// Returns the proving system implemented by this backend.
+    fn system(&self) -> ProvingSystem;
+
+    /// Runs the setup for the program, returning the proving key and the verification key.
+    fn setup(
+        &self,
+        program: Compiler<Fr, EdwardsGroupType>,
+        rng: &mut dyn RngCore,
+    ) -> Result<(Vec<u8>, Vec<u8>), CLIError>;
+
+    /// Returns a proof of the program using the given proving key.
+    fn prove(
+        &self,
+        proving_key: &[u8],
+        program: Compiler<Fr, EdwardsGroupType>,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<u8>, CLIError>;
+
+    /// Returns `true` if the proof is valid for the verification key and public inputs.
+    fn verify(&self, verification_key: &[u8], public_inputs: &[Fr], proof: &[u8]) -> Result<bool, CLIError>;
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod backend;
+pub use self::backend::*;
+
+pub mod rng;
+pub use self::rng::*;
+
+pub mod snark;
+pub use self::snark::*;
+
+use crate::errors::{CLIError, ProvingError};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::root::ProvingSystem;
+
+use snarkos_algorithms::snark::{gm17::GM17, groth16::Groth16};
+use snarkos_curves::bls12_377::{Bls12_377, Fr};
+
+/// Returns the backend implementing the given proving system.
+pub fn proving_backend(system: ProvingSystem) -> Result<Box<dyn ProvingBackend>, ProvingError> {
+    match system {
+        ProvingSystem::Groth16 => Ok(Box::new(SNARKBackend::<
+            Groth16<Bls12_377, Compiler<Fr, EdwardsGroupType>, Vec<Fr>>,
+        >::new(system))),
+        ProvingSystem::GM17 => Ok(Box::new(SNARKBackend::<
+            GM17<Bls12_377, Compiler<Fr, EdwardsGroupType>, Vec<Fr>>,
+        >::new(system))),
+        ProvingSystem::Marlin => Err(ProvingError::UnsupportedSystem(system)),
+    }
+}
+
+/// Splits the proving system header from the bytes of the given key or proof file.
+pub fn read_header<'a>(file: &'static str, bytes: &'a [u8]) -> Result<(ProvingSystem, &'a [u8]), CLIError> {
+    Ok(ProvingSystem::read_header(bytes).ok_or(ProvingError::MissingHeader(file))?)
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ProvingError;
+
+use rand::{thread_rng, RngCore, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::env;
+
+/// The environment variable holding the hex seed used when `--seed` is not given.
+pub const LEO_RNG_SEED: &str = "LEO_RNG_SEED";
+
+/// The ChaCha stream used to run the setup.
+pub const SETUP_RNG_STREAM: u64 = 0;
+
+/// The ChaCha stream used to produce proofs.
+pub const PROVE_RNG_STREAM: u64 = 1;
+
+/// Parses a seed of up to 32 bytes from a hex string, padding it with zeros.
+pub fn parse_seed(seed: &str) -> Result<[u8; 32], ProvingError> {
+    let bytes = hex::decode(seed.trim_start_matches("0x")).map_err(|_| ProvingError::InvalidSeed(seed.to_string()))?;
+    if bytes.is_empty() || bytes.len() > 32 {
+        return Err(ProvingError::InvalidSeed(seed.to_string()));
+    }
+
+    let mut output = [0u8; 32];
+    output[..bytes.len()].copy_from_slice(&bytes);
+
+    Ok(output)
+}
+
+/// Returns the seed given with `--seed`, or the one in `LEO_RNG_SEED` if it is set.
+pub fn resolve_seed(seed: Option<String>) -> Result<Option<[u8; 32]>, ProvingError> {
+    match seed.or_else(|| env::var(LEO_RNG_SEED).ok()) {
+        Some(seed) => Ok(Some(parse_seed(&seed)?)),
+        None => Ok(None),
+    }
+}
+
+/// Returns a ChaCha rng seeded with `seed` on the given stream, or the thread rng if there is no seed.
+pub fn proving_rng(seed: Option<[u8; 32]>, stream: u64) -> Box<dyn RngCore> {
+    match seed {
+        Some(seed) => {
+            tracing::warn!("Using a seeded rng, the output must not be used in production");
+
+            let mut rng = ChaChaRng::from_seed(seed);
+            rng.set_stream(stream);
+
+            Box::new(rng)
+        }
+        None => Box::new(thread_rng()),
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::CLIError, proving::ProvingBackend};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::root::ProvingSystem;
+
+use snarkos_curves::bls12_377::Fr;
+use snarkos_models::algorithms::SNARK;
+use snarkos_utilities::{FromBytes, ToBytes};
+
+use rand_core::RngCore;
+use std::marker::PhantomData;
+
+/// A proving backend for any SNARK from snarkOS that proves compiled Leo programs.
+pub struct SNARKBackend<S: SNARK> {
+    system: ProvingSystem,
+    _snark: PhantomData<S>,
+}
+
+impl<S: SNARK> SNARKBackend<S> {
+    pub fn new(system: ProvingSystem) -> Self {
+        Self {
+            system,
+            _snark: PhantomData,
+        }
+    }
+}
+
+impl<S> ProvingBackend for SNARKBackend<S>
+where
+    S: SNARK<
+        Circuit = Compiler<Fr, EdwardsGroupType>,
+        AssignedCircuit = Compiler<Fr, EdwardsGroupType>,
+        VerifierInput = Vec<Fr>,
+    >,
+{
+    fn system(&self) -> ProvingSystem {
+        self.system
+    }
+
+    fn setup(
+        &self,
+        program: Compiler<Fr, EdwardsGroupType>,
+        mut rng: &mut dyn RngCore,
+    ) -> Result<(Vec<u8>, Vec<u8>), CLIError> {
+        let (proving_key, prepared_verifying_key) = S::setup(program, &mut rng)?;
+        let verifying_key = S::VerificationParameters::from(prepared_verifying_key);
+
+        let mut proving_key_bytes = vec![];
+        proving_key.write(&mut proving_key_bytes)?;
+
+        let mut verifying_key_bytes = vec![];
+        verifying_key.write(&mut verifying_key_bytes)?;
+
+        Ok((proving_key_bytes, verifying_key_bytes))
+    }
+
+    fn prove(
+        &self,
+        proving_key: &[u8],
+        program: Compiler<Fr, EdwardsGroupType>,
+        mut rng: &mut dyn RngCore,
+    ) -> Result<Vec<u8>, CLIError> {
+        let proving_key = S::ProvingParameters::read(proving_key)?;
+        let proof = S::prove(&proving_key, program, &mut rng)?;
+
+        let mut proof_bytes = vec![];
+        proof.write(&mut proof_bytes)?;
+
+        Ok(proof_bytes)
+    }
+
+    fn verify(&self, verification_key: &[u8], public_inputs: &[Fr], proof: &[u8]) -> Result<bool, CLIError> {
+        let verifying_key = S::VerificationParameters::read(verification_key)?;
+        let prepared_verifying_key = S::PreparedVerificationParameters::from(verifying_key);
+        let proof = S::Proof::read(proof)?;
+
+        Ok(S::verify(&prepared_verifying_key, &public_inputs.to_vec(), &proof)?)
+    }
+}
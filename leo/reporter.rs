@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Machine-readable reports of `leo test` results.
+
+use leo_compiler::{TestReport, TestStatus};
+
+use serde_json::json;
+use std::time::Duration;
+
+/// The format `leo test` reports its results in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TestFormat {
+    Pretty,
+    Json,
+    JUnit,
+}
+
+impl TestFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "pretty" => Some(TestFormat::Pretty),
+            "json" => Some(TestFormat::Json),
+            "junit" => Some(TestFormat::JUnit),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the report is written to stdout instead of the log.
+    pub fn is_machine_readable(&self) -> bool {
+        *self != TestFormat::Pretty
+    }
+
+    /// Returns the report in this format, or `None` for the pretty format that is logged as tests run.
+    pub fn report(&self, package_name: &str, report: &TestReport) -> Option<String> {
+        match self {
+            TestFormat::Pretty => None,
+            TestFormat::Json => Some(json_report(package_name, report)),
+            TestFormat::JUnit => Some(junit_report(package_name, report)),
+        }
+    }
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Returns the test report as a JSON document.
+pub fn json_report(package_name: &str, report: &TestReport) -> String {
+    let tests = report
+        .results
+        .iter()
+        .map(|result| {
+            let (status, message) = match &result.status {
+                TestStatus::Passed => ("passed", None),
+                TestStatus::Failed(message) => ("failed", Some(message.clone())),
+            };
+
+            json!({
+                "name": result.name,
+                "status": status,
+                "message": message,
+                "constraints": result.num_constraints,
+                "duration_ms": milliseconds(result.duration),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let document = json!({
+        "package": package_name,
+        "passed": report.passed(),
+        "failed": report.failed(),
+        "filtered_out": report.filtered_out,
+        "duration_ms": milliseconds(report.duration()),
+        "tests": tests,
+    });
+
+    serde_json::to_string_pretty(&document).unwrap()
+}
+
+/// Returns the test report as a JUnit XML document.
+pub fn junit_report(package_name: &str, report: &TestReport) -> String {
+    let package_name = escape_xml(package_name);
+    let time = report.duration().as_secs_f64();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml += &format!(
+        "<testsuites tests=\"{}\" failures=\"{}\" time=\"{:.6}\">\n",
+        report.results.len(),
+        report.failed(),
+        time
+    );
+    xml += &format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{:.6}\">\n",
+        package_name,
+        report.results.len(),
+        report.failed(),
+        time
+    );
+
+    for result in &report.results {
+        xml += &format!(
+            "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\">\n",
+            escape_xml(&result.name),
+            package_name,
+            result.duration.as_secs_f64()
+        );
+        if let TestStatus::Failed(message) = &result.status {
+            let message = escape_xml(message);
+            xml += &format!("      <failure message=\"{}\">{}</failure>\n", message, message);
+        }
+        xml += &format!(
+            "      <system-out>constraints: {}</system-out>\n",
+            result.num_constraints
+        );
+        xml += "    </testcase>\n";
+    }
+
+    xml += "  </testsuite>\n";
+    xml += "</testsuites>\n";
+    xml
+}
+
+fn escape_xml(string: &str) -> String {
+    string
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_typed::Identifier;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingKind {
+    /// A function input such as `a` in `function main(a: u32)`.
+    Input,
+    /// A variable introduced by a `let` or `const` statement.
+    Local,
+    /// The index variable of a `for` loop.
+    LoopVariable,
+}
+
+/// A variable defined in a function body, along with how often it is read and assigned to.
+#[derive(Clone, Debug)]
+pub struct Binding {
+    pub identifier: Identifier,
+    pub kind: BindingKind,
+    pub mutable: bool,
+    /// `true` if a variable with the same name was already in scope when this one was defined.
+    pub shadows: bool,
+    pub reads: usize,
+    pub writes: usize,
+}
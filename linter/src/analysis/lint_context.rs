@@ -0,0 +1,358 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Binding, BindingKind};
+use leo_typed::{
+    Assignee,
+    CircuitMember,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    Expression,
+    Function,
+    Identifier,
+    InputVariable,
+    Program,
+    RangeOrExpression,
+    SpreadOrExpression,
+    Statement,
+    Type,
+};
+
+use std::collections::{HashMap, HashSet};
+
+/// Facts about a program shared by all lint rules.
+pub struct LintContext<'a> {
+    pub program: &'a Program,
+    /// Every variable defined in a function body of the program.
+    pub bindings: Vec<Binding>,
+    /// Names that are used but not defined inside a function, such as functions, circuits and imports.
+    pub references: HashSet<String>,
+    /// Names of circuit members that are accessed with `.` or `::` syntax.
+    pub member_accesses: HashSet<String>,
+}
+
+impl<'a> LintContext<'a> {
+    pub fn new(program: &'a Program) -> Self {
+        let mut builder = ContextBuilder::default();
+
+        for circuit in program.circuits.values() {
+            for member in circuit.members.iter() {
+                match member {
+                    CircuitMember::CircuitVariable(_, _, type_) => builder.visit_type(type_),
+                    CircuitMember::CircuitFunction(_, function) => builder.visit_function(function),
+                }
+            }
+        }
+
+        for function in program.functions.values() {
+            builder.visit_function(function);
+        }
+
+        for test in program.tests.values() {
+            builder.visit_function(&test.function);
+        }
+
+        Self {
+            program,
+            bindings: builder.bindings,
+            references: builder.references,
+            member_accesses: builder.member_accesses,
+        }
+    }
+
+    /// Returns every function of the program, including circuit functions and tests.
+    pub fn functions(&self) -> Vec<&'a Function> {
+        let circuit_functions = self.program.circuits.values().flat_map(|circuit| {
+            circuit.members.iter().filter_map(|member| match member {
+                CircuitMember::CircuitFunction(_, function) => Some(function),
+                CircuitMember::CircuitVariable(..) => None,
+            })
+        });
+
+        circuit_functions
+            .chain(self.program.functions.values())
+            .chain(self.program.tests.values().map(|test| &test.function))
+            .collect()
+    }
+}
+
+/// Walks function bodies while tracking which variables are in scope.
+#[derive(Default)]
+struct ContextBuilder {
+    bindings: Vec<Binding>,
+    scopes: Vec<HashMap<String, usize>>,
+    references: HashSet<String>,
+    member_accesses: HashSet<String>,
+}
+
+impl ContextBuilder {
+    fn define(&mut self, identifier: &Identifier, kind: BindingKind, mutable: bool) {
+        let shadows = self.lookup(&identifier.name).is_some();
+
+        self.bindings.push(Binding {
+            identifier: identifier.clone(),
+            kind,
+            mutable,
+            shadows,
+            reads: 0,
+            writes: 0,
+        });
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(identifier.name.clone(), self.bindings.len() - 1);
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<usize> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name).copied())
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.scopes.push(HashMap::new());
+
+        for input in function.input.iter() {
+            if let InputVariable::FunctionInput(input) = input {
+                self.visit_type(&input.type_);
+                self.define(&input.identifier, BindingKind::Input, input.mutable);
+            }
+        }
+
+        if let Some(type_) = &function.returns {
+            self.visit_type(type_);
+        }
+
+        for statement in function.statements.iter() {
+            self.visit_statement(statement);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn visit_block(&mut self, statements: &[Statement]) {
+        self.scopes.push(HashMap::new());
+
+        for statement in statements {
+            self.visit_statement(statement);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn visit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Return(expression, _) => self.visit_expression(expression),
+            Statement::Definition(_, variables, expressions, _) => {
+                for expression in expressions {
+                    self.visit_expression(expression);
+                }
+
+                if let Some(type_) = &variables.type_ {
+                    self.visit_type(type_);
+                }
+
+                for variable in variables.names.iter() {
+                    self.define(&variable.identifier, BindingKind::Local, variable.mutable);
+                }
+            }
+            Statement::Assign(assignee, expression, _) => {
+                self.visit_assignee(assignee);
+                self.visit_expression(expression);
+            }
+            Statement::Conditional(conditional, _) => self.visit_conditional(conditional),
+            Statement::Iteration(index, start, stop, statements, _) => {
+                self.visit_expression(start);
+                self.visit_expression(stop);
+
+                self.scopes.push(HashMap::new());
+                self.define(index, BindingKind::LoopVariable, false);
+
+                for statement in statements {
+                    self.visit_statement(statement);
+                }
+
+                self.scopes.pop();
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => self.visit_expression(expression),
+                ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                    for parameter in string.parameters.iter() {
+                        self.visit_expression(&parameter.expression);
+                    }
+                }
+            },
+            Statement::Expression(expression, _) => self.visit_expression(expression),
+        }
+    }
+
+    fn visit_conditional(&mut self, conditional: &ConditionalStatement) {
+        self.visit_expression(&conditional.condition);
+        self.visit_block(&conditional.statements);
+
+        match &conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => self.visit_conditional(nested),
+            Some(ConditionalNestedOrEndStatement::End(statements)) => self.visit_block(statements),
+            None => {}
+        }
+    }
+
+    fn visit_assignee(&mut self, assignee: &Assignee) {
+        match assignee {
+            Assignee::Identifier(identifier) => {
+                if let Some(index) = self.lookup(&identifier.name) {
+                    self.bindings[index].writes += 1;
+                }
+            }
+            Assignee::Array(array, range) => {
+                self.visit_assignee(array);
+                self.visit_range(range);
+            }
+            Assignee::Tuple(tuple, _) => self.visit_assignee(tuple),
+            Assignee::CircuitField(circuit, _) => self.visit_assignee(circuit),
+        }
+    }
+
+    fn visit_range(&mut self, range: &RangeOrExpression) {
+        match range {
+            RangeOrExpression::Range(from, to) => {
+                for expression in from.iter().chain(to.iter()) {
+                    self.visit_expression(expression);
+                }
+            }
+            RangeOrExpression::Expression(expression) => self.visit_expression(expression),
+        }
+    }
+
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Array(type_, _) => self.visit_type(type_),
+            Type::Tuple(types) => {
+                for type_ in types {
+                    self.visit_type(type_);
+                }
+            }
+            Type::Circuit(identifier) => {
+                self.references.insert(identifier.name.clone());
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(identifier) => match self.lookup(&identifier.name) {
+                Some(index) => self.bindings[index].reads += 1,
+                None => {
+                    self.references.insert(identifier.name.clone());
+                }
+            },
+
+            Expression::Address(..)
+            | Expression::Boolean(..)
+            | Expression::Field(..)
+            | Expression::Group(..)
+            | Expression::Implicit(..)
+            | Expression::Integer(..) => {}
+
+            Expression::Add(left, right, _)
+            | Expression::Sub(left, right, _)
+            | Expression::Mul(left, right, _)
+            | Expression::Div(left, right, _)
+            | Expression::Pow(left, right, _)
+            | Expression::Rem(left, right, _)
+            | Expression::BitAnd(left, right, _)
+            | Expression::BitOr(left, right, _)
+            | Expression::BitXor(left, right, _)
+            | Expression::Shl(left, right, _)
+            | Expression::Shr(left, right, _)
+            | Expression::Or(left, right, _)
+            | Expression::And(left, right, _)
+            | Expression::Eq(left, right, _)
+            | Expression::Ge(left, right, _)
+            | Expression::Gt(left, right, _)
+            | Expression::Le(left, right, _)
+            | Expression::Lt(left, right, _) => {
+                self.visit_expression(left);
+                self.visit_expression(right);
+            }
+            Expression::Not(expression, _) | Expression::Negate(expression, _) | Expression::Cast(expression, _, _) => {
+                self.visit_expression(expression)
+            }
+
+            Expression::IfElse(condition, first, second, _) => {
+                self.visit_expression(condition);
+                self.visit_expression(first);
+                self.visit_expression(second);
+            }
+
+            Expression::Array(elements, _) => {
+                for element in elements {
+                    match element.as_ref() {
+                        SpreadOrExpression::Spread(expression) | SpreadOrExpression::Expression(expression) => {
+                            self.visit_expression(expression)
+                        }
+                    }
+                }
+            }
+            Expression::ArrayAccess(array, range, _) => {
+                self.visit_expression(array);
+                self.visit_range(range);
+            }
+
+            Expression::Tuple(elements, _) => {
+                for element in elements {
+                    self.visit_expression(element);
+                }
+            }
+            Expression::TupleAccess(tuple, _, _) => self.visit_expression(tuple),
+
+            Expression::Circuit(identifier, members, _) => {
+                self.references.insert(identifier.name.clone());
+
+                for member in members {
+                    self.visit_expression(&member.expression);
+                }
+            }
+            Expression::CircuitMemberAccess(circuit, member, _)
+            | Expression::CircuitStaticFunctionAccess(circuit, member, _) => {
+                self.visit_expression(circuit);
+                self.member_accesses.insert(member.name.clone());
+            }
+
+            Expression::FunctionCall(function, arguments, _) => {
+                self.visit_expression(function);
+
+                // A circuit function may modify `self`, so count the call as an assignment to the receiver.
+                if let Expression::CircuitMemberAccess(circuit, _, _) = function.as_ref() {
+                    if let Expression::Identifier(identifier) = circuit.as_ref() {
+                        if let Some(index) = self.lookup(&identifier.name) {
+                            self.bindings[index].writes += 1;
+                        }
+                    }
+                }
+
+                for argument in arguments {
+                    self.visit_expression(argument);
+                }
+            }
+            Expression::CoreFunctionCall(_, arguments, _) => {
+                for argument in arguments {
+                    self.visit_expression(argument);
+                }
+            }
+        }
+    }
+}
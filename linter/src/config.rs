@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{all_rules, LintConfigError, LintLevel};
+
+use std::collections::HashMap;
+
+/// The level of each lint rule. Rules without a configured level are reported as warnings.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    levels: HashMap<String, LintLevel>,
+}
+
+impl LintConfig {
+    /// Reads the `[lint]` section of `Leo.toml`, which maps rule names to `allow`, `warn` or `deny`.
+    pub fn from_levels(levels: &HashMap<String, String>) -> Result<Self, LintConfigError> {
+        let rules = all_rules().iter().map(|rule| rule.name()).collect::<Vec<_>>();
+        let mut config = Self::default();
+
+        for (rule, level) in levels {
+            if !rules.contains(&rule.as_str()) {
+                return Err(LintConfigError::UnknownRule(rule.clone()));
+            }
+
+            let lint_level =
+                LintLevel::parse(level).ok_or_else(|| LintConfigError::InvalidLevel(rule.clone(), level.clone()))?;
+
+            config.set_level(rule, lint_level);
+        }
+
+        Ok(config)
+    }
+
+    pub fn set_level(&mut self, rule: &str, level: LintLevel) {
+        self.levels.insert(rule.to_string(), level);
+    }
+
+    pub fn level(&self, rule: &str) -> LintLevel {
+        self.levels.get(rule).copied().unwrap_or(LintLevel::Warn)
+    }
+}
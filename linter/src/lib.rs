@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo linter.
+//!
+//! Runs a set of lint rules over a `leo_typed::Program` and reports each finding with the span it
+//! applies to. The level of every rule can be overridden in the `[lint]` section of `Leo.toml`.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod analysis;
+pub use self::analysis::*;
+
+pub mod config;
+pub use self::config::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod lint;
+pub use self::lint::*;
+
+pub mod linter;
+pub use self::linter::*;
+
+pub mod rules;
+pub use self::rules::*;
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::LintLevel;
+use leo_typed::{Error as FormattedError, Span};
+
+use std::{fmt, path::PathBuf};
+
+/// A single finding of a lint rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint {
+    pub rule: &'static str,
+    pub level: LintLevel,
+    pub error: FormattedError,
+}
+
+impl Lint {
+    pub fn new(rule: &'static str, message: String, span: Span) -> Self {
+        Self {
+            rule,
+            level: LintLevel::Warn,
+            error: FormattedError::new_from_span(message, span),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.error.message
+    }
+
+    pub fn line(&self) -> usize {
+        self.error.line
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.level == LintLevel::Deny
+    }
+
+    pub fn set_path(&mut self, path: PathBuf) {
+        self.error.set_path(path)
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]\n{}", self.level, self.rule, self.error.format())
+    }
+}
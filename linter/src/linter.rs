@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{all_rules, Lint, LintConfig, LintContext, LintLevel, LintRule};
+use leo_typed::Program;
+
+/// Runs every lint rule that is not allowed by the configuration.
+pub struct Linter {
+    config: LintConfig,
+    rules: Vec<Box<dyn LintRule>>,
+}
+
+impl Linter {
+    pub fn new(config: LintConfig) -> Self {
+        Self {
+            config,
+            rules: all_rules(),
+        }
+    }
+
+    /// Returns the findings of all enabled rules, ordered by their position in the program.
+    pub fn lint(&self, program: &Program) -> Vec<Lint> {
+        let context = LintContext::new(program);
+        let mut lints = vec![];
+
+        for rule in self.rules.iter() {
+            let level = self.config.level(rule.name());
+
+            if level == LintLevel::Allow {
+                continue;
+            }
+
+            lints.extend(rule.check(&context).into_iter().map(|mut lint| {
+                lint.level = level;
+                lint
+            }));
+        }
+
+        lints.sort_by_key(|lint| (lint.error.line, lint.error.start));
+
+        lints
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+use leo_typed::{ConditionalNestedOrEndStatement, ConditionalStatement, ConsoleFunction, Expression, Statement};
+
+pub const CONSTANT_ASSERT: &str = "constant_assert";
+
+/// `console.assert` calls whose condition is always true.
+pub struct ConstantAssert;
+
+impl ConstantAssert {
+    fn check_block(&self, statements: &[Statement], lints: &mut Vec<Lint>) {
+        for statement in statements {
+            match statement {
+                Statement::Console(console) => {
+                    if let ConsoleFunction::Assert(expression) = &console.function {
+                        if evaluate(expression) == Some(true) {
+                            lints.push(Lint::new(
+                                self.name(),
+                                "this assertion is always true".to_string(),
+                                expression.span().clone(),
+                            ));
+                        }
+                    }
+                }
+                Statement::Conditional(conditional, _) => self.check_conditional(conditional, lints),
+                Statement::Iteration(_, _, _, statements, _) => self.check_block(statements, lints),
+                _ => {}
+            }
+        }
+    }
+
+    fn check_conditional(&self, conditional: &ConditionalStatement, lints: &mut Vec<Lint>) {
+        self.check_block(&conditional.statements, lints);
+
+        match &conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => self.check_conditional(nested, lints),
+            Some(ConditionalNestedOrEndStatement::End(statements)) => self.check_block(statements, lints),
+            None => {}
+        }
+    }
+}
+
+impl LintRule for ConstantAssert {
+    fn name(&self) -> &'static str {
+        CONSTANT_ASSERT
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        let mut lints = vec![];
+
+        for function in context.functions() {
+            self.check_block(&function.statements, &mut lints);
+        }
+
+        lints
+    }
+}
+
+/// Returns the value of a boolean expression if it does not depend on any variable.
+fn evaluate(expression: &Expression) -> Option<bool> {
+    match expression {
+        Expression::Boolean(value, _) => value.parse().ok(),
+        Expression::Not(expression, _) => evaluate(expression).map(|value| !value),
+        Expression::And(left, right, _) => Some(evaluate(left)? && evaluate(right)?),
+        Expression::Or(left, right, _) => Some(evaluate(left)? || evaluate(right)?),
+        Expression::Eq(left, right, _) => match (left.as_ref(), right.as_ref()) {
+            (Expression::Identifier(left), Expression::Identifier(right)) if left.name == right.name => Some(true),
+            (Expression::Address(left, _), Expression::Address(right, _))
+            | (Expression::Field(left, _), Expression::Field(right, _))
+            | (Expression::Implicit(left, _), Expression::Implicit(right, _)) => Some(left == right),
+            (Expression::Integer(left_type, left, _), Expression::Integer(right_type, right, _))
+                if left_type == right_type =>
+            {
+                Some(left == right)
+            }
+            (left, right) => Some(evaluate(left)? == evaluate(right)?),
+        },
+        _ => None,
+    }
+}
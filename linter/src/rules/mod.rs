@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod constant_assert;
+pub use self::constant_assert::*;
+
+pub mod shadowed_names;
+pub use self::shadowed_names::*;
+
+pub mod unreachable_code;
+pub use self::unreachable_code::*;
+
+pub mod unused_circuit_members;
+pub use self::unused_circuit_members::*;
+
+pub mod unused_imports;
+pub use self::unused_imports::*;
+
+pub mod unused_mut;
+pub use self::unused_mut::*;
+
+pub mod unused_variables;
+pub use self::unused_variables::*;
+
+use crate::{Lint, LintContext};
+
+/// A check that reports findings over a whole program.
+pub trait LintRule {
+    /// The name of the rule in the `[lint]` section of `Leo.toml`.
+    fn name(&self) -> &'static str;
+
+    fn check(&self, context: &LintContext) -> Vec<Lint>;
+}
+
+/// Returns every lint rule known to the linter.
+pub fn all_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(ConstantAssert),
+        Box::new(ShadowedNames),
+        Box::new(UnreachableCode),
+        Box::new(UnusedCircuitMembers),
+        Box::new(UnusedImports),
+        Box::new(UnusedMut),
+        Box::new(UnusedVariables),
+    ]
+}
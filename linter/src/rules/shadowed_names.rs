@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+
+pub const SHADOWED_NAMES: &str = "shadowed_names";
+
+/// Variables that reuse the name of a variable that is still in scope.
+pub struct ShadowedNames;
+
+impl LintRule for ShadowedNames {
+    fn name(&self) -> &'static str {
+        SHADOWED_NAMES
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        context
+            .bindings
+            .iter()
+            .filter(|binding| binding.shadows)
+            .map(|binding| {
+                let message = format!(
+                    "`{}` shadows a variable that is already in scope",
+                    binding.identifier.name
+                );
+
+                Lint::new(self.name(), message, binding.identifier.span.clone())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+use leo_typed::{ConditionalNestedOrEndStatement, ConditionalStatement, Statement};
+
+pub const UNREACHABLE_CODE: &str = "unreachable_code";
+
+/// Statements that follow a `return` in the same block.
+pub struct UnreachableCode;
+
+impl UnreachableCode {
+    fn check_block(&self, statements: &[Statement], lints: &mut Vec<Lint>) {
+        for (index, statement) in statements.iter().enumerate() {
+            match statement {
+                Statement::Conditional(conditional, _) => self.check_conditional(conditional, lints),
+                Statement::Iteration(_, _, _, statements, _) => self.check_block(statements, lints),
+                _ => {}
+            }
+
+            if always_returns(statement) {
+                // Only the first unreachable statement of a block is reported.
+                if let Some(next) = statements.get(index + 1) {
+                    lints.push(Lint::new(
+                        self.name(),
+                        "unreachable statement".to_string(),
+                        next.span().clone(),
+                    ));
+                }
+
+                return;
+            }
+        }
+    }
+
+    fn check_conditional(&self, conditional: &ConditionalStatement, lints: &mut Vec<Lint>) {
+        self.check_block(&conditional.statements, lints);
+
+        match &conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => self.check_conditional(nested, lints),
+            Some(ConditionalNestedOrEndStatement::End(statements)) => self.check_block(statements, lints),
+            None => {}
+        }
+    }
+}
+
+impl LintRule for UnreachableCode {
+    fn name(&self) -> &'static str {
+        UNREACHABLE_CODE
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        let mut lints = vec![];
+
+        for function in context.functions() {
+            self.check_block(&function.statements, &mut lints);
+        }
+
+        lints
+    }
+}
+
+/// Returns `true` if every path through the statement ends in a `return`.
+fn always_returns(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return(..) => true,
+        Statement::Conditional(conditional, _) => conditional_returns(conditional),
+        _ => false,
+    }
+}
+
+fn conditional_returns(conditional: &ConditionalStatement) -> bool {
+    let branch_returns = conditional.statements.iter().any(always_returns);
+
+    match &conditional.next {
+        Some(ConditionalNestedOrEndStatement::Nested(nested)) => branch_returns && conditional_returns(nested),
+        Some(ConditionalNestedOrEndStatement::End(statements)) => {
+            branch_returns && statements.iter().any(always_returns)
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+use leo_typed::CircuitMember;
+
+pub const UNUSED_CIRCUIT_MEMBERS: &str = "unused_circuit_members";
+
+/// Circuit variables that are never read and circuit functions that are never called.
+///
+/// Member accesses are matched by name only, so a member counts as used if any circuit member with
+/// the same name is accessed.
+pub struct UnusedCircuitMembers;
+
+impl LintRule for UnusedCircuitMembers {
+    fn name(&self) -> &'static str {
+        UNUSED_CIRCUIT_MEMBERS
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        let mut lints = vec![];
+
+        for circuit in context.program.circuits.values() {
+            let circuit_name = &circuit.circuit_name.name;
+
+            for member in circuit.members.iter() {
+                let (identifier, message) = match member {
+                    CircuitMember::CircuitVariable(_, identifier, _) => (
+                        identifier,
+                        format!(
+                            "field `{}` of circuit `{}` is never read",
+                            identifier.name, circuit_name
+                        ),
+                    ),
+                    CircuitMember::CircuitFunction(_, function) => (
+                        &function.identifier,
+                        format!(
+                            "function `{}` of circuit `{}` is never used",
+                            function.identifier.name, circuit_name
+                        ),
+                    ),
+                };
+
+                if !context.member_accesses.contains(&identifier.name) {
+                    lints.push(Lint::new(self.name(), message, identifier.span.clone()));
+                }
+            }
+        }
+
+        lints
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+use leo_typed::{ImportSymbol, PackageAccess};
+
+pub const UNUSED_IMPORTS: &str = "unused_imports";
+
+/// Imported symbols that are never referenced. Star imports are not checked.
+pub struct UnusedImports;
+
+impl UnusedImports {
+    fn check_access(&self, context: &LintContext, access: &PackageAccess, lints: &mut Vec<Lint>) {
+        match access {
+            PackageAccess::Star(_) => {}
+            PackageAccess::SubPackage(package) => self.check_access(context, &package.access, lints),
+            PackageAccess::Symbol(symbol) => self.check_symbol(context, symbol, lints),
+            PackageAccess::Multiple(accesses) => {
+                for access in accesses {
+                    self.check_access(context, access, lints);
+                }
+            }
+        }
+    }
+
+    fn check_symbol(&self, context: &LintContext, symbol: &ImportSymbol, lints: &mut Vec<Lint>) {
+        let name = &symbol.alias.as_ref().unwrap_or(&symbol.symbol).name;
+
+        if !context.references.contains(name) {
+            lints.push(Lint::new(
+                self.name(),
+                format!("unused import `{}`", name),
+                symbol.span.clone(),
+            ));
+        }
+    }
+}
+
+impl LintRule for UnusedImports {
+    fn name(&self) -> &'static str {
+        UNUSED_IMPORTS
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        let mut lints = vec![];
+
+        for import in context.program.imports.iter() {
+            self.check_access(context, &import.package.access, &mut lints);
+        }
+
+        lints
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+
+pub const UNUSED_MUT: &str = "unused_mut";
+
+/// `mut` variables and function inputs that are never assigned to.
+pub struct UnusedMut;
+
+impl LintRule for UnusedMut {
+    fn name(&self) -> &'static str {
+        UNUSED_MUT
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        context
+            .bindings
+            .iter()
+            .filter(|binding| binding.mutable && binding.writes == 0)
+            .map(|binding| {
+                let message = format!("variable `{}` does not need to be mutable", binding.identifier.name);
+
+                Lint::new(self.name(), message, binding.identifier.span.clone())
+            })
+            .collect()
+    }
+}
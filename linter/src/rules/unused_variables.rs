@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, LintRule};
+
+pub const UNUSED_VARIABLES: &str = "unused_variables";
+
+/// Variables and function inputs that are never read.
+pub struct UnusedVariables;
+
+impl LintRule for UnusedVariables {
+    fn name(&self) -> &'static str {
+        UNUSED_VARIABLES
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Lint> {
+        context
+            .bindings
+            .iter()
+            .filter(|binding| binding.reads == 0)
+            .map(|binding| {
+                let message = if binding.writes > 0 {
+                    format!("variable `{}` is assigned to, but never used", binding.identifier.name)
+                } else {
+                    format!("unused variable `{}`", binding.identifier.name)
+                };
+
+                Lint::new(self.name(), message, binding.identifier.span.clone())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::lint_program_with_config;
+use leo_linter::{LintConfig, LintConfigError, LintLevel};
+
+use std::collections::HashMap;
+
+fn levels(levels: &[(&str, &str)]) -> HashMap<String, String> {
+    levels
+        .iter()
+        .map(|(rule, level)| (rule.to_string(), level.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_allow() {
+    let bytes = include_bytes!("program.leo");
+    let config = LintConfig::from_levels(&levels(&[("unused_variables", "allow")])).unwrap();
+
+    let lints = lint_program_with_config(bytes, config);
+
+    assert_eq!(lints.len(), 1);
+    assert_eq!(lints[0].rule, "unused_mut");
+    assert_eq!(lints[0].level, LintLevel::Warn);
+}
+
+#[test]
+fn test_deny() {
+    let bytes = include_bytes!("program.leo");
+    let config = LintConfig::from_levels(&levels(&[("unused_mut", "deny")])).unwrap();
+
+    let lints = lint_program_with_config(bytes, config);
+    let denied = lints.iter().filter(|lint| lint.is_denied()).collect::<Vec<_>>();
+
+    assert_eq!(lints.len(), 2);
+    assert_eq!(denied.len(), 1);
+    assert_eq!(denied[0].rule, "unused_mut");
+}
+
+#[test]
+fn test_unknown_rule() {
+    let error = LintConfig::from_levels(&levels(&[("unused_everything", "deny")])).unwrap_err();
+
+    match error {
+        LintConfigError::UnknownRule(rule) => assert_eq!(rule, "unused_everything"),
+        error => panic!("expected unknown rule error, got {}", error),
+    }
+}
+
+#[test]
+fn test_invalid_level() {
+    let error = LintConfig::from_levels(&levels(&[("unused_mut", "forbid")])).unwrap_err();
+
+    match error {
+        LintConfigError::InvalidLevel(rule, level) => {
+            assert_eq!(rule, "unused_mut");
+            assert_eq!(level, "forbid");
+        }
+        error => panic!("expected invalid level error, got {}", error),
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod config;
+pub mod rules;
+
+use leo_ast::LeoAst;
+use leo_linter::{Lint, LintConfig, Linter};
+use leo_typed::LeoTypedAst;
+
+use std::path::PathBuf;
+
+const TEST_PROGRAM_PATH: &str = "";
+
+pub(crate) fn lint_program_with_config(bytes: &[u8], config: LintConfig) -> Vec<Lint> {
+    let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+    let program_string = String::from_utf8_lossy(bytes);
+
+    // Parse the Leo file and construct a typed syntax tree.
+    let ast = LeoAst::new(&file_path, &program_string).unwrap();
+    let program = LeoTypedAst::new("test", &ast).into_repr();
+
+    Linter::new(config).lint(&program)
+}
+
+pub(crate) fn lint_program(bytes: &[u8]) -> Vec<Lint> {
+    lint_program_with_config(bytes, LintConfig::default())
+}
+
+/// Returns the rule name and message of each lint, in order of appearance.
+pub(crate) fn lint_messages(bytes: &[u8]) -> Vec<(&'static str, String)> {
+    lint_program(bytes)
+        .into_iter()
+        .map(|lint| (lint.rule, lint.message().to_string()))
+        .collect()
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{lint_messages, lint_program};
+
+#[test]
+fn test_clean() {
+    let bytes = include_bytes!("clean.leo");
+
+    assert!(lint_program(bytes).is_empty());
+}
+
+#[test]
+fn test_unused_variables() {
+    let bytes = include_bytes!("unused_variables.leo");
+
+    assert_eq!(lint_messages(bytes), vec![
+        ("unused_variables", "unused variable `b`".to_string()),
+        ("unused_variables", "unused variable `c`".to_string()),
+        (
+            "unused_variables",
+            "variable `e` is assigned to, but never used".to_string()
+        ),
+        ("unused_variables", "unused variable `i`".to_string()),
+    ]);
+}
+
+#[test]
+fn test_unused_mut() {
+    let bytes = include_bytes!("unused_mut.leo");
+
+    assert_eq!(lint_messages(bytes), vec![
+        ("unused_mut", "variable `a` does not need to be mutable".to_string()),
+        ("unused_mut", "variable `c` does not need to be mutable".to_string()),
+    ]);
+}
+
+#[test]
+fn test_shadowed_names() {
+    let bytes = include_bytes!("shadowed_names.leo");
+
+    assert_eq!(lint_messages(bytes), vec![
+        (
+            "shadowed_names",
+            "`a` shadows a variable that is already in scope".to_string()
+        ),
+        (
+            "shadowed_names",
+            "`b` shadows a variable that is already in scope".to_string()
+        ),
+    ]);
+}
+
+#[test]
+fn test_unreachable_code() {
+    let bytes = include_bytes!("unreachable_code.leo");
+
+    let lints = lint_program(bytes);
+    let lines = lints.iter().map(|lint| (lint.rule, lint.line())).collect::<Vec<_>>();
+
+    assert_eq!(lines, vec![("unreachable_code", 3), ("unreachable_code", 13)]);
+}
+
+#[test]
+fn test_constant_assert() {
+    let bytes = include_bytes!("constant_assert.leo");
+
+    let lints = lint_program(bytes);
+    let lines = lints.iter().map(|lint| (lint.rule, lint.line())).collect::<Vec<_>>();
+
+    assert_eq!(lines, vec![
+        ("constant_assert", 2),
+        ("constant_assert", 3),
+        ("constant_assert", 4),
+        ("constant_assert", 5),
+    ]);
+}
+
+#[test]
+fn test_unused_imports() {
+    let bytes = include_bytes!("unused_imports.leo");
+
+    assert_eq!(lint_messages(bytes), vec![
+        ("unused_imports", "unused import `Bar`".to_string()),
+        ("unused_imports", "unused import `qux`".to_string()),
+    ]);
+}
+
+#[test]
+fn test_unused_circuit_members() {
+    let bytes = include_bytes!("unused_circuit_members.leo");
+
+    assert_eq!(lint_messages(bytes), vec![
+        (
+            "unused_circuit_members",
+            "field `y` of circuit `Foo` is never read".to_string()
+        ),
+        (
+            "unused_circuit_members",
+            "function `unused` of circuit `Foo` is never used".to_string()
+        ),
+    ]);
+}
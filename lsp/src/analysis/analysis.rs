@@ -0,0 +1,325 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Diagnostic, Item, Symbol};
+use leo_ast::LeoAst;
+use leo_compiler::ImportParser;
+use leo_package::root::MANIFEST_FILENAME;
+use leo_type_inference::{InferredType, TypeChecker};
+use leo_typed::{CircuitMember, Identifier, ImportSymbol, LeoTypedAst, PackageAccess, Program, Span};
+
+use std::path::{Path, PathBuf};
+
+/// A span in a Leo file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub file_path: PathBuf,
+    pub span: Span,
+}
+
+/// The identifier under the cursor.
+struct Word {
+    name: String,
+    /// `true` if the identifier follows `.` or `::`.
+    member: bool,
+}
+
+/// A Leo file after parsing, resolving its imports and type checking.
+///
+/// Lines and columns start at 1, as in `leo_typed::Span`.
+pub struct Analysis {
+    file_path: PathBuf,
+    source: String,
+    program: Option<Program>,
+    imports: ImportParser,
+    variables: Vec<(Identifier, InferredType)>,
+    function_lines: Vec<usize>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Analysis {
+    pub fn new(file_path: PathBuf, source: &str) -> Self {
+        let mut analysis = Self {
+            file_path: file_path.clone(),
+            source: source.to_string(),
+            program: None,
+            imports: ImportParser::new(),
+            variables: vec![],
+            function_lines: vec![],
+            diagnostics: vec![],
+        };
+
+        let ast = match LeoAst::new(&file_path, source) {
+            Ok(ast) => ast,
+            Err(error) => {
+                analysis.diagnostics.push(Diagnostic::from_parser_error(&error));
+                return analysis;
+            }
+        };
+
+        let program_name = file_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let program = LeoTypedAst::new(&program_name, &ast).into_repr();
+
+        // Resolve each import on its own so that every failing import is reported.
+        let directory = package_directory(&file_path);
+        let mut imports_resolved = true;
+
+        for import in program.imports.iter() {
+            if let Err(error) = analysis.imports.parse_package(directory.clone(), &import.package) {
+                analysis
+                    .diagnostics
+                    .push(Diagnostic::from_import_error(&error, &import.span));
+                imports_resolved = false;
+            }
+        }
+
+        // Skip type checking if an import is missing, as each use of it would be reported again.
+        if imports_resolved {
+            let checker = TypeChecker::check(&program, analysis.imports.imports());
+            analysis.variables = checker.variables().to_vec();
+
+            if let Err(error) = checker.into_result() {
+                analysis
+                    .diagnostics
+                    .extend(error.errors().iter().map(Diagnostic::from_type_error));
+            }
+        }
+
+        analysis.function_lines = function_lines(&program);
+        analysis.program = Some(program);
+
+        analysis
+    }
+
+    pub fn file_path(&self) -> &PathBuf {
+        &self.file_path
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn program(&self) -> Option<&Program> {
+        self.program.as_ref()
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.program.as_ref().map(Symbol::from_program).unwrap_or_default()
+    }
+
+    /// Returns the definition of the function, circuit or imported symbol at the given position.
+    pub fn definition(&self, line: usize, column: usize) -> Option<Location> {
+        self.resolve(line, column).map(|(location, _)| location)
+    }
+
+    /// Returns the type of the variable or the signature of the item at the given position.
+    pub fn hover(&self, line: usize, column: usize) -> Option<String> {
+        let word = self.word_at(line, column)?;
+
+        if !word.member {
+            if let Some(type_) = self.variable_type(&word.name, line, column) {
+                return Some(format!("{}: {}", word.name, type_));
+            }
+        }
+
+        self.resolve(line, column).map(|(_, signature)| signature)
+    }
+
+    fn resolve(&self, line: usize, column: usize) -> Option<(Location, String)> {
+        let word = self.word_at(line, column)?;
+        let program = self.program.as_ref()?;
+
+        let found = |file_path: &PathBuf, item: Item| {
+            let location = Location {
+                file_path: file_path.clone(),
+                span: item.span().clone(),
+            };
+
+            (location, item.signature())
+        };
+
+        // `a.b` and `A::b` refer to a circuit member, of a local circuit or an imported one.
+        if word.member {
+            if let Some(item) = Item::find_member(program, &word.name) {
+                return Some(found(&self.file_path, item));
+            }
+
+            return self.imported_programs().find_map(|(file_path, program)| {
+                Item::find_member(program, &word.name).map(|item| found(file_path, item))
+            });
+        }
+
+        if let Some(item) = Item::find(program, &word.name) {
+            return Some(found(&self.file_path, item));
+        }
+
+        for import in program.imports.iter() {
+            let mut symbols = vec![];
+            import_symbols(&import.package.name.name, &import.package.access, &mut symbols);
+
+            for (file_name, symbol) in symbols {
+                let (program, file_path) = match (
+                    self.imports.get_import(&file_name),
+                    self.imports.get_import_path(&file_name),
+                ) {
+                    (Some(program), Some(file_path)) => (program, file_path),
+                    _ => continue,
+                };
+
+                let name = match symbol {
+                    // A star import brings every definition of the file into scope.
+                    None => &word.name,
+                    Some(symbol) if symbol.alias.as_ref().unwrap_or(&symbol.symbol).name == word.name => {
+                        &symbol.symbol.name
+                    }
+                    Some(_) => continue,
+                };
+
+                if let Some(item) = Item::find(program, name) {
+                    return Some(found(file_path, item));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn imported_programs(&self) -> impl Iterator<Item = (&PathBuf, &Program)> {
+        self.imports
+            .imports()
+            .iter()
+            .filter_map(move |(file_name, program)| self.imports.get_import_path(file_name).map(|path| (path, program)))
+    }
+
+    /// Returns the type of the last variable with the given name defined before the position in the same function.
+    fn variable_type(&self, name: &str, line: usize, column: usize) -> Option<&InferredType> {
+        let function_line = self.function_line(line);
+
+        self.variables
+            .iter()
+            .rev()
+            .find(|(identifier, _)| {
+                identifier.name == name
+                    && (identifier.span.line, identifier.span.start) <= (line, column)
+                    && self.function_line(identifier.span.line) == function_line
+            })
+            .map(|(_, type_)| type_)
+    }
+
+    /// Returns the line of the function declared last before the given line.
+    fn function_line(&self, line: usize) -> usize {
+        self.function_lines
+            .iter()
+            .rev()
+            .find(|function_line| **function_line <= line)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn word_at(&self, line: usize, column: usize) -> Option<Word> {
+        let text = self.source.lines().nth(line.checked_sub(1)?)?;
+        let chars = text.chars().collect::<Vec<_>>();
+        let is_identifier = |c: &char| c.is_ascii_alphanumeric() || *c == '_';
+
+        // Accept a cursor placed right after the identifier.
+        let mut index = column.checked_sub(1)?;
+        if !chars.get(index).is_some_and(is_identifier) {
+            index = index.checked_sub(1)?;
+            if !chars.get(index).is_some_and(is_identifier) {
+                return None;
+            }
+        }
+
+        let start = chars[..index]
+            .iter()
+            .rposition(|c| !is_identifier(c))
+            .map_or(0, |position| position + 1);
+        let end = chars[index..]
+            .iter()
+            .position(|c| !is_identifier(c))
+            .map_or(chars.len(), |position| index + position);
+
+        if chars[start].is_ascii_digit() {
+            return None;
+        }
+
+        let prefix = chars[..start].iter().collect::<String>();
+        let member = !text.trim_start().starts_with("import ") && (prefix.ends_with('.') || prefix.ends_with("::"));
+
+        Some(Word {
+            name: chars[start..end].iter().collect(),
+            member,
+        })
+    }
+}
+
+/// Returns the directory of the package holding the file, or the directory of the file outside of a package.
+pub fn package_directory(file_path: &Path) -> PathBuf {
+    let directory = file_path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    directory
+        .ancestors()
+        .find(|ancestor| ancestor.join(MANIFEST_FILENAME).exists())
+        .map(Path::to_path_buf)
+        .unwrap_or(directory)
+}
+
+/// Collects the symbols brought into scope by an import, with the name of the file they are imported from.
+/// Star imports have no symbol.
+fn import_symbols<'a>(
+    package_name: &str,
+    access: &'a PackageAccess,
+    symbols: &mut Vec<(String, Option<&'a ImportSymbol>)>,
+) {
+    match access {
+        PackageAccess::Star(_) => symbols.push((package_name.to_string(), None)),
+        PackageAccess::Symbol(symbol) => symbols.push((package_name.to_string(), Some(symbol))),
+        PackageAccess::SubPackage(package) => import_symbols(&package.name.name, &package.access, symbols),
+        PackageAccess::Multiple(accesses) => accesses
+            .iter()
+            .for_each(|access| import_symbols(package_name, access, symbols)),
+    }
+}
+
+fn function_lines(program: &Program) -> Vec<usize> {
+    let functions = program
+        .functions
+        .values()
+        .chain(program.tests.values().map(|test| &test.function));
+    let methods = program
+        .circuits
+        .values()
+        .flat_map(|circuit| circuit.members.iter())
+        .filter_map(|member| match member {
+            CircuitMember::CircuitFunction(_, function) => Some(function),
+            CircuitMember::CircuitVariable(..) => None,
+        });
+
+    let mut lines = functions
+        .chain(methods)
+        .map(|function| function.identifier.span.line)
+        .collect::<Vec<_>>();
+    lines.sort();
+
+    lines
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{ParserError, SyntaxError};
+use leo_compiler::errors::ImportError;
+use leo_type_inference::TypeError;
+use leo_typed::{Error as FormattedError, Span};
+
+use pest::error::{ErrorVariant, LineColLocation};
+
+/// An error in a Leo file. Lines and columns start at 1, `end` is the column after the error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(message: String, line: usize, start: usize, end: usize) -> Self {
+        // Spans over several lines only know their end column, so underline the first character.
+        let end = if end > start { end } else { start + 1 };
+
+        Self {
+            line,
+            start,
+            end,
+            message,
+        }
+    }
+
+    pub fn new_from_span(message: String, span: &Span) -> Self {
+        Self::new(message, span.line, span.start, span.end)
+    }
+
+    pub fn from_formatted_error(error: &FormattedError) -> Self {
+        Self::new(error.message.clone(), error.line, error.start, error.end)
+    }
+
+    pub fn from_parser_error(error: &ParserError) -> Self {
+        match error {
+            ParserError::SyntaxError(SyntaxError::Error(error)) => {
+                let message = match &error.variant {
+                    ErrorVariant::CustomError { message } => message.clone(),
+                    ErrorVariant::ParsingError { .. } => error.to_string(),
+                };
+
+                match error.line_col {
+                    LineColLocation::Pos((line, start)) => Self::new(message, line, start, start + 1),
+                    LineColLocation::Span((line, start), (end_line, end)) if line == end_line => {
+                        Self::new(message, line, start, end)
+                    }
+                    LineColLocation::Span((line, start), _) => Self::new(message, line, start, start + 1),
+                }
+            }
+            error => Self::new(error.to_string(), 1, 1, 1),
+        }
+    }
+
+    /// Returns the diagnostic of an error in the import at `span`.
+    pub fn from_import_error(error: &ImportError, span: &Span) -> Self {
+        match error {
+            ImportError::Error(error) => Self::from_formatted_error(error),
+            error => Self::new_from_span(error.to_string(), span),
+        }
+    }
+
+    pub fn from_type_error(error: &TypeError) -> Self {
+        match error {
+            TypeError::Error(error) => Self::from_formatted_error(error),
+            error => Self::new(error.to_string(), 1, 1, 1),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_typed::{Circuit, CircuitMember, Function, Program, Span};
+
+/// A circuit, function or circuit member that can be jumped to or hovered.
+#[derive(Clone, Copy)]
+pub enum Item<'a> {
+    Circuit(&'a Circuit),
+    Function(&'a Function),
+    Member(&'a Circuit, &'a CircuitMember),
+}
+
+impl<'a> Item<'a> {
+    /// Returns the circuit, function or test with the given name.
+    pub fn find(program: &'a Program, name: &str) -> Option<Self> {
+        let circuit = program
+            .circuits
+            .values()
+            .find(|circuit| circuit.circuit_name.name == name)
+            .map(Item::Circuit);
+
+        let function = || {
+            program
+                .functions
+                .values()
+                .chain(program.tests.values().map(|test| &test.function))
+                .find(|function| function.identifier.name == name)
+                .map(Item::Function)
+        };
+
+        circuit.or_else(function)
+    }
+
+    /// Returns the circuit member with the given name.
+    pub fn find_member(program: &'a Program, name: &str) -> Option<Self> {
+        program.circuits.values().find_map(|circuit| {
+            circuit
+                .members
+                .iter()
+                .find(|member| member_identifier_span(member).0 == name)
+                .map(|member| Item::Member(circuit, member))
+        })
+    }
+
+    /// Returns the span of the name of the item.
+    pub fn span(&self) -> &'a Span {
+        match self {
+            Item::Circuit(circuit) => &circuit.circuit_name.span,
+            Item::Function(function) => &function.identifier.span,
+            Item::Member(_, member) => member_identifier_span(member).1,
+        }
+    }
+
+    /// Returns the declaration of the item without its body.
+    pub fn signature(&self) -> String {
+        match self {
+            Item::Circuit(circuit) => format!("circuit {}", circuit.circuit_name),
+            Item::Function(function) => function_signature(function),
+            Item::Member(_, CircuitMember::CircuitVariable(mutable, identifier, type_)) => match mutable {
+                true => format!("mut {}: {}", identifier, type_),
+                false => format!("{}: {}", identifier, type_),
+            },
+            Item::Member(_, CircuitMember::CircuitFunction(is_static, function)) => match is_static {
+                true => format!("static {}", function_signature(function)),
+                false => function_signature(function),
+            },
+        }
+    }
+}
+
+fn member_identifier_span(member: &CircuitMember) -> (&str, &Span) {
+    match member {
+        CircuitMember::CircuitVariable(_, identifier, _) => (&identifier.name, &identifier.span),
+        CircuitMember::CircuitFunction(_, function) => (&function.identifier.name, &function.identifier.span),
+    }
+}
+
+/// Returns `function name(inputs) -> returns`.
+pub fn function_signature(function: &Function) -> String {
+    let inputs = function
+        .input
+        .iter()
+        .map(|input| input.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    match &function.returns {
+        Some(returns) => format!("function {}({}) -> {}", function.identifier, inputs, returns),
+        None => format!("function {}({})", function.identifier, inputs),
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{function_signature, Item};
+use leo_typed::{CircuitMember, Program, Span};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolKind {
+    Circuit,
+    Function,
+    Method,
+    Test,
+    Variable,
+}
+
+/// An entry of the outline of a Leo file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub detail: String,
+    pub span: Span,
+    pub children: Vec<Symbol>,
+}
+
+impl Symbol {
+    /// Returns the circuits, functions and tests of a program in order of appearance.
+    pub fn from_program(program: &Program) -> Vec<Self> {
+        let circuits = program.circuits.values().map(|circuit| {
+            let children = circuit
+                .members
+                .iter()
+                .map(|member| {
+                    let (identifier, kind) = match member {
+                        CircuitMember::CircuitVariable(_, identifier, _) => (identifier, SymbolKind::Variable),
+                        CircuitMember::CircuitFunction(_, function) => (&function.identifier, SymbolKind::Method),
+                    };
+
+                    Symbol {
+                        name: identifier.name.clone(),
+                        kind,
+                        detail: Item::Member(circuit, member).signature(),
+                        span: identifier.span.clone(),
+                        children: vec![],
+                    }
+                })
+                .collect();
+
+            Symbol {
+                name: circuit.circuit_name.name.clone(),
+                kind: SymbolKind::Circuit,
+                detail: Item::Circuit(circuit).signature(),
+                span: circuit.circuit_name.span.clone(),
+                children,
+            }
+        });
+
+        let functions = program
+            .functions
+            .values()
+            .map(|function| (function, SymbolKind::Function))
+            .chain(program.tests.values().map(|test| (&test.function, SymbolKind::Test)))
+            .map(|(function, kind)| Symbol {
+                name: function.identifier.name.clone(),
+                kind,
+                detail: function_signature(function),
+                span: function.identifier.span.clone(),
+                children: vec![],
+            });
+
+        let mut symbols = circuits.chain(functions).collect::<Vec<_>>();
+        symbols.sort_by_key(|symbol| (symbol.span.line, symbol.span.start));
+
+        symbols
+    }
+}
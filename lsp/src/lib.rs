@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo language server.
+//!
+//! Analyzes the open `.leo` files of a local package and serves diagnostics, go-to-definition, hover
+//! and document symbols over the language server protocol.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod analysis;
+pub use self::analysis::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod server;
+pub use self::server::*;
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-fn main() {
-    println!("Hello, world!");
+use leo_lsp::{LeoLanguageServer, LspError};
+
+fn main() -> Result<(), LspError> {
+    LeoLanguageServer::run()
 }
@@ -0,0 +1,285 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Analysis, LspError, SymbolKind};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument,
+        DidCloseTextDocument,
+        DidOpenTextDocument,
+        DidSaveTextDocument,
+        Notification as _,
+        PublishDiagnostics,
+    },
+    request::{DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as _},
+    DiagnosticSeverity,
+    DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams,
+    DocumentSymbol,
+    DocumentSymbolParams,
+    DocumentSymbolResponse,
+    GotoDefinitionParams,
+    GotoDefinitionResponse,
+    Hover,
+    HoverContents,
+    HoverParams,
+    HoverProviderCapability,
+    MarkupContent,
+    MarkupKind,
+    OneOf,
+    Position,
+    PublishDiagnosticsParams,
+    Range,
+    ServerCapabilities,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    Url,
+};
+use std::collections::HashMap;
+
+/// Serves the language server protocol over stdin and stdout.
+pub struct LeoLanguageServer {
+    connection: Connection,
+    documents: HashMap<Url, Analysis>,
+}
+
+impl LeoLanguageServer {
+    /// Runs the server until the client asks it to shut down.
+    pub fn run() -> Result<(), LspError> {
+        let (connection, io_threads) = Connection::stdio();
+
+        let capabilities = ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            document_symbol_provider: Some(OneOf::Left(true)),
+            ..ServerCapabilities::default()
+        };
+        connection.initialize(serde_json::to_value(capabilities)?)?;
+
+        let mut server = Self {
+            connection,
+            documents: HashMap::new(),
+        };
+        server.main_loop()?;
+
+        // The writer thread stops once the connection is dropped.
+        drop(server);
+        io_threads.join()?;
+
+        Ok(())
+    }
+
+    fn main_loop(&mut self) -> Result<(), LspError> {
+        let receiver = self.connection.receiver.clone();
+
+        for message in receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> Result<(), LspError> {
+        let id = request.id.clone();
+
+        let result = match request.method.as_str() {
+            GotoDefinition::METHOD => {
+                let params: GotoDefinitionParams = serde_json::from_value(request.params)?;
+                serde_json::to_value(self.definition(params))?
+            }
+            HoverRequest::METHOD => {
+                let params: HoverParams = serde_json::from_value(request.params)?;
+                serde_json::to_value(self.hover(params))?
+            }
+            DocumentSymbolRequest::METHOD => {
+                let params: DocumentSymbolParams = serde_json::from_value(request.params)?;
+                serde_json::to_value(self.document_symbols(params))?
+            }
+            method => {
+                let message = format!("unsupported request `{}`", method);
+                return self.respond(Response::new_err(id, ErrorCode::MethodNotFound as i32, message));
+            }
+        };
+
+        self.respond(Response::new_ok(id, result))
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<(), LspError> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.analyze(params.text_document.uri, &params.text_document.text)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+                match params.content_changes.last() {
+                    Some(change) => self.analyze(params.text_document.uri, &change.text),
+                    None => Ok(()),
+                }
+            }
+            // Saving a file may fix or break the imports of the other open files.
+            DidSaveTextDocument::METHOD => {
+                let documents = self
+                    .documents
+                    .iter()
+                    .map(|(uri, analysis)| (uri.clone(), analysis.source().to_string()))
+                    .collect::<Vec<_>>();
+
+                for (uri, source) in documents {
+                    self.analyze(uri, &source)?;
+                }
+
+                Ok(())
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                self.publish_diagnostics(params.text_document.uri, vec![])
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn analyze(&mut self, uri: Url, source: &str) -> Result<(), LspError> {
+        let file_path = match uri.to_file_path() {
+            Ok(file_path) => file_path,
+            Err(_) => return Ok(()),
+        };
+
+        let analysis = Analysis::new(file_path, source);
+        let diagnostics = analysis
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| lsp_types::Diagnostic {
+                range: range(diagnostic.line, diagnostic.start, diagnostic.end),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("leo".to_string()),
+                message: diagnostic.message.clone(),
+                ..lsp_types::Diagnostic::default()
+            })
+            .collect();
+
+        self.documents.insert(uri.clone(), analysis);
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let analysis = self.documents.get(&position.text_document.uri)?;
+
+        let (line, column) = from_position(position.position);
+        let location = analysis.definition(line, column)?;
+
+        Some(GotoDefinitionResponse::Scalar(lsp_types::Location {
+            uri: Url::from_file_path(location.file_path).ok()?,
+            range: range(location.span.line, location.span.start, location.span.end),
+        }))
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        let analysis = self.documents.get(&position.text_document.uri)?;
+
+        let (line, column) = from_position(position.position);
+        let text = analysis.hover(line, column)?;
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```leo\n{}\n```", text),
+            }),
+            range: None,
+        })
+    }
+
+    fn document_symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        let analysis = self.documents.get(&params.text_document.uri)?;
+        let symbols = analysis.symbols().iter().map(document_symbol).collect();
+
+        Some(DocumentSymbolResponse::Nested(symbols))
+    }
+
+    fn publish_diagnostics(&self, uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<(), LspError> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+
+        self.send(Message::Notification(notification))
+    }
+
+    fn respond(&self, response: Response) -> Result<(), LspError> {
+        self.send(Message::Response(response))
+    }
+
+    fn send(&self, message: Message) -> Result<(), LspError> {
+        self.connection
+            .sender
+            .send(message)
+            .map_err(|error| LspError::SendError(error.to_string()))
+    }
+}
+
+#[allow(deprecated)]
+fn document_symbol(symbol: &crate::Symbol) -> DocumentSymbol {
+    let kind = match symbol.kind {
+        SymbolKind::Circuit => lsp_types::SymbolKind::STRUCT,
+        SymbolKind::Function | SymbolKind::Test => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Method => lsp_types::SymbolKind::METHOD,
+        SymbolKind::Variable => lsp_types::SymbolKind::FIELD,
+    };
+    let range = range(symbol.span.line, symbol.span.start, symbol.span.end);
+
+    DocumentSymbol {
+        name: symbol.name.clone(),
+        detail: Some(symbol.detail.clone()),
+        kind,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range: range,
+        children: Some(symbol.children.iter().map(document_symbol).collect()),
+    }
+}
+
+/// Converts a position of the protocol, starting at 0, to a line and column starting at 1.
+fn from_position(position: Position) -> (usize, usize) {
+    (position.line as usize + 1, position.character as usize + 1)
+}
+
+/// Converts a line and columns starting at 1 to a range of the protocol, starting at 0.
+fn range(line: usize, start: usize, end: usize) -> Range {
+    let line = line.saturating_sub(1) as u32;
+    let start = start.saturating_sub(1) as u32;
+    let end = (end.saturating_sub(1) as u32).max(start + 1);
+
+    Range::new(Position::new(line, start), Position::new(line, end))
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{analyze, analyze_main};
+
+#[test]
+fn test_valid_program() {
+    let analysis = analyze_main();
+
+    assert!(analysis.diagnostics().is_empty());
+    assert!(analysis.program().is_some());
+}
+
+#[test]
+fn test_syntax_error() {
+    let analysis = analyze("function main() {\n    let a = ;\n}\n");
+    let diagnostics = analysis.diagnostics();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 2);
+    assert_eq!(diagnostics[0].start, 13);
+    assert!(analysis.program().is_none());
+}
+
+#[test]
+fn test_type_error() {
+    let analysis = analyze("function main() {\n    let a: u8 = true;\n}\n");
+    let diagnostics = analysis.diagnostics();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 2);
+    assert!(diagnostics[0].message.contains("u8"));
+}
+
+#[test]
+fn test_unknown_import() {
+    let analysis = analyze("import foo.Foo;\n\nfunction main() {}\n");
+    let diagnostics = analysis.diagnostics();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!((diagnostics[0].line, diagnostics[0].start), (1, 8));
+    assert!(diagnostics[0].message.contains("foo"));
+}
@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod diagnostics;
+pub mod navigation;
+
+use leo_lsp::Analysis;
+
+use std::{fs, path::PathBuf};
+
+/// Returns the path of a file in the test package.
+pub(crate) fn package_file(file_name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/package/src")
+        .join(file_name)
+}
+
+/// Analyzes `main.leo` of the test package, with the given contents.
+pub(crate) fn analyze(source: &str) -> Analysis {
+    Analysis::new(package_file("main.leo"), source)
+}
+
+/// Analyzes `main.leo` of the test package.
+pub(crate) fn analyze_main() -> Analysis {
+    let file_path = package_file("main.leo");
+    let source = fs::read_to_string(&file_path).unwrap();
+
+    Analysis::new(file_path, &source)
+}
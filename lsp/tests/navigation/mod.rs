@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{analyze_main, package_file};
+use leo_lsp::SymbolKind;
+
+#[test]
+fn test_document_symbols() {
+    let symbols = analyze_main().symbols();
+    let names = symbols
+        .iter()
+        .map(|symbol| (symbol.name.as_str(), symbol.kind))
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec![
+        ("Point", SymbolKind::Circuit),
+        ("double", SymbolKind::Function),
+        ("main", SymbolKind::Function),
+    ]);
+    assert_eq!(symbols[0].children.len(), 3);
+    assert_eq!(symbols[1].detail, "function double(a: u32) -> u32");
+}
+
+#[test]
+fn test_definition_of_function() {
+    // `double` in `y: double(a)`
+    let location = analyze_main().definition(20, 37).unwrap();
+
+    assert_eq!(location.file_path, package_file("main.leo"));
+    assert_eq!((location.span.line, location.span.start), (15, 10));
+}
+
+#[test]
+fn test_definition_of_circuit() {
+    // `Point` in `let point = Point { .. }`
+    let location = analyze_main().definition(20, 17).unwrap();
+
+    assert_eq!(location.file_path, package_file("main.leo"));
+    assert_eq!((location.span.line, location.span.start), (6, 9));
+}
+
+#[test]
+fn test_definition_of_method() {
+    // `sum` in `point.sum()`
+    let location = analyze_main().definition(21, 30).unwrap();
+
+    assert_eq!((location.span.line, location.span.start), (10, 14));
+}
+
+#[test]
+fn test_definition_of_imported_symbol() {
+    let analysis = analyze_main();
+
+    // `Bar` in `Bar::new(..)`
+    let location = analysis.definition(21, 15).unwrap();
+    assert_eq!(location.file_path, package_file("bar.leo"));
+    assert_eq!((location.span.line, location.span.start), (1, 9));
+
+    // `qux`, the alias of `baz`
+    let location = analysis.definition(22, 13).unwrap();
+    assert_eq!(location.file_path, package_file("bar.leo"));
+    assert_eq!((location.span.line, location.span.start), (9, 10));
+}
+
+#[test]
+fn test_hover() {
+    let analysis = analyze_main();
+
+    // `point` in `point.sum()`
+    assert_eq!(analysis.hover(21, 24).unwrap(), "point: circuit Point");
+    // `a` in `Point { x: a, .. }`
+    assert_eq!(analysis.hover(20, 28).unwrap(), "a: u32");
+    // `double`
+    assert_eq!(analysis.hover(20, 37).unwrap(), "function double(a: u32) -> u32");
+    // whitespace
+    assert!(analysis.hover(20, 1).is_none());
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo optimizer.
+//!
+//! Rewrites a `leo_typed::Program` into an equivalent program that synthesizes fewer constraints.
+//! The passes that run are selected by the `--opt-level` of `leo build`.
+
+pub mod literal;
+pub use self::literal::*;
+
+pub mod opt_level;
+pub use self::opt_level::*;
+
+pub mod optimizer;
+pub use self::optimizer::*;
+
+pub mod passes;
+pub use self::passes::*;
+
+pub mod traversal;
+pub use self::traversal::*;
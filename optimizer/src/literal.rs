@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_typed::{Expression, IntegerType, Span};
+
+/// A boolean or integer constant the optimizer can evaluate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Literal {
+    Boolean(bool),
+    Unsigned(IntegerType, u128),
+    Signed(IntegerType, i128),
+}
+
+impl Literal {
+    /// Returns the value of a boolean or explicitly typed integer expression.
+    pub fn from_expression(expression: &Expression) -> Option<Self> {
+        match expression {
+            Expression::Boolean(value, _) => match value.as_str() {
+                "true" => Some(Literal::Boolean(true)),
+                "false" => Some(Literal::Boolean(false)),
+                _ => None,
+            },
+            Expression::Integer(type_, value, _) => Self::integer(type_, value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the given digits as an integer of the given type, if it is in range.
+    pub fn integer(type_: &IntegerType, value: &str) -> Option<Self> {
+        if type_.is_signed() {
+            value.parse::<i128>().ok().and_then(|value| Self::signed(type_, value))
+        } else {
+            value
+                .parse::<u128>()
+                .ok()
+                .and_then(|value| Self::unsigned(type_, value))
+        }
+    }
+
+    /// Returns an unsigned integer literal if the value fits in the given type.
+    pub fn unsigned(type_: &IntegerType, value: u128) -> Option<Self> {
+        let max = match type_.size() {
+            128 => u128::MAX,
+            size => (1u128 << size) - 1,
+        };
+
+        if value <= max {
+            Some(Literal::Unsigned(type_.clone(), value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a signed integer literal if the value fits in the given type.
+    pub fn signed(type_: &IntegerType, value: i128) -> Option<Self> {
+        let (min, max) = match type_.size() {
+            128 => (i128::MIN, i128::MAX),
+            size => (-(1i128 << (size - 1)), (1i128 << (size - 1)) - 1),
+        };
+
+        if min <= value && value <= max {
+            Some(Literal::Signed(type_.clone(), value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of an integer literal.
+    pub fn integer_type(&self) -> Option<&IntegerType> {
+        match self {
+            Literal::Boolean(_) => None,
+            Literal::Unsigned(type_, _) | Literal::Signed(type_, _) => Some(type_),
+        }
+    }
+
+    pub fn into_expression(self, span: Span) -> Expression {
+        match self {
+            Literal::Boolean(value) => Expression::Boolean(value.to_string(), span),
+            Literal::Unsigned(type_, value) => Expression::Integer(type_, value.to_string(), span),
+            Literal::Signed(type_, value) => Expression::Integer(type_, value.to_string(), span),
+        }
+    }
+}
+
+/// Returns `true` if the expression is a value that requires no evaluation.
+pub fn is_literal(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Address(_, _)
+            | Expression::Boolean(_, _)
+            | Expression::Field(_, _)
+            | Expression::Group(_)
+            | Expression::Implicit(_, _)
+            | Expression::Integer(_, _, _)
+    )
+}
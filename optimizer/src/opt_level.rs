@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// The set of optimization passes run before synthesis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptLevel {
+    /// The program is synthesized as written.
+    #[default]
+    None,
+    /// Constant folding and constant-condition branch pruning.
+    Basic,
+    /// Adds for-loop unrolling and inlining of small functions.
+    Full,
+}
+
+impl OptLevel {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "0" => Some(OptLevel::None),
+            "1" => Some(OptLevel::Basic),
+            "2" => Some(OptLevel::Full),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OptLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OptLevel::None => write!(f, "0"),
+            OptLevel::Basic => write!(f, "1"),
+            OptLevel::Full => write!(f, "2"),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{fold_statements, inline_functions, prune_statements, unroll_statements, OptLevel};
+use leo_typed::{CircuitMember, Program, Statement};
+
+/// Runs the optimization passes enabled by an optimization level over a program.
+pub struct Optimizer {
+    level: OptLevel,
+}
+
+impl Optimizer {
+    pub fn new(level: OptLevel) -> Self {
+        Self { level }
+    }
+
+    /// Returns an equivalent program that synthesizes at most as many constraints.
+    pub fn optimize(&self, program: Program) -> Program {
+        if self.level == OptLevel::None {
+            return program;
+        }
+
+        let program = self.map_statements(program, |statements| {
+            let statements = prune_statements(fold_statements(statements));
+
+            match self.level {
+                OptLevel::Full => unroll_statements(statements),
+                _ => statements,
+            }
+        });
+
+        if self.level < OptLevel::Full {
+            return program;
+        }
+
+        // Inlined calls may produce constant conditions of their own.
+        self.map_statements(inline_functions(program), |statements| {
+            prune_statements(fold_statements(statements))
+        })
+    }
+
+    /// Applies `f` to the body of every function, test and circuit method of the program.
+    fn map_statements<F>(&self, mut program: Program, f: F) -> Program
+    where
+        F: Fn(Vec<Statement>) -> Vec<Statement>,
+    {
+        for function in program.functions.values_mut() {
+            function.statements = f(std::mem::take(&mut function.statements));
+        }
+        for test in program.tests.values_mut() {
+            test.function.statements = f(std::mem::take(&mut test.function.statements));
+        }
+        for circuit in program.circuits.values_mut() {
+            for member in circuit.members.iter_mut() {
+                if let CircuitMember::CircuitFunction(_, function) = member {
+                    function.statements = f(std::mem::take(&mut function.statements));
+                }
+            }
+        }
+
+        program
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Removes the branches of conditional statements whose condition is a constant.
+//!
+//! A branch that is always taken is spliced into the enclosing block and a branch that is never
+//! taken is dropped, so neither evaluates its condition nor selects its results at synthesis.
+
+use crate::Literal;
+use leo_typed::{ConditionalNestedOrEndStatement, ConditionalStatement, Statement};
+
+enum Pruned {
+    Kept(Box<ConditionalStatement>),
+    Spliced(Vec<Statement>),
+}
+
+/// Prunes every conditional statement in the given statements, including nested blocks.
+pub fn prune_statements(statements: Vec<Statement>) -> Vec<Statement> {
+    let mut pruned = Vec::with_capacity(statements.len());
+
+    for statement in statements {
+        match statement {
+            Statement::Conditional(conditional, span) => match prune_conditional(conditional) {
+                Pruned::Kept(conditional) => pruned.push(Statement::Conditional(*conditional, span)),
+                Pruned::Spliced(statements) => pruned.extend(statements),
+            },
+            Statement::Iteration(index, start, stop, statements, span) => pruned.push(Statement::Iteration(
+                index,
+                start,
+                stop,
+                prune_statements(statements),
+                span,
+            )),
+            statement => pruned.push(statement),
+        }
+    }
+
+    pruned
+}
+
+fn prune_conditional(conditional: ConditionalStatement) -> Pruned {
+    match Literal::from_expression(&conditional.condition) {
+        Some(Literal::Boolean(true)) => Pruned::Spliced(prune_statements(conditional.statements)),
+        Some(Literal::Boolean(false)) => match conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => prune_conditional(*nested),
+            Some(ConditionalNestedOrEndStatement::End(statements)) => Pruned::Spliced(prune_statements(statements)),
+            None => Pruned::Spliced(vec![]),
+        },
+        _ => {
+            let next = conditional.next.map(|next| match next {
+                ConditionalNestedOrEndStatement::Nested(nested) => match prune_conditional(*nested) {
+                    Pruned::Kept(nested) => ConditionalNestedOrEndStatement::Nested(nested),
+                    Pruned::Spliced(statements) => ConditionalNestedOrEndStatement::End(statements),
+                },
+                ConditionalNestedOrEndStatement::End(statements) => {
+                    ConditionalNestedOrEndStatement::End(prune_statements(statements))
+                }
+            });
+
+            Pruned::Kept(Box::new(ConditionalStatement {
+                condition: conditional.condition,
+                statements: prune_statements(conditional.statements),
+                next,
+            }))
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Replaces operations on constant operands with their result.
+//!
+//! Only boolean and integer operations the compiler would evaluate without error are folded; an
+//! operation that overflows or divides by zero is left in place so synthesis reports it as before.
+
+use crate::{map_children, map_expressions, Literal};
+use leo_typed::{Expression, IntegerType, Span, Statement};
+
+use std::convert::TryFrom;
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Rem,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Or,
+    And,
+    Eq,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+/// Folds every expression in the given statements.
+pub fn fold_statements(statements: Vec<Statement>) -> Vec<Statement> {
+    map_expressions(statements, &mut fold_expression)
+}
+
+/// Folds the given expression bottom-up.
+pub fn fold_expression(expression: Expression) -> Expression {
+    let expression = map_children(expression, &mut fold_expression);
+
+    match expression {
+        Expression::Add(left, right, span) => fold_binary(Operation::Add, left, right, span, Expression::Add),
+        Expression::Sub(left, right, span) => fold_binary(Operation::Sub, left, right, span, Expression::Sub),
+        Expression::Mul(left, right, span) => fold_binary(Operation::Mul, left, right, span, Expression::Mul),
+        Expression::Div(left, right, span) => fold_binary(Operation::Div, left, right, span, Expression::Div),
+        Expression::Pow(left, right, span) => fold_binary(Operation::Pow, left, right, span, Expression::Pow),
+        Expression::Rem(left, right, span) => fold_binary(Operation::Rem, left, right, span, Expression::Rem),
+        Expression::BitAnd(left, right, span) => fold_binary(Operation::BitAnd, left, right, span, Expression::BitAnd),
+        Expression::BitOr(left, right, span) => fold_binary(Operation::BitOr, left, right, span, Expression::BitOr),
+        Expression::BitXor(left, right, span) => fold_binary(Operation::BitXor, left, right, span, Expression::BitXor),
+        Expression::Or(left, right, span) => fold_binary(Operation::Or, left, right, span, Expression::Or),
+        Expression::And(left, right, span) => fold_binary(Operation::And, left, right, span, Expression::And),
+        Expression::Eq(left, right, span) => fold_binary(Operation::Eq, left, right, span, Expression::Eq),
+        Expression::Ge(left, right, span) => fold_binary(Operation::Ge, left, right, span, Expression::Ge),
+        Expression::Gt(left, right, span) => fold_binary(Operation::Gt, left, right, span, Expression::Gt),
+        Expression::Le(left, right, span) => fold_binary(Operation::Le, left, right, span, Expression::Le),
+        Expression::Lt(left, right, span) => fold_binary(Operation::Lt, left, right, span, Expression::Lt),
+
+        Expression::Not(expression, span) => match Literal::from_expression(&expression) {
+            Some(Literal::Boolean(value)) => Literal::Boolean(!value).into_expression(span),
+            _ => Expression::Not(expression, span),
+        },
+        Expression::Negate(expression, span) => match Literal::from_expression(&expression) {
+            Some(Literal::Signed(type_, value)) => {
+                match value.checked_neg().and_then(|value| Literal::signed(&type_, value)) {
+                    Some(literal) => literal.into_expression(span),
+                    None => Expression::Negate(expression, span),
+                }
+            }
+            _ => Expression::Negate(expression, span),
+        },
+        Expression::IfElse(condition, first, second, span) => match Literal::from_expression(&condition) {
+            Some(Literal::Boolean(true)) => *first,
+            Some(Literal::Boolean(false)) => *second,
+            _ => Expression::IfElse(condition, first, second, span),
+        },
+
+        expression => expression,
+    }
+}
+
+fn fold_binary(
+    operation: Operation,
+    left: Box<Expression>,
+    right: Box<Expression>,
+    span: Span,
+    rebuild: fn(Box<Expression>, Box<Expression>, Span) -> Expression,
+) -> Expression {
+    match operands(&left, &right).and_then(|(left, right)| evaluate(operation, left, right)) {
+        Some(literal) => literal.into_expression(span),
+        None => rebuild(left, right, span),
+    }
+}
+
+/// Returns the values of two constant operands, reading an implicit operand as the type of the other.
+fn operands(left: &Expression, right: &Expression) -> Option<(Literal, Literal)> {
+    match (Literal::from_expression(left), Literal::from_expression(right)) {
+        (Some(left), Some(right)) => Some((left, right)),
+        (Some(left), None) => {
+            let right = implicit(right, left.integer_type()?)?;
+            Some((left, right))
+        }
+        (None, Some(right)) => {
+            let left = implicit(left, right.integer_type()?)?;
+            Some((left, right))
+        }
+        (None, None) => None,
+    }
+}
+
+fn implicit(expression: &Expression, type_: &IntegerType) -> Option<Literal> {
+    match expression {
+        Expression::Implicit(value, _) => Literal::integer(type_, value),
+        _ => None,
+    }
+}
+
+fn evaluate(operation: Operation, left: Literal, right: Literal) -> Option<Literal> {
+    match (left, right) {
+        (Literal::Boolean(left), Literal::Boolean(right)) => match operation {
+            Operation::Or => Some(Literal::Boolean(left || right)),
+            Operation::And => Some(Literal::Boolean(left && right)),
+            Operation::Eq => Some(Literal::Boolean(left == right)),
+            _ => None,
+        },
+        (Literal::Unsigned(type_, left), Literal::Unsigned(other, right)) if type_ == other => {
+            let value = match operation {
+                Operation::Add => left.checked_add(right),
+                Operation::Sub => left.checked_sub(right),
+                Operation::Mul => left.checked_mul(right),
+                Operation::Div => left.checked_div(right),
+                Operation::Pow => u32::try_from(right).ok().and_then(|right| left.checked_pow(right)),
+                Operation::Rem => left.checked_rem(right),
+                Operation::BitAnd => Some(left & right),
+                Operation::BitOr => Some(left | right),
+                Operation::BitXor => Some(left ^ right),
+                _ => return compare(operation, left, right),
+            };
+
+            value.and_then(|value| Literal::unsigned(&type_, value))
+        }
+        (Literal::Signed(type_, left), Literal::Signed(other, right)) if type_ == other => {
+            // Signed division, remainder and exponentiation are left to the integer gadgets.
+            let value = match operation {
+                Operation::Add => left.checked_add(right),
+                Operation::Sub => left.checked_sub(right),
+                Operation::Mul => left.checked_mul(right),
+                Operation::BitAnd => Some(left & right),
+                Operation::BitOr => Some(left | right),
+                Operation::BitXor => Some(left ^ right),
+                Operation::Div | Operation::Pow | Operation::Rem => None,
+                _ => return compare(operation, left, right),
+            };
+
+            value.and_then(|value| Literal::signed(&type_, value))
+        }
+        _ => None,
+    }
+}
+
+fn compare<T: Ord>(operation: Operation, left: T, right: T) -> Option<Literal> {
+    let value = match operation {
+        Operation::Eq => left == right,
+        Operation::Ge => left >= right,
+        Operation::Gt => left > right,
+        Operation::Le => left <= right,
+        Operation::Lt => left < right,
+        _ => return None,
+    };
+
+    Some(Literal::Boolean(value))
+}
@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Inlines calls to small functions whose body is a single return expression.
+//!
+//! A call is inlined when its arguments are constants and the body folds to a constant, or when it
+//! is the only call to the function in the program. Either way no gadget of the function body is
+//! synthesized twice in the same constraint namespace.
+
+use crate::{children, fold_expression, is_literal, map_children, map_expressions, substitute};
+use leo_typed::{CircuitMember, Expression, Function, InputVariable, Program, Statement, Type};
+
+use std::collections::HashMap;
+
+/// The largest number of expression nodes in the body of an inlined function.
+pub const MAX_INLINED_EXPRESSION_SIZE: usize = 16;
+
+const MAIN_FUNCTION_NAME: &str = "main";
+
+/// A function that can replace its call sites.
+struct InlinableFunction {
+    parameters: Vec<(String, Type)>,
+    body: Expression,
+}
+
+/// Inlines calls to small local functions in every function, test and circuit method.
+pub fn inline_functions(mut program: Program) -> Program {
+    let functions = inlinable_functions(&program);
+    if functions.is_empty() {
+        return program;
+    }
+
+    let calls = count_calls(&program);
+    let inline = |statements: Vec<Statement>| {
+        map_expressions(statements, &mut |expression| {
+            inline_expression(expression, &functions, &calls)
+        })
+    };
+
+    for function in program.functions.values_mut() {
+        function.statements = inline(std::mem::take(&mut function.statements));
+    }
+    for test in program.tests.values_mut() {
+        test.function.statements = inline(std::mem::take(&mut test.function.statements));
+    }
+    for circuit in program.circuits.values_mut() {
+        for member in circuit.members.iter_mut() {
+            if let CircuitMember::CircuitFunction(_, function) = member {
+                function.statements = inline(std::mem::take(&mut function.statements));
+            }
+        }
+    }
+
+    program
+}
+
+fn inline_expression(
+    expression: Expression,
+    functions: &HashMap<String, InlinableFunction>,
+    calls: &HashMap<String, usize>,
+) -> Expression {
+    let expression = map_children(expression, &mut |child| inline_expression(child, functions, calls));
+
+    match expression {
+        Expression::FunctionCall(function, arguments, span) => {
+            let inlined = match function.as_ref() {
+                Expression::Identifier(name) => functions
+                    .get(&name.name)
+                    .and_then(|inlinable| inlinable.inline(&arguments, calls.get(&name.name) == Some(&1))),
+                _ => None,
+            };
+
+            inlined.unwrap_or(Expression::FunctionCall(function, arguments, span))
+        }
+        expression => expression,
+    }
+}
+
+impl InlinableFunction {
+    fn new(function: &Function) -> Option<Self> {
+        let body = match function.statements.as_slice() {
+            [Statement::Return(expression, _)] => expression,
+            _ => return None,
+        };
+        let returns = function.returns.as_ref().filter(|type_| is_scalar(type_))?;
+
+        let mut parameters = Vec::with_capacity(function.input.len());
+        for input in function.input.iter() {
+            match input {
+                InputVariable::FunctionInput(input) if !input.mutable && is_scalar(&input.type_) => {
+                    parameters.push((input.identifier.name.clone(), input.type_.clone()))
+                }
+                _ => return None,
+            }
+        }
+
+        let is_parameter = |name: &str| parameters.iter().any(|(parameter, _)| parameter == name);
+        if size(body) > MAX_INLINED_EXPRESSION_SIZE || !is_self_contained(body, &is_parameter) {
+            return None;
+        }
+
+        Some(Self {
+            body: type_implicits(body.clone(), returns),
+            parameters,
+        })
+    }
+
+    /// Returns the body of the function applied to the given arguments, if the call can be inlined.
+    fn inline(&self, arguments: &[Expression], only_call: bool) -> Option<Expression> {
+        if arguments.len() != self.parameters.len() {
+            return None;
+        }
+
+        let arguments = self
+            .parameters
+            .iter()
+            .zip(arguments.iter())
+            .map(|((_, type_), argument)| type_implicits(argument.clone(), type_))
+            .collect::<Vec<_>>();
+        let values = self
+            .parameters
+            .iter()
+            .map(|(name, _)| name.clone())
+            .zip(arguments.iter().cloned())
+            .collect::<HashMap<_, _>>();
+
+        let inlined = fold_expression(substitute(self.body.clone(), &values));
+
+        if is_literal(&inlined) && arguments.iter().all(is_literal) {
+            return Some(inlined);
+        }
+
+        // Every argument must be evaluated exactly once, as it would be by the call.
+        let evaluated_once = self
+            .parameters
+            .iter()
+            .zip(arguments.iter())
+            .all(|((name, _), argument)| {
+                is_literal(argument) || matches!(argument, Expression::Identifier(_)) || uses(&self.body, name) == 1
+            });
+
+        if only_call && evaluated_once {
+            Some(inlined)
+        } else {
+            None
+        }
+    }
+}
+
+fn inlinable_functions(program: &Program) -> HashMap<String, InlinableFunction> {
+    program
+        .functions
+        .iter()
+        .filter(|(name, _)| name.name != MAIN_FUNCTION_NAME)
+        .filter_map(|(name, function)| InlinableFunction::new(function).map(|inlinable| (name.name.clone(), inlinable)))
+        .collect()
+}
+
+/// Returns the number of call sites of each function called by name.
+fn count_calls(program: &Program) -> HashMap<String, usize> {
+    let mut calls = HashMap::new();
+    let mut count = |function: &Function| {
+        crate::for_each_expression(&function.statements, &mut |expression| {
+            if let Expression::FunctionCall(function, _, _) = expression {
+                if let Expression::Identifier(name) = function.as_ref() {
+                    *calls.entry(name.name.clone()).or_insert(0) += 1;
+                }
+            }
+        })
+    };
+
+    program.functions.values().for_each(&mut count);
+    program.tests.values().for_each(|test| count(&test.function));
+    for circuit in program.circuits.values() {
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitFunction(_, function) = member {
+                count(function);
+            }
+        }
+    }
+
+    calls
+}
+
+fn is_scalar(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::Address | Type::Boolean | Type::Field | Type::Group | Type::IntegerType(_)
+    )
+}
+
+fn size(expression: &Expression) -> usize {
+    1 + children(expression).into_iter().map(size).sum::<usize>()
+}
+
+fn uses(expression: &Expression, name: &str) -> usize {
+    let own = match expression {
+        Expression::Identifier(identifier) if identifier.name == name => 1,
+        _ => 0,
+    };
+
+    own + children(expression)
+        .into_iter()
+        .map(|child| uses(child, name))
+        .sum::<usize>()
+}
+
+/// Returns `true` if the expression only reads parameters and does not call or construct anything.
+fn is_self_contained(expression: &Expression, is_parameter: &dyn Fn(&str) -> bool) -> bool {
+    let allowed = match expression {
+        Expression::Identifier(identifier) => is_parameter(&identifier.name),
+        Expression::Array(_, _)
+        | Expression::ArrayAccess(_, _, _)
+        | Expression::Tuple(_, _)
+        | Expression::TupleAccess(_, _, _)
+        | Expression::Circuit(_, _, _)
+        | Expression::CircuitMemberAccess(_, _, _)
+        | Expression::CircuitStaticFunctionAccess(_, _, _)
+        | Expression::FunctionCall(_, _, _)
+        | Expression::CoreFunctionCall(_, _, _) => false,
+        _ => true,
+    };
+
+    allowed
+        && children(expression)
+            .into_iter()
+            .all(|child| is_self_contained(child, is_parameter))
+}
+
+/// Gives implicitly typed numbers the type the compiler infers from the expected type of the expression.
+fn type_implicits(expression: Expression, type_: &Type) -> Expression {
+    match expression {
+        Expression::Implicit(value, span) => match type_ {
+            Type::IntegerType(integer_type) => Expression::Integer(integer_type.clone(), value, span),
+            Type::Field => Expression::Field(value, span),
+            _ => Expression::Implicit(value, span),
+        },
+        Expression::IfElse(condition, first, second, span) => Expression::IfElse(
+            condition,
+            Box::new(type_implicits(*first, type_)),
+            Box::new(type_implicits(*second, type_)),
+            span,
+        ),
+        expression @ Expression::Add(_, _, _)
+        | expression @ Expression::Sub(_, _, _)
+        | expression @ Expression::Mul(_, _, _)
+        | expression @ Expression::Div(_, _, _)
+        | expression @ Expression::Pow(_, _, _)
+        | expression @ Expression::Rem(_, _, _)
+        | expression @ Expression::BitAnd(_, _, _)
+        | expression @ Expression::BitOr(_, _, _)
+        | expression @ Expression::BitXor(_, _, _)
+        | expression @ Expression::Negate(_, _) => map_children(expression, &mut |child| type_implicits(child, type_)),
+        expression => expression,
+    }
+}
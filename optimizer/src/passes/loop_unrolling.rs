@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unrolls for loops whose bounds are constants.
+//!
+//! Each iteration becomes a loop over a single index with the index replaced by its value, so the
+//! body can be folded and pruned per iteration while keeping its own constraint namespace.
+
+use crate::{fold_statements, map_expressions, prune_statements, substitute};
+use leo_typed::{
+    Assignee,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    Expression,
+    Identifier,
+    IntegerType,
+    Span,
+    Statement,
+};
+
+use std::collections::HashMap;
+
+/// The largest number of iterations a loop is unrolled for.
+pub const MAX_UNROLLED_ITERATIONS: u32 = 256;
+
+/// Unrolls every loop with constant bounds in the given statements, including nested blocks.
+pub fn unroll_statements(statements: Vec<Statement>) -> Vec<Statement> {
+    let mut unrolled = Vec::with_capacity(statements.len());
+
+    for statement in statements {
+        match statement {
+            Statement::Iteration(index, start, stop, statements, span) => {
+                unrolled.extend(unroll_loop(index, start, stop, statements, span))
+            }
+            Statement::Conditional(conditional, span) => {
+                unrolled.push(Statement::Conditional(unroll_conditional(conditional), span))
+            }
+            statement => unrolled.push(statement),
+        }
+    }
+
+    unrolled
+}
+
+fn unroll_loop(
+    index: Identifier,
+    start: Expression,
+    stop: Expression,
+    statements: Vec<Statement>,
+    span: Span,
+) -> Vec<Statement> {
+    let bounds = match (bound(&start), bound(&stop)) {
+        (Some(start), Some(stop)) if stop.saturating_sub(start) <= MAX_UNROLLED_ITERATIONS => Some((start, stop)),
+        _ => None,
+    };
+
+    match bounds {
+        Some((start, stop)) if !binds(&statements, &index.name) => (start..stop)
+            .filter_map(|value| {
+                let mut values = HashMap::new();
+                values.insert(index.name.clone(), index_value(value, &index.span));
+
+                let statements = map_expressions(statements.clone(), &mut |expression| substitute(expression, &values));
+                let statements = unroll_statements(prune_statements(fold_statements(statements)));
+
+                // Iterations left without statements are dropped.
+                if statements.is_empty() {
+                    return None;
+                }
+
+                Some(Statement::Iteration(
+                    index.clone(),
+                    index_value(value, &span),
+                    index_value(value + 1, &span),
+                    statements,
+                    span.clone(),
+                ))
+            })
+            .collect(),
+        _ => vec![Statement::Iteration(
+            index,
+            start,
+            stop,
+            unroll_statements(statements),
+            span,
+        )],
+    }
+}
+
+fn unroll_conditional(conditional: ConditionalStatement) -> ConditionalStatement {
+    ConditionalStatement {
+        condition: conditional.condition,
+        statements: unroll_statements(conditional.statements),
+        next: conditional.next.map(|next| match next {
+            ConditionalNestedOrEndStatement::Nested(nested) => {
+                ConditionalNestedOrEndStatement::Nested(Box::new(unroll_conditional(*nested)))
+            }
+            ConditionalNestedOrEndStatement::End(statements) => {
+                ConditionalNestedOrEndStatement::End(unroll_statements(statements))
+            }
+        }),
+    }
+}
+
+/// Returns the value of a constant loop bound.
+fn bound(expression: &Expression) -> Option<u32> {
+    match expression {
+        Expression::Integer(IntegerType::U32, value, _) | Expression::Implicit(value, _) => value.parse::<u32>().ok(),
+        _ => None,
+    }
+}
+
+fn index_value(value: u32, span: &Span) -> Expression {
+    Expression::Integer(IntegerType::U32, value.to_string(), span.clone())
+}
+
+/// Returns `true` if the statements define, assign or loop over a variable with the given name.
+fn binds(statements: &[Statement], name: &str) -> bool {
+    fn assigns(assignee: &Assignee, name: &str) -> bool {
+        match assignee {
+            Assignee::Identifier(identifier) => identifier.name == name,
+            Assignee::Array(assignee, _) | Assignee::Tuple(assignee, _) | Assignee::CircuitField(assignee, _) => {
+                assigns(assignee, name)
+            }
+        }
+    }
+
+    fn conditional_binds(conditional: &ConditionalStatement, name: &str) -> bool {
+        binds(&conditional.statements, name)
+            || match &conditional.next {
+                Some(ConditionalNestedOrEndStatement::Nested(nested)) => conditional_binds(nested, name),
+                Some(ConditionalNestedOrEndStatement::End(statements)) => binds(statements, name),
+                None => false,
+            }
+    }
+
+    statements.iter().any(|statement| match statement {
+        Statement::Definition(_, variables, _, _) => {
+            variables.names.iter().any(|variable| variable.identifier.name == name)
+        }
+        Statement::Assign(assignee, _, _) => assigns(assignee, name),
+        Statement::Conditional(conditional, _) => conditional_binds(conditional, name),
+        Statement::Iteration(index, _, _, statements, _) => index.name == name || binds(statements, name),
+        _ => false,
+    })
+}
@@ -0,0 +1,377 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generic traversals over typed statements and expressions shared by the optimization passes.
+
+use leo_typed::{
+    Assignee,
+    CircuitVariableDefinition,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConsoleFunctionCall,
+    Expression,
+    FormattedString,
+    RangeOrExpression,
+    SpreadOrExpression,
+    Statement,
+};
+
+use std::collections::HashMap;
+
+/// Returns the direct sub-expressions of the given expression.
+pub fn children(expression: &Expression) -> Vec<&Expression> {
+    match expression {
+        Expression::Identifier(_)
+        | Expression::Address(_, _)
+        | Expression::Boolean(_, _)
+        | Expression::Field(_, _)
+        | Expression::Group(_)
+        | Expression::Implicit(_, _)
+        | Expression::Integer(_, _, _) => vec![],
+
+        Expression::Add(left, right, _)
+        | Expression::Sub(left, right, _)
+        | Expression::Mul(left, right, _)
+        | Expression::Div(left, right, _)
+        | Expression::Pow(left, right, _)
+        | Expression::Rem(left, right, _)
+        | Expression::BitAnd(left, right, _)
+        | Expression::BitOr(left, right, _)
+        | Expression::BitXor(left, right, _)
+        | Expression::Shl(left, right, _)
+        | Expression::Shr(left, right, _)
+        | Expression::Or(left, right, _)
+        | Expression::And(left, right, _)
+        | Expression::Eq(left, right, _)
+        | Expression::Ge(left, right, _)
+        | Expression::Gt(left, right, _)
+        | Expression::Le(left, right, _)
+        | Expression::Lt(left, right, _) => vec![left, right],
+
+        Expression::Cast(expression, _, _)
+        | Expression::Not(expression, _)
+        | Expression::Negate(expression, _)
+        | Expression::TupleAccess(expression, _, _)
+        | Expression::CircuitMemberAccess(expression, _, _)
+        | Expression::CircuitStaticFunctionAccess(expression, _, _) => vec![expression],
+
+        Expression::IfElse(condition, first, second, _) => vec![condition, first, second],
+
+        Expression::Array(elements, _) => elements
+            .iter()
+            .map(|element| match element.as_ref() {
+                SpreadOrExpression::Spread(expression) | SpreadOrExpression::Expression(expression) => expression,
+            })
+            .collect(),
+        Expression::ArrayAccess(array, index, _) => {
+            let mut expressions = vec![array.as_ref()];
+            match index.as_ref() {
+                RangeOrExpression::Range(from, to) => expressions.extend(from.iter().chain(to.iter())),
+                RangeOrExpression::Expression(expression) => expressions.push(expression),
+            }
+            expressions
+        }
+
+        Expression::Tuple(elements, _) => elements.iter().collect(),
+        Expression::Circuit(_, members, _) => members.iter().map(|member| &member.expression).collect(),
+
+        Expression::FunctionCall(function, arguments, _) => {
+            std::iter::once(function.as_ref()).chain(arguments.iter()).collect()
+        }
+        Expression::CoreFunctionCall(_, arguments, _) => arguments.iter().collect(),
+    }
+}
+
+/// Rebuilds the given expression with `f` applied to each of its direct sub-expressions.
+pub fn map_children<F>(expression: Expression, f: &mut F) -> Expression
+where
+    F: FnMut(Expression) -> Expression,
+{
+    let mut map = |expression: Box<Expression>| Box::new(f(*expression));
+
+    match expression {
+        Expression::Add(left, right, span) => Expression::Add(map(left), map(right), span),
+        Expression::Sub(left, right, span) => Expression::Sub(map(left), map(right), span),
+        Expression::Mul(left, right, span) => Expression::Mul(map(left), map(right), span),
+        Expression::Div(left, right, span) => Expression::Div(map(left), map(right), span),
+        Expression::Pow(left, right, span) => Expression::Pow(map(left), map(right), span),
+        Expression::Rem(left, right, span) => Expression::Rem(map(left), map(right), span),
+        Expression::BitAnd(left, right, span) => Expression::BitAnd(map(left), map(right), span),
+        Expression::BitOr(left, right, span) => Expression::BitOr(map(left), map(right), span),
+        Expression::BitXor(left, right, span) => Expression::BitXor(map(left), map(right), span),
+        Expression::Shl(left, right, span) => Expression::Shl(map(left), map(right), span),
+        Expression::Shr(left, right, span) => Expression::Shr(map(left), map(right), span),
+        Expression::Or(left, right, span) => Expression::Or(map(left), map(right), span),
+        Expression::And(left, right, span) => Expression::And(map(left), map(right), span),
+        Expression::Eq(left, right, span) => Expression::Eq(map(left), map(right), span),
+        Expression::Ge(left, right, span) => Expression::Ge(map(left), map(right), span),
+        Expression::Gt(left, right, span) => Expression::Gt(map(left), map(right), span),
+        Expression::Le(left, right, span) => Expression::Le(map(left), map(right), span),
+        Expression::Lt(left, right, span) => Expression::Lt(map(left), map(right), span),
+
+        Expression::Cast(expression, type_, span) => Expression::Cast(map(expression), type_, span),
+        Expression::Not(expression, span) => Expression::Not(map(expression), span),
+        Expression::Negate(expression, span) => Expression::Negate(map(expression), span),
+        Expression::TupleAccess(expression, index, span) => Expression::TupleAccess(map(expression), index, span),
+        Expression::CircuitMemberAccess(expression, member, span) => {
+            Expression::CircuitMemberAccess(map(expression), member, span)
+        }
+        Expression::CircuitStaticFunctionAccess(expression, member, span) => {
+            Expression::CircuitStaticFunctionAccess(map(expression), member, span)
+        }
+
+        Expression::IfElse(condition, first, second, span) => {
+            Expression::IfElse(map(condition), map(first), map(second), span)
+        }
+
+        Expression::Array(elements, span) => Expression::Array(
+            elements
+                .into_iter()
+                .map(|element| {
+                    Box::new(match *element {
+                        SpreadOrExpression::Spread(expression) => SpreadOrExpression::Spread(f(expression)),
+                        SpreadOrExpression::Expression(expression) => SpreadOrExpression::Expression(f(expression)),
+                    })
+                })
+                .collect(),
+            span,
+        ),
+        Expression::ArrayAccess(array, index, span) => {
+            let array = map(array);
+            let index = match *index {
+                RangeOrExpression::Range(from, to) => RangeOrExpression::Range(from.map(&mut *f), to.map(&mut *f)),
+                RangeOrExpression::Expression(expression) => RangeOrExpression::Expression(f(expression)),
+            };
+
+            Expression::ArrayAccess(array, Box::new(index), span)
+        }
+
+        Expression::Tuple(elements, span) => Expression::Tuple(elements.into_iter().map(f).collect(), span),
+        Expression::Circuit(name, members, span) => Expression::Circuit(
+            name,
+            members
+                .into_iter()
+                .map(|member| CircuitVariableDefinition {
+                    identifier: member.identifier,
+                    expression: f(member.expression),
+                })
+                .collect(),
+            span,
+        ),
+
+        Expression::FunctionCall(function, arguments, span) => {
+            Expression::FunctionCall(map(function), arguments.into_iter().map(f).collect(), span)
+        }
+        Expression::CoreFunctionCall(name, arguments, span) => {
+            Expression::CoreFunctionCall(name, arguments.into_iter().map(f).collect(), span)
+        }
+
+        expression => expression,
+    }
+}
+
+/// Replaces each variable named in `values` with its value, leaving function and circuit names untouched.
+pub fn substitute(expression: Expression, values: &HashMap<String, Expression>) -> Expression {
+    match expression {
+        Expression::Identifier(identifier) => match values.get(&identifier.name) {
+            Some(value) => value.clone(),
+            None => Expression::Identifier(identifier),
+        },
+        Expression::FunctionCall(function, arguments, span) => {
+            let function = match *function {
+                Expression::Identifier(name) => Expression::Identifier(name),
+                function => substitute(function, values),
+            };
+            let arguments = arguments
+                .into_iter()
+                .map(|argument| substitute(argument, values))
+                .collect();
+
+            Expression::FunctionCall(Box::new(function), arguments, span)
+        }
+        Expression::CircuitStaticFunctionAccess(circuit, member, span) => {
+            Expression::CircuitStaticFunctionAccess(circuit, member, span)
+        }
+        expression => map_children(expression, &mut |child| substitute(child, values)),
+    }
+}
+
+/// Calls `f` on every expression in the given statements, including sub-expressions and nested blocks.
+pub fn for_each_expression<F>(statements: &[Statement], f: &mut F)
+where
+    F: FnMut(&Expression),
+{
+    fn visit<F: FnMut(&Expression)>(expression: &Expression, f: &mut F) {
+        f(expression);
+        for child in children(expression) {
+            visit(child, f);
+        }
+    }
+
+    fn visit_assignee<F: FnMut(&Expression)>(assignee: &Assignee, f: &mut F) {
+        match assignee {
+            Assignee::Identifier(_) => {}
+            Assignee::Array(assignee, index) => {
+                visit_assignee(assignee, f);
+                match index {
+                    RangeOrExpression::Range(from, to) => {
+                        from.iter().chain(to.iter()).for_each(|expression| visit(expression, f))
+                    }
+                    RangeOrExpression::Expression(expression) => visit(expression, f),
+                }
+            }
+            Assignee::Tuple(assignee, _) | Assignee::CircuitField(assignee, _) => visit_assignee(assignee, f),
+        }
+    }
+
+    fn visit_conditional<F: FnMut(&Expression)>(conditional: &ConditionalStatement, f: &mut F) {
+        visit(&conditional.condition, f);
+        for_each_expression(&conditional.statements, f);
+        match &conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => visit_conditional(nested, f),
+            Some(ConditionalNestedOrEndStatement::End(statements)) => for_each_expression(statements, f),
+            None => {}
+        }
+    }
+
+    for statement in statements {
+        match statement {
+            Statement::Return(expression, _) | Statement::Expression(expression, _) => visit(expression, f),
+            Statement::Definition(_, _, expressions, _) => {
+                expressions.iter().for_each(|expression| visit(expression, f))
+            }
+            Statement::Assign(assignee, expression, _) => {
+                visit_assignee(assignee, f);
+                visit(expression, f);
+            }
+            Statement::Conditional(conditional, _) => visit_conditional(conditional, f),
+            Statement::Iteration(_, start, stop, statements, _) => {
+                visit(start, f);
+                visit(stop, f);
+                for_each_expression(statements, f);
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => visit(expression, f),
+                ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                    string
+                        .parameters
+                        .iter()
+                        .for_each(|parameter| visit(&parameter.expression, f))
+                }
+            },
+        }
+    }
+}
+
+/// Rebuilds the given statements with `f` applied to each top-level expression, recursing into nested blocks.
+///
+/// Sub-expressions are not visited; `f` decides whether to recurse with [`map_children`].
+pub fn map_expressions<F>(statements: Vec<Statement>, f: &mut F) -> Vec<Statement>
+where
+    F: FnMut(Expression) -> Expression,
+{
+    statements
+        .into_iter()
+        .map(|statement| map_statement_expressions(statement, f))
+        .collect()
+}
+
+fn map_statement_expressions<F>(statement: Statement, f: &mut F) -> Statement
+where
+    F: FnMut(Expression) -> Expression,
+{
+    match statement {
+        Statement::Return(expression, span) => Statement::Return(f(expression), span),
+        Statement::Definition(declare, variables, expressions, span) => {
+            Statement::Definition(declare, variables, expressions.into_iter().map(f).collect(), span)
+        }
+        Statement::Assign(assignee, expression, span) => {
+            Statement::Assign(map_assignee(assignee, f), f(expression), span)
+        }
+        Statement::Conditional(conditional, span) => Statement::Conditional(map_conditional(conditional, f), span),
+        Statement::Iteration(index, start, stop, statements, span) => {
+            Statement::Iteration(index, f(start), f(stop), map_expressions(statements, f), span)
+        }
+        Statement::Console(console) => Statement::Console(ConsoleFunctionCall {
+            function: match console.function {
+                ConsoleFunction::Assert(expression) => ConsoleFunction::Assert(f(expression)),
+                ConsoleFunction::Debug(string) => ConsoleFunction::Debug(map_formatted_string(string, f)),
+                ConsoleFunction::Error(string) => ConsoleFunction::Error(map_formatted_string(string, f)),
+                ConsoleFunction::Log(string) => ConsoleFunction::Log(map_formatted_string(string, f)),
+            },
+            span: console.span,
+        }),
+        Statement::Expression(expression, span) => Statement::Expression(f(expression), span),
+    }
+}
+
+fn map_assignee<F>(assignee: Assignee, f: &mut F) -> Assignee
+where
+    F: FnMut(Expression) -> Expression,
+{
+    match assignee {
+        Assignee::Identifier(identifier) => Assignee::Identifier(identifier),
+        Assignee::Array(assignee, index) => {
+            let assignee = Box::new(map_assignee(*assignee, f));
+            let index = match index {
+                RangeOrExpression::Range(from, to) => RangeOrExpression::Range(from.map(&mut *f), to.map(&mut *f)),
+                RangeOrExpression::Expression(expression) => RangeOrExpression::Expression(f(expression)),
+            };
+
+            Assignee::Array(assignee, index)
+        }
+        Assignee::Tuple(assignee, index) => Assignee::Tuple(Box::new(map_assignee(*assignee, f)), index),
+        Assignee::CircuitField(assignee, member) => {
+            Assignee::CircuitField(Box::new(map_assignee(*assignee, f)), member)
+        }
+    }
+}
+
+fn map_conditional<F>(conditional: ConditionalStatement, f: &mut F) -> ConditionalStatement
+where
+    F: FnMut(Expression) -> Expression,
+{
+    ConditionalStatement {
+        condition: f(conditional.condition),
+        statements: map_expressions(conditional.statements, f),
+        next: conditional.next.map(|next| match next {
+            ConditionalNestedOrEndStatement::Nested(nested) => {
+                ConditionalNestedOrEndStatement::Nested(Box::new(map_conditional(*nested, f)))
+            }
+            ConditionalNestedOrEndStatement::End(statements) => {
+                ConditionalNestedOrEndStatement::End(map_expressions(statements, f))
+            }
+        }),
+    }
+}
+
+fn map_formatted_string<F>(string: FormattedString, f: &mut F) -> FormattedString
+where
+    F: FnMut(Expression) -> Expression,
+{
+    FormattedString {
+        parameters: string
+            .parameters
+            .into_iter()
+            .map(|mut parameter| {
+                parameter.expression = f(parameter.expression);
+                parameter
+            })
+            .collect(),
+        ..string
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::optimized_main;
+use leo_optimizer::OptLevel;
+
+#[test]
+fn test_true_condition() {
+    let bytes = include_bytes!("true_condition.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["console.assert(a);"]);
+}
+
+#[test]
+fn test_false_condition() {
+    let bytes = include_bytes!("false_condition.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["console.assert(a);"]);
+}
+
+#[test]
+fn test_else_if() {
+    let bytes = include_bytes!("else_if.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec![
+        "if (a) {\n\t\tconsole.assert(a);\n\t} else {\n\t\tconsole.assert(!a);\n\t}"
+    ]);
+}
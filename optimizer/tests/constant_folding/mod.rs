@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::optimized_main;
+use leo_optimizer::OptLevel;
+
+#[test]
+fn test_integers() {
+    let bytes = include_bytes!("integers.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return 10u32"]);
+}
+
+#[test]
+fn test_booleans() {
+    let bytes = include_bytes!("booleans.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return true"]);
+}
+
+#[test]
+fn test_signed() {
+    let bytes = include_bytes!("signed.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return 4i8"]);
+}
+
+#[test]
+fn test_ternary() {
+    let bytes = include_bytes!("ternary.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return a"]);
+}
+
+#[test]
+fn test_variables() {
+    let bytes = include_bytes!("variables.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return a + 2u8"]);
+}
+
+#[test]
+fn test_overflow_is_not_folded() {
+    let bytes = include_bytes!("overflow.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return 255u8 + 1"]);
+}
+
+#[test]
+fn test_division_by_zero_is_not_folded() {
+    let bytes = include_bytes!("division_by_zero.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return 1u8 / 0u8"]);
+}
+
+#[test]
+fn test_opt_level_none() {
+    let bytes = include_bytes!("integers.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::None), vec!["return 2u32 * 3u32 + 4"]);
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::optimized_main;
+use leo_optimizer::OptLevel;
+
+#[test]
+fn test_constant_arguments() {
+    let bytes = include_bytes!("constant_arguments.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Full), vec![
+        "let b = 9u32;",
+        "return square(a) + square(a) + b",
+    ]);
+}
+
+#[test]
+fn test_single_call() {
+    let bytes = include_bytes!("single_call.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Full), vec!["return a + a"]);
+}
+
+#[test]
+fn test_not_inlined() {
+    let bytes = include_bytes!("not_inlined.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Full), vec![
+        "return sum(a, 1) + twice(a * 2)"
+    ]);
+}
+
+#[test]
+fn test_basic_does_not_inline() {
+    let bytes = include_bytes!("single_call.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic), vec!["return double(a)"]);
+}
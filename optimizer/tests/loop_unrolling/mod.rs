@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::optimized_main;
+use leo_optimizer::OptLevel;
+
+#[test]
+fn test_constant_bounds() {
+    let bytes = include_bytes!("constant_bounds.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Full), vec![
+        "let mut sum = a;",
+        "for i in 1u32..2u32 {\n\t\tsum = sum + 10;\n\t}",
+        "return sum",
+    ]);
+}
+
+#[test]
+fn test_nested() {
+    let bytes = include_bytes!("nested.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Full), vec![
+        "for i in 0u32..1u32 {\n\t\tfor j in 0u32..1u32 {\n\t\tconsole.assert(true);\n\t}\n\t\tfor j in 1u32..2u32 {\n\t\tconsole.assert(true);\n\t}\n\t}",
+        "for i in 1u32..2u32 {\n\t\tfor j in 1u32..2u32 {\n\t\tconsole.assert(true);\n\t}\n\t}",
+    ]);
+}
+
+#[test]
+fn test_empty() {
+    let bytes = include_bytes!("empty.leo");
+
+    assert!(optimized_main(bytes, OptLevel::Full).is_empty());
+}
+
+#[test]
+fn test_too_many_iterations() {
+    let bytes = include_bytes!("too_many_iterations.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Full), vec![
+        "for i in 0..1000 {\n\t\tconsole.assert(i < 1000);\n\t}"
+    ]);
+}
+
+#[test]
+fn test_basic_does_not_unroll() {
+    let bytes = include_bytes!("constant_bounds.leo");
+
+    assert_eq!(optimized_main(bytes, OptLevel::Basic).len(), 3);
+}
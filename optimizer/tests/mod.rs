@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod branch_pruning;
+pub mod constant_folding;
+pub mod function_inlining;
+pub mod loop_unrolling;
+
+use leo_ast::LeoAst;
+use leo_optimizer::{OptLevel, Optimizer};
+use leo_typed::{LeoTypedAst, Program};
+
+use std::path::PathBuf;
+
+const TEST_PROGRAM_PATH: &str = "";
+
+pub(crate) fn optimize_program(bytes: &[u8], level: OptLevel) -> Program {
+    let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+    let program_string = String::from_utf8_lossy(bytes);
+
+    // Parse the Leo file and construct a typed syntax tree.
+    let ast = LeoAst::new(&file_path, &program_string).unwrap();
+    let program = LeoTypedAst::new("test", &ast).into_repr();
+
+    Optimizer::new(level).optimize(program)
+}
+
+/// Returns each statement of the `main` function after optimizing at the given level.
+pub(crate) fn optimized_main(bytes: &[u8], level: OptLevel) -> Vec<String> {
+    let program = optimize_program(bytes, level);
+    let (_, main) = program.functions.iter().find(|(name, _)| name.name == "main").unwrap();
+
+    main.statements.iter().map(|statement| statement.to_string()).collect()
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The deployment artifact of a package.
+
+use crate::errors::DeployError;
+
+use serde::{Deserialize, Serialize};
+
+/// A compiled program with everything a node needs to verify its proofs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentArtifact {
+    pub package_name: String,
+    pub version: String,
+    /// The checksum of the main file the program was compiled from.
+    pub checksum: String,
+    /// The serialized program, as returned by `Compiler::to_bytes`.
+    pub program: Vec<u8>,
+    pub verification_key: Vec<u8>,
+}
+
+impl DeploymentArtifact {
+    pub fn new(package_name: &str, version: &str, checksum: &str, program: Vec<u8>, verification_key: Vec<u8>) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+            version: version.to_string(),
+            checksum: checksum.to_string(),
+            program,
+            verification_key,
+        }
+    }
+
+    /// Returns the identifier of the deployment, `package_name-version`.
+    pub fn id(&self) -> String {
+        format!("{}-{}", self.package_name, self.version)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DeployError> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeployError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
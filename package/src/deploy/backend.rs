@@ -0,0 +1,26 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{deploy::DeploymentArtifact, errors::DeployError};
+
+/// A destination for deployed programs, such as a node of the network.
+pub trait DeployBackend {
+    /// Returns a description of the backend for logging.
+    fn name(&self) -> String;
+
+    /// Submits the artifact and returns the identifier of the deployment.
+    fn deploy(&mut self, artifact: &DeploymentArtifact) -> Result<String, DeployError>;
+}
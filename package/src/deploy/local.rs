@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A mock node storing deployments in a local directory.
+
+use crate::{
+    deploy::{DeployBackend, DeploymentArtifact},
+    errors::DeployError,
+};
+
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::PathBuf,
+};
+
+pub static DEPLOYMENT_FILE_EXTENSION: &str = ".deploy";
+
+/// Stores each deployment as a `package_name-version.deploy` file in a directory.
+///
+/// Like a node, it refuses to replace a deployed version with a different program.
+#[derive(Clone, Debug)]
+pub struct LocalBackend {
+    pub directory: PathBuf,
+}
+
+impl LocalBackend {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Reads the deployment of the given package version if it exists.
+    pub fn fetch(&self, package_name: &str, version: &str) -> Result<Option<DeploymentArtifact>, DeployError> {
+        let path = self.deployment_file_path(&format!("{}-{}", package_name, version));
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&path).map_err(|_| DeployError::FileReadError(path.clone()))?;
+
+        Ok(Some(DeploymentArtifact::from_bytes(&bytes)?))
+    }
+
+    fn deployment_file_path(&self, id: &str) -> PathBuf {
+        self.directory.join(format!("{}{}", id, DEPLOYMENT_FILE_EXTENSION))
+    }
+}
+
+impl DeployBackend for LocalBackend {
+    fn name(&self) -> String {
+        format!("local node ({:?})", self.directory)
+    }
+
+    fn deploy(&mut self, artifact: &DeploymentArtifact) -> Result<String, DeployError> {
+        let id = artifact.id();
+
+        // Deploying the same program again is a no-op.
+        if let Some(deployed) = self.fetch(&artifact.package_name, &artifact.version)? {
+            if deployed.checksum != artifact.checksum {
+                return Err(DeployError::AlreadyDeployed(
+                    artifact.package_name.clone(),
+                    artifact.version.clone(),
+                ));
+            }
+
+            return Ok(id);
+        }
+
+        fs::create_dir_all(&self.directory).map_err(DeployError::Creating)?;
+
+        let mut file = File::create(self.deployment_file_path(&id)).map_err(DeployError::Creating)?;
+        file.write_all(&artifact.to_bytes()?).map_err(DeployError::Writing)?;
+
+        Ok(id)
+    }
+}
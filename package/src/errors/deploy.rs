@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum DeployError {
+    #[error("package `{}` version {} is already deployed with a different checksum", _0, _1)]
+    AlreadyDeployed(String, String),
+
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("creating: {}", _0)]
+    Creating(io::Error),
+
+    #[error("Deployment artifact was corrupted - {}", _0)]
+    CorruptedArtifact(String),
+
+    #[error("Cannot read from the provided file path - {:?}", _0)]
+    FileReadError(PathBuf),
+
+    #[error("writing: {}", _0)]
+    Writing(io::Error),
+}
+
+impl From<serde_json::Error> for DeployError {
+    fn from(error: serde_json::Error) -> Self {
+        DeployError::CorruptedArtifact(error.to_string())
+    }
+}
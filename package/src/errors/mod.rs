@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod deploy;
+pub use self::deploy::*;
+
 pub mod imports;
 pub use imports::*;
 
@@ -23,11 +23,17 @@ pub use checksum::*;
 pub mod directory;
 pub use self::directory::*;
 
+pub mod profile;
+pub use profile::*;
+
 pub mod proof;
 pub use proof::*;
 
 pub mod proving_key;
 pub use proving_key::*;
 
+pub mod public_inputs;
+pub use public_inputs::*;
+
 pub mod verification_key;
 pub use verification_key::*;
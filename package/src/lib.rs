@@ -20,6 +20,7 @@ extern crate thiserror;
 pub mod errors;
 pub use errors::*;
 
+pub mod deploy;
 pub mod imports;
 pub mod inputs;
 pub mod outputs;
@@ -23,11 +23,17 @@ pub use self::checksum::*;
 pub mod directory;
 pub use directory::*;
 
+pub mod profile;
+pub use self::profile::*;
+
 pub mod proof;
 pub use self::proof::*;
 
 pub mod proving_key;
 pub use self::proving_key::*;
 
+pub mod public_inputs;
+pub use self::public_inputs::*;
+
 pub mod verification_key;
 pub use self::verification_key::*;
@@ -39,16 +39,20 @@ impl ProofFile {
         }
     }
 
+    pub fn full_path(&self, path: &PathBuf) -> PathBuf {
+        self.setup_file_path(path)
+    }
+
     pub fn exists_at(&self, path: &PathBuf) -> bool {
         let path = self.setup_file_path(path);
         path.exists()
     }
 
     /// Reads the proof from the given file path if it exists.
-    pub fn read_from(&self, path: &PathBuf) -> Result<String, ProofFileError> {
+    pub fn read_from(&self, path: &PathBuf) -> Result<Vec<u8>, ProofFileError> {
         let path = self.setup_file_path(path);
 
-        let proof = fs::read_to_string(&path).map_err(|_| ProofFileError::FileReadError(path.clone()))?;
+        let proof = fs::read(&path).map_err(|_| ProofFileError::FileReadError(path.clone()))?;
         Ok(proof)
     }
 
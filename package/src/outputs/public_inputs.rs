@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The public inputs file.
+
+use crate::{errors::PublicInputsFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+
+use serde::Deserialize;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::PathBuf,
+};
+
+pub static PUBLIC_INPUTS_FILE_EXTENSION: &str = ".public";
+
+#[derive(Deserialize)]
+pub struct PublicInputsFile {
+    pub package_name: String,
+}
+
+impl PublicInputsFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn full_path(&self, path: &PathBuf) -> PathBuf {
+        self.setup_file_path(path)
+    }
+
+    pub fn exists_at(&self, path: &PathBuf) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the public inputs from the given file path if it exists.
+    pub fn read_from(&self, path: &PathBuf) -> Result<Vec<u8>, PublicInputsFileError> {
+        let path = self.setup_file_path(path);
+
+        Ok(fs::read(&path).map_err(|_| PublicInputsFileError::FileReadError(path.clone()))?)
+    }
+
+    /// Writes the given public inputs to a file.
+    pub fn write_to(&self, path: &PathBuf, public_inputs: &[u8]) -> Result<PathBuf, PublicInputsFileError> {
+        let path = self.setup_file_path(path);
+
+        let mut file = File::create(&path)?;
+        file.write_all(public_inputs)?;
+
+        Ok(path)
+    }
+
+    /// Removes the public inputs at the given path if it exists. Returns `true` on success,
+    /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
+    pub fn remove(&self, path: &PathBuf) -> Result<bool, PublicInputsFileError> {
+        let path = self.setup_file_path(path);
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&path).map_err(|_| PublicInputsFileError::FileRemovalError(path.clone()))?;
+        Ok(true)
+    }
+
+    fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
+            }
+            path.push(PathBuf::from(format!(
+                "{}{}",
+                self.package_name, PUBLIC_INPUTS_FILE_EXTENSION
+            )));
+        }
+        path
+    }
+}
@@ -14,10 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::ManifestError, package::Package};
+use crate::{
+    errors::ManifestError,
+    package::Package,
+    root::{Proving, ProvingSystem},
+};
 
 use serde::Deserialize;
 use std::{
+    collections::HashMap,
     convert::TryFrom,
     fs::File,
     io::{Read, Write},
@@ -35,6 +40,8 @@ pub struct Remote {
 pub struct Manifest {
     pub project: Package,
     pub remote: Option<Remote>,
+    pub lint: Option<HashMap<String, String>>,
+    pub proving: Option<Proving>,
 }
 
 impl Manifest {
@@ -42,6 +49,8 @@ impl Manifest {
         Self {
             project: Package::new(package_name),
             remote: None,
+            lint: None,
+            proving: None,
         }
     }
 
@@ -77,6 +86,16 @@ impl Manifest {
         self.remote.clone()
     }
 
+    /// Returns the level of each lint rule configured in the `[lint]` section.
+    pub fn get_lint_levels(&self) -> HashMap<String, String> {
+        self.lint.clone().unwrap_or_default()
+    }
+
+    /// Returns the proving system configured in the `[proving]` section, or Groth16 by default.
+    pub fn get_proving_system(&self) -> ProvingSystem {
+        self.proving.as_ref().map(|proving| proving.system).unwrap_or_default()
+    }
+
     pub fn write_to(self, path: &PathBuf) -> Result<(), ManifestError> {
         let mut path = path.to_owned();
         if path.is_dir() {
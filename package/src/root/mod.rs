@@ -20,6 +20,9 @@ pub use self::gitignore::*;
 pub mod manifest;
 pub use self::manifest::*;
 
+pub mod proving;
+pub use self::proving::*;
+
 pub mod readme;
 pub use self::readme::*;
 
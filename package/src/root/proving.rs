@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `[proving]` section of the manifest.
+
+use serde::Deserialize;
+use std::fmt;
+
+/// The prefix of the header that records which proving system produced a key or proof file.
+pub static PROVING_SYSTEM_HEADER_PREFIX: &[u8] = b"leo-proving-system:";
+
+/// The proving systems a package can be set up, proven and verified with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProvingSystem {
+    #[default]
+    Groth16,
+    GM17,
+    Marlin,
+}
+
+impl ProvingSystem {
+    /// Returns the proving system with the given manifest name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "groth16" => Some(ProvingSystem::Groth16),
+            "gm17" => Some(ProvingSystem::GM17),
+            "marlin" => Some(ProvingSystem::Marlin),
+            _ => None,
+        }
+    }
+
+    /// Prepends a header naming this proving system to the given key or proof bytes.
+    pub fn write_header(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut output = PROVING_SYSTEM_HEADER_PREFIX.to_vec();
+        output.extend_from_slice(self.to_string().as_bytes());
+        output.push(b'\n');
+        output.extend_from_slice(bytes);
+
+        output
+    }
+
+    /// Returns the proving system named in the header of the given key or proof bytes,
+    /// and the bytes following the header.
+    pub fn read_header(bytes: &[u8]) -> Option<(Self, &[u8])> {
+        if !bytes.starts_with(PROVING_SYSTEM_HEADER_PREFIX) {
+            return None;
+        }
+
+        let bytes = &bytes[PROVING_SYSTEM_HEADER_PREFIX.len()..];
+        let end = bytes.iter().position(|byte| *byte == b'\n')?;
+        let name = std::str::from_utf8(&bytes[..end]).ok()?;
+
+        Some((Self::from_name(name)?, &bytes[end + 1..]))
+    }
+}
+
+impl fmt::Display for ProvingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvingSystem::Groth16 => write!(f, "groth16"),
+            ProvingSystem::GM17 => write!(f, "gm17"),
+            ProvingSystem::Marlin => write!(f, "marlin"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Proving {
+    pub system: ProvingSystem,
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for deploying to the local backend
+
+use crate::test_dir;
+use leo_package::{
+    deploy::{DeployBackend, DeploymentArtifact, LocalBackend},
+    errors::DeployError,
+};
+
+fn artifact(checksum: &str) -> DeploymentArtifact {
+    DeploymentArtifact::new("test-package", "0.1.0", checksum, vec![1, 2, 3], vec![4, 5, 6])
+}
+
+#[test]
+fn deploy_and_fetch() {
+    let mut backend = LocalBackend::new(test_dir().join("node"));
+    let artifact = artifact("abc");
+
+    assert_eq!(backend.fetch("test-package", "0.1.0").unwrap(), None);
+    assert_eq!(backend.deploy(&artifact).unwrap(), "test-package-0.1.0");
+    assert_eq!(backend.fetch("test-package", "0.1.0").unwrap(), Some(artifact));
+}
+
+#[test]
+fn redeploy_same_checksum() {
+    let mut backend = LocalBackend::new(test_dir());
+
+    backend.deploy(&artifact("abc")).unwrap();
+    assert_eq!(backend.deploy(&artifact("abc")).unwrap(), "test-package-0.1.0");
+}
+
+#[test]
+fn redeploy_different_checksum_fails() {
+    let mut backend = LocalBackend::new(test_dir());
+
+    backend.deploy(&artifact("abc")).unwrap();
+    match backend.deploy(&artifact("def")) {
+        Err(DeployError::AlreadyDeployed(name, version)) => {
+            assert_eq!((name.as_str(), version.as_str()), ("test-package", "0.1.0"))
+        }
+        result => panic!("expected an already deployed error, found {:?}", result),
+    }
+
+    // The first deployment is kept.
+    let deployed = backend.fetch("test-package", "0.1.0").unwrap().unwrap();
+    assert_eq!(deployed.checksum, "abc");
+}
@@ -15,3 +15,5 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod manifest;
+
+pub mod proving;
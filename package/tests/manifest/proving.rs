@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for the `[proving]` section of the package manifest
+
+use crate::test_dir;
+use leo_package::root::{Manifest, ProvingSystem, MANIFEST_FILENAME};
+
+use std::{convert::TryFrom, fs, path::PathBuf};
+
+const MANIFEST: &str = r#"[project]
+name = "test-package"
+version = "0.1.0"
+description = "Testing the proving section."
+license = "MIT"
+"#;
+
+/// Create a manifest file with the given extra sections.
+fn create_manifest_file(sections: &str) -> PathBuf {
+    let mut path = test_dir();
+    path.push(PathBuf::from(MANIFEST_FILENAME));
+
+    fs::write(&path, format!("{}{}", MANIFEST, sections)).unwrap();
+
+    path
+}
+
+#[test]
+fn test_proving_system_default() {
+    let manifest_path = create_manifest_file("");
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+
+    assert_eq!(ProvingSystem::Groth16, manifest.get_proving_system());
+}
+
+#[test]
+fn test_proving_system_gm17() {
+    let manifest_path = create_manifest_file("\n[proving]\nsystem = \"gm17\"\n");
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+
+    assert_eq!(ProvingSystem::GM17, manifest.get_proving_system());
+}
+
+#[test]
+fn test_proving_system_unknown() {
+    let manifest_path = create_manifest_file("\n[proving]\nsystem = \"plonk\"\n");
+
+    assert!(Manifest::try_from(&manifest_path).is_err());
+}
+
+#[test]
+fn test_proving_system_header() {
+    let bytes = [1u8, 2, 3, b'\n'];
+
+    for system in &[ProvingSystem::Groth16, ProvingSystem::GM17, ProvingSystem::Marlin] {
+        let file = system.write_header(&bytes);
+        let (header_system, contents) = ProvingSystem::read_header(&file).unwrap();
+
+        assert_eq!(*system, header_system);
+        assert_eq!(&bytes[..], contents);
+    }
+}
+
+#[test]
+fn test_proving_system_header_missing() {
+    assert!(ProvingSystem::read_header(&[1u8, 2, 3]).is_none());
+    assert!(ProvingSystem::read_header(b"leo-proving-system:plonk\n").is_none());
+}
@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{InferredType, Scope, SymbolTable, TypeError, TypeInferenceError};
+use leo_typed::{Circuit, CircuitMember, Function, Identifier, InputVariable, Program, Span, Type};
+
+use std::collections::HashMap;
+
+/// Checks the functions, circuits, and tests of a program for type errors.
+///
+/// Errors are collected rather than returned early so that every error in the program is reported at once.
+pub struct TypeChecker {
+    pub(crate) symbols: SymbolTable,
+    pub(crate) errors: Vec<TypeError>,
+
+    /// The circuit of the function being checked.
+    pub(crate) circuit: Option<Circuit>,
+    /// `true` if the function being checked can access `self`.
+    pub(crate) self_allowed: bool,
+    /// The declared return type of the function being checked.
+    pub(crate) return_type: InferredType,
+    pub(crate) scope: Scope,
+
+    /// The type of every variable defined in the program, in order of definition.
+    pub(crate) variables: Vec<(Identifier, InferredType)>,
+}
+
+impl TypeChecker {
+    pub fn new(symbols: SymbolTable) -> Self {
+        Self {
+            symbols,
+            errors: vec![],
+            circuit: None,
+            self_allowed: false,
+            return_type: InferredType::empty(),
+            scope: Scope::new(),
+            variables: vec![],
+        }
+    }
+
+    /// Checks the given program and returns every type error found, ordered by position.
+    /// Imported programs are keyed by file name, as stored by the compiler's import parser.
+    pub fn check_program(program: &Program, imports: &HashMap<String, Program>) -> Result<(), TypeInferenceError> {
+        Self::check(program, imports).into_result()
+    }
+
+    /// Checks the given program and returns the checker, holding the errors and variable types found.
+    pub fn check(program: &Program, imports: &HashMap<String, Program>) -> Self {
+        let (symbols, errors) = SymbolTable::new(program, imports);
+
+        let mut checker = Self::new(symbols);
+        checker.errors = errors;
+
+        for circuit in program.circuits.values() {
+            checker.check_circuit(circuit);
+        }
+
+        for function in program.functions.values() {
+            checker.check_function(function, None, false);
+        }
+
+        for test in program.tests.values() {
+            checker.check_function(&test.function, None, false);
+        }
+
+        checker
+    }
+
+    /// Returns the type of every variable defined in the program, in order of definition.
+    pub fn variables(&self) -> &[(Identifier, InferredType)] {
+        &self.variables
+    }
+
+    /// Returns the errors found so far, ordered by position.
+    pub fn into_result(mut self) -> Result<(), TypeInferenceError> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        self.errors.sort_by_key(|error| match error {
+            TypeError::Error(error) => (error.line, error.start),
+            _ => (0, 0),
+        });
+
+        Err(TypeInferenceError::new(self.errors))
+    }
+
+    pub fn check_circuit(&mut self, circuit: &Circuit) {
+        for member in circuit.members.iter() {
+            match member {
+                CircuitMember::CircuitVariable(_mutable, identifier, type_) => {
+                    self.circuit = Some(circuit.clone());
+                    self.resolve_type(type_, &identifier.span);
+                }
+                CircuitMember::CircuitFunction(is_static, function) => {
+                    self.check_function(function, Some(circuit), !is_static);
+                }
+            }
+        }
+
+        self.circuit = None;
+    }
+
+    pub fn check_function(&mut self, function: &Function, circuit: Option<&Circuit>, self_allowed: bool) {
+        self.circuit = circuit.cloned();
+        self.self_allowed = self_allowed;
+        self.scope = Scope::new();
+
+        for input in function.input.iter() {
+            match input {
+                InputVariable::InputKeyword(identifier) => {
+                    self.define(identifier, InferredType::Unknown);
+                }
+                InputVariable::FunctionInput(input) => {
+                    let type_ = self.resolve_type(&input.type_, &input.span);
+
+                    self.define(&input.identifier, type_);
+                }
+            }
+        }
+
+        self.return_type = match &function.returns {
+            Some(type_) => self.resolve_type(type_, &function.identifier.span),
+            None => InferredType::empty(),
+        };
+
+        for statement in function.statements.iter() {
+            self.check_statement(statement);
+        }
+    }
+
+    /// Defines a variable in the innermost block and records its type.
+    pub(crate) fn define(&mut self, identifier: &Identifier, type_: InferredType) {
+        self.scope.insert(identifier.name.clone(), type_.clone());
+        self.variables.push((identifier.clone(), type_));
+    }
+
+    /// Converts a type written in the checked program, reporting circuits that are not in scope.
+    pub(crate) fn resolve_type(&mut self, type_: &Type, span: &Span) -> InferredType {
+        match type_ {
+            Type::Array(type_, dimensions) => InferredType::array(self.resolve_type(type_, span), dimensions),
+            Type::Tuple(types) => {
+                InferredType::Tuple(types.iter().map(|type_| self.resolve_type(type_, span)).collect())
+            }
+            Type::Circuit(identifier) => match self.symbols.get_circuit(&identifier.name) {
+                Some(circuit) => InferredType::Circuit(circuit.circuit_name.clone()),
+                None => {
+                    self.errors.push(TypeError::undefined_circuit(identifier));
+
+                    InferredType::Unknown
+                }
+            },
+            Type::SelfType => match self.circuit_name() {
+                Some(identifier) => InferredType::Circuit(identifier),
+                None => {
+                    self.errors.push(TypeError::self_keyword(span.clone()));
+
+                    InferredType::Unknown
+                }
+            },
+            type_ => InferredType::from_type(type_, None),
+        }
+    }
+
+    /// Returns the name of the circuit of the function being checked.
+    pub(crate) fn circuit_name(&self) -> Option<Identifier> {
+        self.circuit.as_ref().map(|circuit| circuit.circuit_name.clone())
+    }
+
+    /// Reports an error if `actual` cannot take the `expected` type.
+    /// Returns the most specific of the two types.
+    pub(crate) fn expect_type(&mut self, expected: &InferredType, actual: &InferredType, span: &Span) -> InferredType {
+        match expected.unify(actual) {
+            Some(type_) => type_,
+            None => {
+                self.errors
+                    .push(TypeError::mismatched_types(expected, actual, span.clone()));
+
+                expected.clone()
+            }
+        }
+    }
+}
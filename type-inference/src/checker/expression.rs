@@ -0,0 +1,858 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{InferredType, TypeChecker, TypeError};
+use leo_typed::{
+    Circuit,
+    CircuitMember,
+    CircuitVariableDefinition,
+    Expression,
+    Function,
+    Identifier,
+    InputVariable,
+    IntegerType,
+    RangeOrExpression,
+    Span,
+    SpreadOrExpression,
+    Type,
+};
+
+impl TypeChecker {
+    /// Returns the type of the given expression and reports any type errors inside it.
+    /// The expected type is used to resolve numbers without a type suffix and array elements.
+    pub fn check_expression(&mut self, expression: &Expression, expected: &InferredType) -> InferredType {
+        match expression {
+            // Variables
+            Expression::Identifier(identifier) => self.check_identifier(identifier, expected),
+
+            // Values
+            Expression::Address(_, _) => InferredType::Address,
+            Expression::Boolean(_, _) => InferredType::Boolean,
+            Expression::Field(_, _) => InferredType::Field,
+            Expression::Group(_) => InferredType::Group,
+            Expression::Implicit(_, _) => {
+                if expected.is_numeric() {
+                    expected.clone()
+                } else {
+                    InferredType::Implicit
+                }
+            }
+            Expression::Integer(type_, _, _) => InferredType::IntegerType(type_.clone()),
+
+            // Number operations
+            Expression::Add(left, right, span) => self.check_arithmetic("+", left, right, expected, true, span),
+            Expression::Sub(left, right, span) => self.check_arithmetic("-", left, right, expected, true, span),
+            Expression::Mul(left, right, span) => self.check_mul(left, right, expected, span),
+            Expression::Div(left, right, span) => self.check_arithmetic("/", left, right, expected, false, span),
+            Expression::Pow(left, right, span) => {
+                let type_ = self.check_arithmetic("**", left, right, expected, false, span);
+
+                if let InferredType::Field = type_ {
+                    self.errors
+                        .push(TypeError::invalid_operation("**", &type_, span.clone()));
+                }
+
+                type_
+            }
+            Expression::Rem(left, right, span) => self.check_bitwise("%", left, right, expected, span),
+            Expression::Negate(expression, span) => {
+                let type_ = self.check_expression(expression, expected);
+
+                match type_ {
+                    InferredType::IntegerType(_)
+                    | InferredType::Field
+                    | InferredType::Group
+                    | InferredType::Implicit
+                    | InferredType::Unknown => type_,
+                    type_ => {
+                        self.errors
+                            .push(TypeError::invalid_operation("-", &type_, span.clone()));
+
+                        InferredType::Unknown
+                    }
+                }
+            }
+
+            // Bitwise operations
+            Expression::BitAnd(left, right, span) => self.check_bitwise("&", left, right, expected, span),
+            Expression::BitOr(left, right, span) => self.check_bitwise("|", left, right, expected, span),
+            Expression::BitXor(left, right, span) => self.check_bitwise("^", left, right, expected, span),
+            Expression::Shl(left, right, span) => self.check_bitwise("<<", left, right, expected, span),
+            Expression::Shr(left, right, span) => self.check_bitwise(">>", left, right, expected, span),
+
+            // Casts
+            Expression::Cast(expression, type_, span) => self.check_cast(expression, type_, span),
+
+            // Boolean operations
+            Expression::Not(expression, span) => {
+                let type_ = self.check_expression(expression, &InferredType::Boolean);
+
+                self.check_boolean_operand("!", &type_, span);
+
+                InferredType::Boolean
+            }
+            Expression::Or(left, right, span) => self.check_logical("||", left, right, span),
+            Expression::And(left, right, span) => self.check_logical("&&", left, right, span),
+            Expression::Eq(left, right, span) => {
+                let type_ = self.check_operands("==", left, right, &InferredType::Unknown, span);
+
+                if let InferredType::Circuit(_) = type_ {
+                    self.errors
+                        .push(TypeError::invalid_operation("==", &type_, span.clone()));
+                }
+
+                InferredType::Boolean
+            }
+            Expression::Ge(left, right, span) => self.check_comparison(">=", left, right, span),
+            Expression::Gt(left, right, span) => self.check_comparison(">", left, right, span),
+            Expression::Le(left, right, span) => self.check_comparison("<=", left, right, span),
+            Expression::Lt(left, right, span) => self.check_comparison("<", left, right, span),
+
+            // Conditionals
+            Expression::IfElse(condition, first, second, span) => {
+                let condition_type = self.check_expression(condition, &InferredType::Boolean);
+
+                if InferredType::Boolean.unify(&condition_type).is_none() {
+                    self.errors
+                        .push(TypeError::conditional_boolean(&condition_type, span.clone()));
+                }
+
+                let first_type = self.check_expression(first, expected);
+                let second_type = self.check_expression(second, expected);
+
+                self.expect_type(&first_type, &second_type, span)
+            }
+
+            // Arrays
+            Expression::Array(elements, span) => self.check_array(elements, expected, span),
+            Expression::ArrayAccess(array, index, span) => self.check_array_access(array, index, span),
+
+            // Tuples
+            Expression::Tuple(elements, _span) => {
+                let expected_types = match expected {
+                    InferredType::Tuple(types) if types.len() == elements.len() => types.clone(),
+                    _ => vec![InferredType::Unknown; elements.len()],
+                };
+
+                let types = elements
+                    .iter()
+                    .zip(expected_types.iter())
+                    .map(|(element, expected)| self.check_expression(element, expected))
+                    .collect();
+
+                InferredType::Tuple(types)
+            }
+            Expression::TupleAccess(tuple, index, span) => match self.check_expression(tuple, &InferredType::Unknown) {
+                InferredType::Tuple(types) => match types.get(*index) {
+                    Some(type_) => type_.clone(),
+                    None => {
+                        self.errors
+                            .push(TypeError::index_out_of_bounds(*index, types.len(), span.clone()));
+
+                        InferredType::Unknown
+                    }
+                },
+                InferredType::Unknown => InferredType::Unknown,
+                type_ => {
+                    self.errors.push(TypeError::invalid_tuple(&type_, span.clone()));
+
+                    InferredType::Unknown
+                }
+            },
+
+            // Circuits
+            Expression::Circuit(identifier, members, span) => self.check_circuit_expression(identifier, members, span),
+            Expression::CircuitMemberAccess(circuit, member, span) => {
+                let circuit_type = self.check_expression(circuit, &InferredType::Unknown);
+
+                match self.check_member_access(circuit_type, member, span) {
+                    Some((circuit_name, CircuitMember::CircuitVariable(_, _, type_))) => {
+                        InferredType::from_type(&type_, Some(&circuit_name))
+                    }
+                    _ => InferredType::Unknown,
+                }
+            }
+            Expression::CircuitStaticFunctionAccess(circuit, member, span) => {
+                self.check_static_access(circuit, member, span);
+
+                InferredType::Unknown
+            }
+
+            // Functions
+            Expression::FunctionCall(function, arguments, span) => self.check_function_call(function, arguments, span),
+            Expression::CoreFunctionCall(_, arguments, _) => {
+                for argument in arguments.iter() {
+                    self.check_expression(argument, &InferredType::Unknown);
+                }
+
+                InferredType::Unknown
+            }
+        }
+    }
+
+    fn check_identifier(&mut self, identifier: &Identifier, expected: &InferredType) -> InferredType {
+        if let Some(type_) = self.scope.get(&identifier.name) {
+            return type_.clone();
+        }
+
+        if identifier.is_self() {
+            return match self.circuit_name() {
+                Some(circuit_name) if self.self_allowed => InferredType::Circuit(circuit_name),
+                _ => {
+                    self.errors.push(TypeError::self_variable(identifier.span.clone()));
+
+                    InferredType::Unknown
+                }
+            };
+        }
+
+        // Functions and circuits are only values when they are called or accessed.
+        if self.symbols.get_function(&identifier.name).is_some() || self.symbols.get_circuit(&identifier.name).is_some()
+        {
+            return InferredType::Unknown;
+        }
+
+        // An undefined identifier is parsed as an address if one is expected.
+        if let InferredType::Address = expected {
+            return InferredType::Address;
+        }
+
+        self.errors.push(TypeError::undefined_identifier(identifier));
+
+        InferredType::Unknown
+    }
+
+    /// Checks both operands of a binary operation and returns their common type.
+    fn check_operands(
+        &mut self,
+        operation: &str,
+        left: &Expression,
+        right: &Expression,
+        expected: &InferredType,
+        span: &Span,
+    ) -> InferredType {
+        let left_type = self.check_expression(left, expected);
+        let right_type = self.check_expression(right, expected);
+
+        match left_type.unify(&right_type) {
+            Some(type_) => type_,
+            None => {
+                self.errors.push(TypeError::mismatched_operands(
+                    operation,
+                    &left_type,
+                    &right_type,
+                    span.clone(),
+                ));
+
+                InferredType::Unknown
+            }
+        }
+    }
+
+    fn check_arithmetic(
+        &mut self,
+        operation: &str,
+        left: &Expression,
+        right: &Expression,
+        expected: &InferredType,
+        allow_group: bool,
+        span: &Span,
+    ) -> InferredType {
+        let type_ = self.check_operands(operation, left, right, expected, span);
+
+        self.check_arithmetic_type(operation, type_, allow_group, span)
+    }
+
+    fn check_arithmetic_type(
+        &mut self,
+        operation: &str,
+        type_: InferredType,
+        allow_group: bool,
+        span: &Span,
+    ) -> InferredType {
+        match type_ {
+            InferredType::IntegerType(_) | InferredType::Field | InferredType::Implicit | InferredType::Unknown => {
+                type_
+            }
+            InferredType::Group if allow_group => type_,
+            type_ => {
+                self.errors
+                    .push(TypeError::invalid_operation(operation, &type_, span.clone()));
+
+                InferredType::Unknown
+            }
+        }
+    }
+
+    /// Multiplication also allows a group element to be multiplied by a field scalar.
+    fn check_mul(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+        expected: &InferredType,
+        span: &Span,
+    ) -> InferredType {
+        let left_type = self.check_expression(left, expected);
+        let right_type = self.check_expression(right, expected);
+
+        match (&left_type, &right_type) {
+            (InferredType::Group, InferredType::Field | InferredType::Unknown)
+            | (InferredType::Field | InferredType::Unknown, InferredType::Group) => {
+                return InferredType::Group;
+            }
+            _ => {}
+        }
+
+        match left_type.unify(&right_type) {
+            Some(type_) => self.check_arithmetic_type("*", type_, false, span),
+            None => {
+                self.errors.push(TypeError::mismatched_operands(
+                    "*",
+                    &left_type,
+                    &right_type,
+                    span.clone(),
+                ));
+
+                InferredType::Unknown
+            }
+        }
+    }
+
+    fn check_bitwise(
+        &mut self,
+        operation: &str,
+        left: &Expression,
+        right: &Expression,
+        expected: &InferredType,
+        span: &Span,
+    ) -> InferredType {
+        let type_ = self.check_operands(operation, left, right, expected, span);
+
+        match type_ {
+            InferredType::IntegerType(_) | InferredType::Implicit | InferredType::Unknown => type_,
+            type_ => {
+                self.errors
+                    .push(TypeError::invalid_operation(operation, &type_, span.clone()));
+
+                InferredType::Unknown
+            }
+        }
+    }
+
+    /// Casts are allowed between integers, from integers to fields and from booleans to integers.
+    fn check_cast(&mut self, expression: &Expression, type_: &Type, span: &Span) -> InferredType {
+        let source = self.check_expression(expression, &InferredType::Unknown);
+        let target = InferredType::from_type(type_, None);
+
+        let valid = match (&source, &target) {
+            (InferredType::Unknown, _) => true,
+            (InferredType::IntegerType(_), InferredType::IntegerType(_)) => true,
+            (InferredType::IntegerType(_), InferredType::Field) => true,
+            (InferredType::Implicit, InferredType::IntegerType(_)) => true,
+            (InferredType::Implicit, InferredType::Field) => true,
+            (InferredType::Boolean, InferredType::IntegerType(_)) => true,
+            _ => false,
+        };
+
+        if !valid {
+            self.errors
+                .push(TypeError::invalid_cast(&source, &target, span.clone()));
+        }
+
+        target
+    }
+
+    fn check_comparison(
+        &mut self,
+        operation: &str,
+        left: &Expression,
+        right: &Expression,
+        span: &Span,
+    ) -> InferredType {
+        let type_ = self.check_operands(operation, left, right, &InferredType::Unknown, span);
+
+        match type_ {
+            InferredType::IntegerType(_) | InferredType::Implicit | InferredType::Unknown => {}
+            type_ => {
+                self.errors
+                    .push(TypeError::invalid_operation(operation, &type_, span.clone()));
+            }
+        }
+
+        InferredType::Boolean
+    }
+
+    fn check_logical(&mut self, operation: &str, left: &Expression, right: &Expression, span: &Span) -> InferredType {
+        let left_type = self.check_expression(left, &InferredType::Boolean);
+        let right_type = self.check_expression(right, &InferredType::Boolean);
+
+        self.check_boolean_operand(operation, &left_type, span);
+        self.check_boolean_operand(operation, &right_type, span);
+
+        InferredType::Boolean
+    }
+
+    fn check_boolean_operand(&mut self, operation: &str, type_: &InferredType, span: &Span) {
+        if InferredType::Boolean.unify(type_).is_none() {
+            self.errors
+                .push(TypeError::invalid_operation(operation, type_, span.clone()));
+        }
+    }
+
+    /// Checks an array index or a loop bound. The compiler accepts any integer type.
+    pub(crate) fn check_index(&mut self, index: &Expression, span: &Span) {
+        let expected = InferredType::IntegerType(IntegerType::U32);
+
+        match self.check_expression(index, &expected) {
+            InferredType::IntegerType(_) | InferredType::Implicit | InferredType::Unknown => {}
+            type_ => self.errors.push(TypeError::invalid_index(&type_, span.clone())),
+        }
+    }
+
+    fn check_array(
+        &mut self,
+        elements: &[Box<SpreadOrExpression>],
+        expected: &InferredType,
+        span: &Span,
+    ) -> InferredType {
+        let (mut element_type, expected_length) = match expected {
+            InferredType::Array(type_, length) => (*type_.clone(), *length),
+            _ => (InferredType::Unknown, None),
+        };
+
+        let mut length = Some(0usize);
+
+        for element in elements.iter() {
+            match element.as_ref() {
+                SpreadOrExpression::Spread(expression) => match self
+                    .check_expression(expression, &InferredType::Unknown)
+                {
+                    InferredType::Array(type_, spread_length) => {
+                        element_type = self.expect_type(&element_type, &type_, span);
+                        length = length.and_then(|length| spread_length.map(|spread_length| length + spread_length));
+                    }
+                    InferredType::Unknown => length = None,
+                    type_ => {
+                        self.errors.push(TypeError::invalid_spread(&type_, span.clone()));
+                        length = None;
+                    }
+                },
+                SpreadOrExpression::Expression(expression) => {
+                    let type_ = self.check_expression(expression, &element_type);
+
+                    element_type = self.expect_type(&element_type, &type_, expression.span());
+                    length = length.map(|length| length + 1);
+                }
+            }
+        }
+
+        if let (Some(expected_length), Some(actual_length)) = (expected_length, length) {
+            if expected_length != actual_length {
+                self.errors
+                    .push(TypeError::invalid_length(expected_length, actual_length, span.clone()));
+
+                return InferredType::Unknown;
+            }
+        }
+
+        InferredType::Array(Box::new(element_type), length)
+    }
+
+    fn check_array_access(&mut self, array: &Expression, index: &RangeOrExpression, span: &Span) -> InferredType {
+        let array_type = self.check_expression(array, &InferredType::Unknown);
+
+        let (element_type, length) = match array_type {
+            InferredType::Array(type_, length) => (*type_, length),
+            InferredType::Unknown => (InferredType::Unknown, None),
+            type_ => {
+                self.errors.push(TypeError::invalid_array(&type_, span.clone()));
+
+                (InferredType::Unknown, None)
+            }
+        };
+
+        match index {
+            RangeOrExpression::Expression(index) => {
+                self.check_index(index, span);
+
+                element_type
+            }
+            RangeOrExpression::Range(from, to) => {
+                let from_index = match from {
+                    Some(from) => {
+                        self.check_index(from, span);
+                        constant_index(from)
+                    }
+                    None => Some(0),
+                };
+                let to_index = match to {
+                    Some(to) => {
+                        self.check_index(to, span);
+                        constant_index(to)
+                    }
+                    None => length,
+                };
+
+                let slice_length = match (from_index, to_index) {
+                    (Some(from), Some(to)) if from <= to => Some(to - from),
+                    _ => None,
+                };
+
+                InferredType::Array(Box::new(element_type), slice_length)
+            }
+        }
+    }
+
+    fn check_circuit_expression(
+        &mut self,
+        identifier: &Identifier,
+        members: &[CircuitVariableDefinition],
+        span: &Span,
+    ) -> InferredType {
+        let circuit = if identifier.is_self() {
+            match &self.circuit {
+                Some(circuit) => Some(circuit.clone()),
+                None => {
+                    self.errors.push(TypeError::self_keyword(identifier.span.clone()));
+                    None
+                }
+            }
+        } else {
+            match self.symbols.get_circuit(&identifier.name) {
+                Some(circuit) => Some(circuit.clone()),
+                None => {
+                    self.errors.push(TypeError::undefined_circuit(identifier));
+                    None
+                }
+            }
+        };
+
+        let circuit = match circuit {
+            Some(circuit) => circuit,
+            None => {
+                for member in members.iter() {
+                    self.check_expression(&member.expression, &InferredType::Unknown);
+                }
+
+                return InferredType::Unknown;
+            }
+        };
+
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitVariable(_, member_name, type_) = member {
+                match members.iter().find(|variable| variable.identifier.eq(member_name)) {
+                    Some(variable) => {
+                        let expected = InferredType::from_type(type_, Some(&circuit.circuit_name));
+                        let actual = self.check_expression(&variable.expression, &expected);
+
+                        self.expect_type(&expected, &actual, variable.expression.span());
+                    }
+                    None => self.errors.push(TypeError::expected_circuit_member(
+                        circuit.circuit_name.to_string(),
+                        member_name.to_string(),
+                        span.clone(),
+                    )),
+                }
+            }
+        }
+
+        for variable in members.iter() {
+            let is_member = circuit.members.iter().any(|member| match member {
+                CircuitMember::CircuitVariable(_, member_name, _) => member_name.eq(&variable.identifier),
+                CircuitMember::CircuitFunction(_, _) => false,
+            });
+
+            if !is_member {
+                self.check_expression(&variable.expression, &InferredType::Unknown);
+                self.errors.push(TypeError::undefined_member_access(
+                    circuit.circuit_name.to_string(),
+                    variable.identifier.to_string(),
+                    variable.identifier.span.clone(),
+                ));
+            }
+        }
+
+        InferredType::Circuit(circuit.circuit_name.clone())
+    }
+
+    /// Returns the circuit definition of the given type, if it is known.
+    fn circuit_definition(&self, type_: &InferredType) -> Option<Circuit> {
+        match type_ {
+            InferredType::Circuit(identifier) => self.symbols.get_circuit_definition(&identifier.name).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Returns the circuit and member accessed with `.` syntax, reporting members that are missing or static.
+    fn check_member_access(
+        &mut self,
+        circuit_type: InferredType,
+        member: &Identifier,
+        span: &Span,
+    ) -> Option<(Identifier, CircuitMember)> {
+        let definition = match circuit_type {
+            InferredType::Circuit(_) => self.circuit_definition(&circuit_type)?,
+            InferredType::Unknown => return None,
+            type_ => {
+                self.errors.push(TypeError::undefined_member_type(
+                    &type_,
+                    member.to_string(),
+                    span.clone(),
+                ));
+
+                return None;
+            }
+        };
+
+        let matched_member = definition.members.iter().find(|circuit_member| match circuit_member {
+            CircuitMember::CircuitVariable(_, identifier, _) => identifier.eq(member),
+            CircuitMember::CircuitFunction(_, function) => function.identifier.eq(member),
+        });
+
+        match matched_member {
+            Some(CircuitMember::CircuitFunction(true, _)) => {
+                self.errors
+                    .push(TypeError::invalid_static_access(member.to_string(), span.clone()));
+
+                None
+            }
+            Some(circuit_member) => Some((definition.circuit_name.clone(), circuit_member.clone())),
+            None => {
+                self.errors.push(TypeError::undefined_member_access(
+                    definition.circuit_name.to_string(),
+                    member.to_string(),
+                    span.clone(),
+                ));
+
+                None
+            }
+        }
+    }
+
+    /// Returns the circuit and static function accessed with `::` syntax.
+    fn check_static_access(
+        &mut self,
+        circuit: &Expression,
+        member: &Identifier,
+        span: &Span,
+    ) -> Option<(Identifier, Function)> {
+        let definition = match circuit {
+            Expression::Identifier(identifier) if identifier.is_self() => match &self.circuit {
+                Some(circuit) => circuit.clone(),
+                None => {
+                    self.errors.push(TypeError::self_keyword(identifier.span.clone()));
+
+                    return None;
+                }
+            },
+            Expression::Identifier(identifier) => match self.symbols.get_circuit(&identifier.name) {
+                Some(circuit) => circuit.clone(),
+                None => {
+                    self.errors.push(TypeError::undefined_circuit(identifier));
+
+                    return None;
+                }
+            },
+            expression => {
+                self.check_expression(expression, &InferredType::Unknown);
+
+                return None;
+            }
+        };
+
+        let matched_function = definition
+            .members
+            .iter()
+            .find_map(|circuit_member| match circuit_member {
+                CircuitMember::CircuitFunction(is_static, function) if function.identifier.eq(member) => {
+                    Some((*is_static, function.clone()))
+                }
+                _ => None,
+            });
+
+        match matched_function {
+            Some((true, function)) => Some((definition.circuit_name.clone(), function)),
+            Some((false, function)) => {
+                self.errors.push(TypeError::invalid_member_access(
+                    function.identifier.to_string(),
+                    span.clone(),
+                ));
+
+                None
+            }
+            None => {
+                self.errors.push(TypeError::undefined_static_access(
+                    definition.circuit_name.to_string(),
+                    member.to_string(),
+                    span.clone(),
+                ));
+
+                None
+            }
+        }
+    }
+
+    fn check_function_call(&mut self, function: &Expression, arguments: &[Expression], span: &Span) -> InferredType {
+        let called = match function {
+            Expression::Identifier(identifier) => match self.symbols.get_function(&identifier.name) {
+                Some(function) => Some((None, function.clone())),
+                None => {
+                    if self.scope.get(&identifier.name).is_some()
+                        || self.symbols.get_circuit(&identifier.name).is_some()
+                    {
+                        self.errors
+                            .push(TypeError::invalid_call(identifier.to_string(), span.clone()));
+                    } else {
+                        self.errors.push(TypeError::undefined_function(identifier));
+                    }
+
+                    None
+                }
+            },
+            Expression::CircuitMemberAccess(circuit, member, member_span) => {
+                let circuit_type = self.check_expression(circuit, &InferredType::Unknown);
+
+                if let InferredType::Group = circuit_type {
+                    if is_group_accessor(member) {
+                        return self.check_group_call(member, arguments, InferredType::Field, span);
+                    }
+                }
+
+                match self.check_member_access(circuit_type, member, member_span) {
+                    Some((circuit_name, CircuitMember::CircuitFunction(_, function))) => {
+                        Some((Some(circuit_name), function))
+                    }
+                    Some((_, CircuitMember::CircuitVariable(_, identifier, _))) => {
+                        self.errors
+                            .push(TypeError::invalid_call(identifier.to_string(), span.clone()));
+
+                        None
+                    }
+                    None => None,
+                }
+            }
+            Expression::CircuitStaticFunctionAccess(circuit, member, member_span) if is_group_keyword(circuit) => {
+                if member.name == "generator" {
+                    return self.check_group_call(member, arguments, InferredType::Group, span);
+                }
+
+                self.errors.push(TypeError::undefined_static_access(
+                    GROUP_KEYWORD.to_string(),
+                    member.to_string(),
+                    member_span.clone(),
+                ));
+
+                None
+            }
+            Expression::CircuitStaticFunctionAccess(circuit, member, member_span) => self
+                .check_static_access(circuit, member, member_span)
+                .map(|(circuit_name, function)| (Some(circuit_name), function)),
+            expression => {
+                self.check_expression(expression, &InferredType::Unknown);
+                self.errors
+                    .push(TypeError::invalid_call(expression.to_string(), span.clone()));
+
+                None
+            }
+        };
+
+        let (circuit_name, function) = match called {
+            Some(called) => called,
+            None => {
+                for argument in arguments.iter() {
+                    self.check_expression(argument, &InferredType::Unknown);
+                }
+
+                return InferredType::Unknown;
+            }
+        };
+
+        if function.input.len() != arguments.len() {
+            self.errors.push(TypeError::arguments_length(
+                function.get_name(),
+                function.input.len(),
+                arguments.len(),
+                span.clone(),
+            ));
+        }
+
+        for (index, argument) in arguments.iter().enumerate() {
+            match function.input.get(index) {
+                Some(InputVariable::FunctionInput(input)) => {
+                    let expected = InferredType::from_type(&input.type_, circuit_name.as_ref());
+                    let actual = self.check_expression(argument, &expected);
+
+                    self.expect_type(&expected, &actual, argument.span());
+                }
+                _ => {
+                    self.check_expression(argument, &InferredType::Unknown);
+                }
+            }
+        }
+
+        match &function.returns {
+            Some(type_) => InferredType::from_type(type_, circuit_name.as_ref()),
+            None => InferredType::empty(),
+        }
+    }
+
+    /// Checks a call to a built-in group function, which takes no arguments.
+    fn check_group_call(
+        &mut self,
+        member: &Identifier,
+        arguments: &[Expression],
+        returns: InferredType,
+        span: &Span,
+    ) -> InferredType {
+        if !arguments.is_empty() {
+            self.errors.push(TypeError::arguments_length(
+                member.to_string(),
+                0,
+                arguments.len(),
+                span.clone(),
+            ));
+        }
+
+        for argument in arguments.iter() {
+            self.check_expression(argument, &InferredType::Unknown);
+        }
+
+        returns
+    }
+}
+
+static GROUP_KEYWORD: &str = "group";
+
+/// Returns `true` if the expression is the `group` keyword in `group::generator()`.
+fn is_group_keyword(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(identifier) => identifier.name == GROUP_KEYWORD,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the member is a coordinate accessor of a group value.
+fn is_group_accessor(member: &Identifier) -> bool {
+    member.name == "to_x_coordinate" || member.name == "to_y_coordinate"
+}
+
+/// Returns the value of an index that is a number literal.
+fn constant_index(expression: &Expression) -> Option<usize> {
+    match expression {
+        Expression::Implicit(number, _) | Expression::Integer(_, number, _) => number.parse::<usize>().ok(),
+        _ => None,
+    }
+}
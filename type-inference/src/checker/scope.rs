@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::InferredType;
+
+use std::collections::HashMap;
+
+/// The variables defined in a function body, one map per nested block.
+#[derive(Clone, Debug)]
+pub struct Scope {
+    blocks: Vec<HashMap<String, InferredType>>,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Self {
+            blocks: vec![HashMap::new()],
+        }
+    }
+
+    /// Enters a nested block. Variables defined inside are dropped by `pop_block`.
+    pub fn push_block(&mut self) {
+        self.blocks.push(HashMap::new());
+    }
+
+    pub fn pop_block(&mut self) {
+        self.blocks.pop();
+    }
+
+    /// Defines a variable in the innermost block, shadowing any previous definition.
+    pub fn insert(&mut self, name: String, type_: InferredType) {
+        if let Some(block) = self.blocks.last_mut() {
+            block.insert(name, type_);
+        }
+    }
+
+    /// Returns the type of the innermost variable with the given name.
+    pub fn get(&self, name: &str) -> Option<&InferredType> {
+        self.blocks.iter().rev().find_map(|block| block.get(name))
+    }
+}
+
+impl Default for Scope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
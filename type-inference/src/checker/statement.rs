@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{InferredType, TypeChecker, TypeError};
+use leo_typed::{
+    Assignee,
+    CircuitMember,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    Expression,
+    IntegerType,
+    RangeOrExpression,
+    Span,
+    Statement,
+    Variables,
+};
+
+impl TypeChecker {
+    pub fn check_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Return(expression, span) => {
+                let expected = self.return_type.clone();
+                let actual = self.check_expression(expression, &expected);
+
+                self.expect_type(&expected, &actual, span);
+            }
+            Statement::Definition(_declare, variables, expressions, span) => {
+                self.check_definition(variables, expressions, span)
+            }
+            Statement::Assign(assignee, expression, span) => {
+                let expected = self.check_assignee(assignee, span);
+                let actual = self.check_expression(expression, &expected);
+
+                self.expect_type(&expected, &actual, span);
+            }
+            Statement::Conditional(statement, _span) => self.check_conditional(statement),
+            Statement::Iteration(index, start, stop, statements, span) => {
+                self.check_index(start, span);
+                self.check_index(stop, span);
+
+                self.scope.push_block();
+                self.define(index, InferredType::IntegerType(IntegerType::U32));
+                self.check_block(statements);
+                self.scope.pop_block();
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => {
+                    let type_ = self.check_expression(expression, &InferredType::Boolean);
+
+                    if InferredType::Boolean.unify(&type_).is_none() {
+                        self.errors
+                            .push(TypeError::console_assert_boolean(&type_, console.span.clone()));
+                    }
+                }
+                ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                    if string.containers.len() != string.parameters.len() {
+                        self.errors.push(TypeError::console_length(
+                            string.containers.len(),
+                            string.parameters.len(),
+                            string.span.clone(),
+                        ));
+                    }
+
+                    for parameter in string.parameters.iter() {
+                        self.check_expression(&parameter.expression, &InferredType::Unknown);
+                    }
+                }
+            },
+            Statement::Expression(expression, span) => {
+                let type_ = self.check_expression(expression, &InferredType::Unknown);
+
+                if InferredType::empty().unify(&type_).is_none() {
+                    self.errors
+                        .push(TypeError::unassigned(expression.to_string(), span.clone()));
+                }
+            }
+        }
+    }
+
+    /// Checks statements inside a new block.
+    fn check_block(&mut self, statements: &[Statement]) {
+        self.scope.push_block();
+
+        for statement in statements.iter() {
+            self.check_statement(statement);
+        }
+
+        self.scope.pop_block();
+    }
+
+    fn check_conditional(&mut self, statement: &ConditionalStatement) {
+        let condition_type = self.check_expression(&statement.condition, &InferredType::Boolean);
+
+        if InferredType::Boolean.unify(&condition_type).is_none() {
+            self.errors.push(TypeError::conditional_boolean(
+                &condition_type,
+                statement.condition.span().clone(),
+            ));
+        }
+
+        self.check_block(&statement.statements);
+
+        match &statement.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => self.check_conditional(nested),
+            Some(ConditionalNestedOrEndStatement::End(statements)) => self.check_block(statements),
+            None => {}
+        }
+    }
+
+    fn check_definition(&mut self, variables: &Variables, expressions: &[Expression], span: &Span) {
+        let declared = variables.type_.as_ref().map(|type_| self.resolve_type(type_, span));
+
+        let types = if expressions.len() == 1 {
+            let expected = declared.clone().unwrap_or(InferredType::Unknown);
+            let actual = self.check_expression(&expressions[0], &expected);
+            let type_ = self.expect_type(&expected, &actual, span);
+
+            if variables.names.len() == 1 {
+                vec![type_]
+            } else {
+                // Define multiple variables for an expression that returns multiple results.
+                match type_ {
+                    InferredType::Tuple(types) => types,
+                    InferredType::Unknown => vec![InferredType::Unknown; variables.names.len()],
+                    type_ => {
+                        self.errors.push(TypeError::multiple_definition(&type_, span.clone()));
+
+                        vec![InferredType::Unknown; variables.names.len()]
+                    }
+                }
+            }
+        } else {
+            // Each expression takes the matching type of a declared tuple.
+            let expected_types = match declared {
+                Some(InferredType::Tuple(ref types)) if types.len() == expressions.len() => types.clone(),
+                Some(ref type_) => {
+                    self.errors.push(TypeError::tuple_type(type_.to_string(), span.clone()));
+
+                    vec![InferredType::Unknown; expressions.len()]
+                }
+                None => vec![InferredType::Unknown; expressions.len()],
+            };
+
+            let types = expressions
+                .iter()
+                .zip(expected_types.iter())
+                .map(|(expression, expected)| {
+                    let actual = self.check_expression(expression, expected);
+
+                    self.expect_type(expected, &actual, span)
+                })
+                .collect::<Vec<_>>();
+
+            if variables.names.len() == 1 {
+                vec![InferredType::Tuple(types)]
+            } else {
+                types
+            }
+        };
+
+        if types.len() != variables.names.len() {
+            self.errors.push(TypeError::invalid_number_of_definitions(
+                types.len(),
+                variables.names.len(),
+                span.clone(),
+            ));
+        }
+
+        for (index, variable) in variables.names.iter().enumerate() {
+            let type_ = types.get(index).cloned().unwrap_or(InferredType::Unknown);
+
+            self.define(&variable.identifier, type_);
+        }
+    }
+
+    /// Returns the type of the value being assigned to.
+    fn check_assignee(&mut self, assignee: &Assignee, span: &Span) -> InferredType {
+        match assignee {
+            Assignee::Identifier(identifier) => {
+                self.check_expression(&Expression::Identifier(identifier.clone()), &InferredType::Unknown)
+            }
+            Assignee::Array(array, index) => match self.check_assignee(array, span) {
+                InferredType::Array(type_, length) => match index {
+                    RangeOrExpression::Expression(index) => {
+                        self.check_index(index, span);
+
+                        *type_
+                    }
+                    RangeOrExpression::Range(from, to) => {
+                        from.iter()
+                            .chain(to.iter())
+                            .for_each(|index| self.check_index(index, span));
+
+                        let length = if from.is_none() && to.is_none() { length } else { None };
+
+                        InferredType::Array(type_, length)
+                    }
+                },
+                InferredType::Unknown => InferredType::Unknown,
+                type_ => {
+                    self.errors.push(TypeError::invalid_array(&type_, span.clone()));
+
+                    InferredType::Unknown
+                }
+            },
+            Assignee::Tuple(tuple, index) => match self.check_assignee(tuple, span) {
+                InferredType::Tuple(types) => match types.get(*index) {
+                    Some(type_) => type_.clone(),
+                    None => {
+                        self.errors
+                            .push(TypeError::index_out_of_bounds(*index, types.len(), span.clone()));
+
+                        InferredType::Unknown
+                    }
+                },
+                InferredType::Unknown => InferredType::Unknown,
+                type_ => {
+                    self.errors.push(TypeError::invalid_tuple(&type_, span.clone()));
+
+                    InferredType::Unknown
+                }
+            },
+            Assignee::CircuitField(circuit, member) => {
+                let circuit_type = self.check_assignee(circuit, span);
+
+                let (circuit_name, definition) = match circuit_type {
+                    InferredType::Circuit(ref identifier) => {
+                        match self.symbols.get_circuit_definition(&identifier.name) {
+                            Some(definition) => (identifier.clone(), definition.clone()),
+                            None => return InferredType::Unknown,
+                        }
+                    }
+                    InferredType::Unknown => return InferredType::Unknown,
+                    type_ => {
+                        self.errors.push(TypeError::undefined_member_type(
+                            &type_,
+                            member.to_string(),
+                            span.clone(),
+                        ));
+
+                        return InferredType::Unknown;
+                    }
+                };
+
+                for circuit_member in definition.members.iter() {
+                    match circuit_member {
+                        CircuitMember::CircuitVariable(_, identifier, type_) if identifier.eq(member) => {
+                            return InferredType::from_type(type_, Some(&circuit_name));
+                        }
+                        CircuitMember::CircuitFunction(_, function) if function.identifier.eq(member) => {
+                            self.errors
+                                .push(TypeError::immutable_circuit_function(member.to_string(), span.clone()));
+
+                            return InferredType::Unknown;
+                        }
+                        _ => {}
+                    }
+                }
+
+                self.errors.push(TypeError::undefined_member_access(
+                    circuit_name.to_string(),
+                    member.to_string(),
+                    span.clone(),
+                ));
+
+                InferredType::Unknown
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::InferredType;
+use leo_core::CorePackageListError;
+use leo_typed::{Error as FormattedError, Identifier, ImportSymbol, Span};
+
+use std::path::PathBuf;
+
+#[derive(Debug, Error)]
+pub enum TypeError {
+    #[error("{}", _0)]
+    CorePackageListError(#[from] CorePackageListError),
+
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+}
+
+impl TypeError {
+    pub fn set_path(&mut self, path: PathBuf) {
+        match self {
+            TypeError::CorePackageListError(error) => error.set_path(path),
+            TypeError::Error(error) => error.set_path(path),
+        }
+    }
+
+    fn new_from_span(message: String, span: Span) -> Self {
+        TypeError::Error(FormattedError::new_from_span(message, span))
+    }
+
+    pub fn arguments_length(function: String, expected: usize, actual: usize, span: Span) -> Self {
+        let message = format!(
+            "function `{}` expected {} input variables, found {}",
+            function, expected, actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn conditional_boolean(actual: &InferredType, span: Span) -> Self {
+        let message = format!("if, else conditional must resolve to a boolean, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn console_assert_boolean(actual: &InferredType, span: Span) -> Self {
+        let message = format!(
+            "assertion expression must evaluate to a boolean value, found `{}`",
+            actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn console_length(containers: usize, parameters: usize, span: Span) -> Self {
+        let message = format!(
+            "Formatter given {} containers and found {} parameters",
+            containers, parameters
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn expected_circuit_member(circuit: String, member: String, span: Span) -> Self {
+        let message = format!("missing member `{}` in initializer of circuit `{}`", member, circuit);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn immutable_circuit_function(name: String, span: Span) -> Self {
+        let message = format!("cannot assign to circuit function `{}`", name);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn index_out_of_bounds(index: usize, length: usize, span: Span) -> Self {
+        let message = format!(
+            "cannot access index {} of tuple with length {} out of bounds",
+            index, length
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_array(actual: &InferredType, span: Span) -> Self {
+        let message = format!("expected an array, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_call(expression: String, span: Span) -> Self {
+        let message = format!("`{}` is not a function", expression);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_cast(actual: &InferredType, target: &InferredType, span: Span) -> Self {
+        let message = format!("cannot cast type `{}` as `{}`", actual, target);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_index(actual: &InferredType, span: Span) -> Self {
+        let message = format!("index must resolve to an integer, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_length(expected: usize, actual: usize, span: Span) -> Self {
+        let message = format!("expected array length {}, found one with length {}", expected, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_member_access(member: String, span: Span) -> Self {
+        let message = format!("non-static member `{}` must be accessed using `.` syntax", member);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_number_of_definitions(expected: usize, actual: usize, span: Span) -> Self {
+        let message = format!(
+            "multiple definition statement expected {} return values, found {} values",
+            expected, actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_operation(operation: &str, actual: &InferredType, span: Span) -> Self {
+        let message = format!("operator `{}` cannot be applied to type `{}`", operation, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_spread(actual: &InferredType, span: Span) -> Self {
+        let message = format!("spread should contain an array, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_static_access(member: String, span: Span) -> Self {
+        let message = format!("static member `{}` must be accessed using `::` syntax", member);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_tuple(actual: &InferredType, span: Span) -> Self {
+        let message = format!("expected a tuple, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn multiple_definition(actual: &InferredType, span: Span) -> Self {
+        let message = format!(
+            "cannot assign multiple variables to a single value of type `{}`",
+            actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn mismatched_operands(operation: &str, left: &InferredType, right: &InferredType, span: Span) -> Self {
+        let message = format!(
+            "mismatched types for operator `{}`: left operand is `{}`, right operand is `{}`",
+            operation, left, right
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn mismatched_types(expected: &InferredType, actual: &InferredType, span: Span) -> Self {
+        let message = format!("mismatched types: expected `{}`, found `{}`", expected, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn self_keyword(span: Span) -> Self {
+        let message = "cannot call keyword `Self` outside of a circuit function".to_string();
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn self_variable(span: Span) -> Self {
+        let message = "cannot access `self` outside of a non-static circuit function".to_string();
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn tuple_type(actual: String, span: Span) -> Self {
+        let message = format!("expected tuple type, found type `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn unassigned(expression: String, span: Span) -> Self {
+        let message = format!("expected assignment of return values for expression `{}`", expression);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn undefined_circuit(identifier: &Identifier) -> Self {
+        let message = format!("cannot find circuit `{}` in this scope", identifier.name);
+
+        Self::new_from_span(message, identifier.span.clone())
+    }
+
+    pub fn undefined_function(identifier: &Identifier) -> Self {
+        let message = format!("cannot find function `{}` in this scope", identifier.name);
+
+        Self::new_from_span(message, identifier.span.clone())
+    }
+
+    pub fn undefined_identifier(identifier: &Identifier) -> Self {
+        let message = format!("cannot find value `{}` in this scope", identifier.name);
+
+        Self::new_from_span(message, identifier.span.clone())
+    }
+
+    pub fn undefined_member_access(circuit: String, member: String, span: Span) -> Self {
+        let message = format!("Circuit `{}` has no member `{}`", circuit, member);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn undefined_member_type(actual: &InferredType, member: String, span: Span) -> Self {
+        let message = format!("no member `{}` on type `{}`", member, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn undefined_static_access(circuit: String, member: String, span: Span) -> Self {
+        let message = format!("Circuit `{}` has no static member `{}`", circuit, member);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn unknown_symbol(symbol: &ImportSymbol, file: String) -> Self {
+        let message = format!("cannot find imported symbol `{}` in imported file `{}`", symbol, file);
+
+        Self::new_from_span(message, symbol.span.clone())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeError;
+
+use std::{fmt, path::PathBuf};
+
+/// Every type error found while checking a program.
+#[derive(Debug)]
+pub struct TypeInferenceError {
+    errors: Vec<TypeError>,
+}
+
+impl TypeInferenceError {
+    pub fn new(errors: Vec<TypeError>) -> Self {
+        Self { errors }
+    }
+
+    pub fn errors(&self) -> &Vec<TypeError> {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<TypeError> {
+        self.errors
+    }
+
+    pub fn set_path(&mut self, path: PathBuf) {
+        self.errors.iter_mut().for_each(|error| error.set_path(path.clone()));
+    }
+}
+
+impl fmt::Display for TypeInferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let errors = self
+            .errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        write!(f, "{}", errors)
+    }
+}
+
+impl std::error::Error for TypeInferenceError {}
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A static type checker for Leo programs.
+//!
+//! The type checker walks a typed syntax tree and reports every type error it finds, with spans,
+//! before the compiler begins generating constraints. It does not require a constraint system,
+//! so it can also be called directly by editors and other tooling.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod checker;
+pub use self::checker::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod symbols;
+pub use self::symbols::*;
+
+pub mod types;
+pub use self::types::*;
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeError;
+use leo_core::CorePackageList;
+use leo_typed::{Circuit, Function, ImportSymbol, Package, PackageAccess, Program};
+
+use std::collections::HashMap;
+
+pub static CORE_PACKAGE_NAME: &str = "core";
+
+/// The circuits and functions that are visible from the top level of a program.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolTable {
+    /// Circuits by the name they are referenced with in the program (including import aliases).
+    circuits: HashMap<String, Circuit>,
+    /// Functions by the name they are referenced with in the program (including import aliases).
+    functions: HashMap<String, Function>,
+    /// Every known circuit by the name in its definition.
+    definitions: HashMap<String, Circuit>,
+}
+
+impl SymbolTable {
+    /// Creates a symbol table for the given program and its imported programs.
+    /// Imported programs are keyed by file name, as stored by the compiler's import parser.
+    /// Import statements that do not resolve are reported in the returned errors.
+    pub fn new(program: &Program, imports: &HashMap<String, Program>) -> (Self, Vec<TypeError>) {
+        let mut table = Self::default();
+        let mut errors = vec![];
+
+        // Circuits of imported programs may appear in the signatures of imported functions.
+        for imported_program in imports.values() {
+            for circuit in imported_program.circuits.values() {
+                table.insert_definition(circuit);
+            }
+        }
+
+        for import in program.imports.iter() {
+            if let Err(error) = table.insert_import(&import.package, imports) {
+                errors.push(error);
+            }
+        }
+
+        for (identifier, circuit) in program.circuits.iter() {
+            table.insert_definition(circuit);
+            table.circuits.insert(identifier.name.clone(), circuit.clone());
+        }
+
+        for (identifier, function) in program.functions.iter() {
+            table.functions.insert(identifier.name.clone(), function.clone());
+        }
+
+        (table, errors)
+    }
+
+    /// Returns the circuit referenced by the given name.
+    pub fn get_circuit(&self, name: &str) -> Option<&Circuit> {
+        self.circuits.get(name)
+    }
+
+    /// Returns the circuit with the given definition name.
+    pub fn get_circuit_definition(&self, name: &str) -> Option<&Circuit> {
+        self.definitions.get(name)
+    }
+
+    /// Returns the function referenced by the given name.
+    pub fn get_function(&self, name: &str) -> Option<&Function> {
+        self.functions.get(name)
+    }
+
+    fn insert_definition(&mut self, circuit: &Circuit) {
+        self.definitions
+            .insert(circuit.circuit_name.name.clone(), circuit.clone());
+    }
+
+    fn insert_import(&mut self, package: &Package, imports: &HashMap<String, Program>) -> Result<(), TypeError> {
+        if package.name.name.eq(CORE_PACKAGE_NAME) {
+            let list = CorePackageList::from_package_access(package.access.clone())?;
+
+            for (symbol, circuit) in list.to_symbols()?.symbols() {
+                self.insert_definition(&circuit);
+                self.circuits.insert(symbol, circuit);
+            }
+
+            return Ok(());
+        }
+
+        self.insert_package_access(package.name.name.clone(), &package.access, imports)
+    }
+
+    fn insert_package_access(
+        &mut self,
+        package_name: String,
+        access: &PackageAccess,
+        imports: &HashMap<String, Program>,
+    ) -> Result<(), TypeError> {
+        match access {
+            PackageAccess::SubPackage(package) => {
+                self.insert_package_access(package.name.name.clone(), &package.access, imports)
+            }
+            PackageAccess::Star(_span) => {
+                // The compiler reports unknown packages while parsing imports.
+                if let Some(program) = imports.get(&package_name) {
+                    for (identifier, circuit) in program.circuits.iter() {
+                        self.circuits.insert(identifier.name.clone(), circuit.clone());
+                    }
+                    for (identifier, function) in program.functions.iter() {
+                        self.functions.insert(identifier.name.clone(), function.clone());
+                    }
+                }
+
+                Ok(())
+            }
+            PackageAccess::Symbol(symbol) => match imports.get(&package_name) {
+                Some(program) => self.insert_symbol(package_name, symbol, program),
+                None => Ok(()),
+            },
+            PackageAccess::Multiple(accesses) => {
+                for access in accesses {
+                    self.insert_package_access(package_name.clone(), access, imports)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn insert_symbol(
+        &mut self,
+        package_name: String,
+        symbol: &ImportSymbol,
+        program: &Program,
+    ) -> Result<(), TypeError> {
+        let name = symbol.alias.as_ref().unwrap_or(&symbol.symbol).name.clone();
+
+        // Identifiers hash their span, so look up the symbol by name.
+        let circuit = program
+            .circuits
+            .iter()
+            .find(|(identifier, _)| identifier.name == symbol.symbol.name);
+        let function = program
+            .functions
+            .iter()
+            .find(|(identifier, _)| identifier.name == symbol.symbol.name);
+
+        if let Some((_, circuit)) = circuit {
+            self.circuits.insert(name, circuit.clone());
+        } else if let Some((_, function)) = function {
+            self.functions.insert(name, function.clone());
+        } else {
+            return Err(TypeError::unknown_symbol(symbol, package_name));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_typed::{Identifier, IntegerType, Type};
+
+use std::fmt;
+
+/// The type of an expression as seen by the type checker.
+///
+/// Multidimensional arrays are stored as nested single dimension arrays so that
+/// `[u8; (2, 3)]` and `[[u8; 3]; 2]` compare equal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InferredType {
+    // Data types
+    Address,
+    Boolean,
+    Field,
+    Group,
+    IntegerType(IntegerType),
+
+    /// A number without a type suffix. Takes the field, group or integer type of its context.
+    Implicit,
+
+    // Data type wrappers
+    /// An array with an element type and a length. The length is `None` if it depends on a non-constant slice.
+    Array(Box<InferredType>, Option<usize>),
+    Tuple(Vec<InferredType>),
+    Circuit(Identifier),
+
+    /// A type that could not be determined, such as an `input` keyword access or the result of an error.
+    /// Matches every other type so one mistake is only reported once.
+    Unknown,
+}
+
+impl InferredType {
+    /// Returns the type of a function with no return value.
+    pub fn empty() -> Self {
+        InferredType::Tuple(vec![])
+    }
+
+    /// Returns a nested array type for the given element type and dimensions.
+    pub fn array(element: InferredType, dimensions: &[usize]) -> Self {
+        dimensions.iter().rev().fold(element, |type_, dimension| {
+            InferredType::Array(Box::new(type_), Some(*dimension))
+        })
+    }
+
+    /// Converts an explicit type into an inferred type.
+    /// `Self` is replaced by the given circuit, or `Unknown` outside of a circuit.
+    pub fn from_type(type_: &Type, circuit: Option<&Identifier>) -> Self {
+        match type_ {
+            Type::Address => InferredType::Address,
+            Type::Boolean => InferredType::Boolean,
+            Type::Field => InferredType::Field,
+            Type::Group => InferredType::Group,
+            Type::IntegerType(integer_type) => InferredType::IntegerType(integer_type.clone()),
+            Type::Array(type_, dimensions) => InferredType::array(InferredType::from_type(type_, circuit), dimensions),
+            Type::Tuple(types) => InferredType::Tuple(
+                types
+                    .iter()
+                    .map(|type_| InferredType::from_type(type_, circuit))
+                    .collect(),
+            ),
+            Type::Circuit(identifier) => InferredType::Circuit(identifier.clone()),
+            Type::SelfType => match circuit {
+                Some(identifier) => InferredType::Circuit(identifier.clone()),
+                None => InferredType::Unknown,
+            },
+        }
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, InferredType::Unknown)
+    }
+
+    /// Returns `true` if a number without a type suffix can take this type.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            InferredType::Field | InferredType::Group | InferredType::IntegerType(_) | InferredType::Implicit
+        )
+    }
+
+    /// Returns the most specific type that both `self` and `other` can take, or `None` if they are incompatible.
+    pub fn unify(&self, other: &InferredType) -> Option<InferredType> {
+        match (self, other) {
+            (InferredType::Unknown, type_) | (type_, InferredType::Unknown) => Some(type_.clone()),
+            (InferredType::Implicit, type_) | (type_, InferredType::Implicit) => {
+                if type_.is_numeric() {
+                    Some(type_.clone())
+                } else {
+                    None
+                }
+            }
+            (InferredType::Array(type_1, length_1), InferredType::Array(type_2, length_2)) => {
+                let length = match (length_1, length_2) {
+                    (Some(length_1), Some(length_2)) if length_1 != length_2 => return None,
+                    (Some(length), _) | (_, Some(length)) => Some(*length),
+                    (None, None) => None,
+                };
+
+                Some(InferredType::Array(Box::new(type_1.unify(type_2)?), length))
+            }
+            (InferredType::Tuple(types_1), InferredType::Tuple(types_2)) => {
+                if types_1.len() != types_2.len() {
+                    return None;
+                }
+
+                let types = types_1
+                    .iter()
+                    .zip(types_2.iter())
+                    .map(|(type_1, type_2)| type_1.unify(type_2))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(InferredType::Tuple(types))
+            }
+            (InferredType::Circuit(name_1), InferredType::Circuit(name_2)) => {
+                if name_1.name == name_2.name {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+            (type_1, type_2) => {
+                if type_1 == type_2 {
+                    Some(type_1.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for InferredType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InferredType::Address => write!(f, "address"),
+            InferredType::Boolean => write!(f, "bool"),
+            InferredType::Field => write!(f, "field"),
+            InferredType::Group => write!(f, "group"),
+            InferredType::IntegerType(ref integer_type) => write!(f, "{}", integer_type),
+            InferredType::Implicit => write!(f, "{{number}}"),
+            InferredType::Array(ref type_, ref length) => match length {
+                Some(length) => write!(f, "[{}; {}]", type_, length),
+                None => write!(f, "[{}; _]", type_),
+            },
+            InferredType::Tuple(ref types) => {
+                let types = types.iter().map(|x| format!("{}", x)).collect::<Vec<_>>().join(", ");
+
+                write!(f, "({})", types)
+            }
+            InferredType::Circuit(ref identifier) => write!(f, "circuit {}", identifier),
+            InferredType::Unknown => write!(f, "_"),
+        }
+    }
+}
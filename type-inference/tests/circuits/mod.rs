@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_errors, expect_pass};
+
+#[test]
+fn test_valid_circuit() {
+    let bytes = include_bytes!("valid.leo");
+
+    expect_pass(bytes);
+}
+
+#[test]
+fn test_undefined_member() {
+    let bytes = include_bytes!("undefined_member.leo");
+
+    assert_eq!(expect_errors(bytes), vec!["Circuit `Foo` has no member `y`"]);
+}
+
+#[test]
+fn test_missing_member() {
+    let bytes = include_bytes!("missing_member.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "missing member `y` in initializer of circuit `Foo`"
+    ]);
+}
+
+#[test]
+fn test_static_access() {
+    let bytes = include_bytes!("static_access.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "static member `new` must be accessed using `::` syntax"
+    ]);
+}
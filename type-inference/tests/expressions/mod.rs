@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_errors, expect_pass};
+
+#[test]
+fn test_implicit() {
+    let bytes = include_bytes!("implicit.leo");
+
+    expect_pass(bytes);
+}
+
+#[test]
+fn test_mismatched_types() {
+    let bytes = include_bytes!("mismatched_types.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "mismatched types: expected `u8`, found `bool`"
+    ]);
+}
+
+#[test]
+fn test_mismatched_operands() {
+    let bytes = include_bytes!("mismatched_operands.leo");
+
+    assert_eq!(expect_errors(bytes).len(), 1);
+}
+
+#[test]
+fn test_invalid_operation() {
+    let bytes = include_bytes!("invalid_operation.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "operator `**` cannot be applied to type `field`"
+    ]);
+}
+
+#[test]
+fn test_invalid_bitwise_operation() {
+    let bytes = include_bytes!("invalid_bitwise_operation.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "operator `&` cannot be applied to type `field`",
+        "operator `<<` cannot be applied to type `bool`",
+    ]);
+}
+
+#[test]
+fn test_invalid_cast() {
+    let bytes = include_bytes!("invalid_cast.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "cannot cast type `bool` as `field`",
+        "cannot cast type `group` as `u32`",
+        "cannot cast type `u8` as `address`",
+    ]);
+}
+
+#[test]
+fn test_tuple_index_out_of_bounds() {
+    let bytes = include_bytes!("tuple_index_out_of_bounds.leo");
+
+    assert_eq!(expect_errors(bytes).len(), 1);
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_errors, expect_pass};
+
+#[test]
+fn test_valid_function() {
+    let bytes = include_bytes!("valid.leo");
+
+    expect_pass(bytes);
+}
+
+#[test]
+fn test_arguments_length() {
+    let bytes = include_bytes!("arguments_length.leo");
+
+    assert_eq!(expect_errors(bytes).len(), 1);
+}
+
+#[test]
+fn test_argument_type() {
+    let bytes = include_bytes!("argument_type.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "mismatched types: expected `u8`, found `bool`"
+    ]);
+}
+
+#[test]
+fn test_return_type() {
+    let bytes = include_bytes!("return_type.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "mismatched types: expected `field`, found `u8`"
+    ]);
+}
+
+#[test]
+fn test_undefined_function() {
+    let bytes = include_bytes!("undefined_function.leo");
+
+    assert_eq!(expect_errors(bytes), vec!["cannot find function `foo` in this scope"]);
+}
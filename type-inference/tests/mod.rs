@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod circuits;
+pub mod expressions;
+pub mod functions;
+pub mod statements;
+
+use leo_ast::LeoAst;
+use leo_type_inference::{TypeChecker, TypeError, TypeInferenceError};
+use leo_typed::LeoTypedAst;
+
+use std::{collections::HashMap, path::PathBuf};
+
+const TEST_PROGRAM_PATH: &str = "";
+
+pub(crate) fn check_program(bytes: &[u8]) -> Result<(), TypeInferenceError> {
+    let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+    let program_string = String::from_utf8_lossy(bytes);
+
+    // Parse the Leo file and construct a typed syntax tree.
+    let ast = LeoAst::new(&file_path, &program_string).unwrap();
+    let program = LeoTypedAst::new("test", &ast).into_repr();
+
+    TypeChecker::check_program(&program, &HashMap::new())
+}
+
+pub(crate) fn expect_pass(bytes: &[u8]) {
+    if let Err(error) = check_program(bytes) {
+        panic!("expected program to type check, got {}", error)
+    }
+}
+
+/// Returns the error messages reported by the type checker, in order of appearance.
+pub(crate) fn expect_errors(bytes: &[u8]) -> Vec<String> {
+    let error = check_program(bytes).expect_err("expected type inference error");
+
+    error
+        .into_errors()
+        .into_iter()
+        .map(|error| match error {
+            TypeError::Error(error) => error.message,
+            error => panic!("expected formatted error, got {}", error),
+        })
+        .collect()
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_errors, expect_pass};
+
+#[test]
+fn test_valid_statements() {
+    let bytes = include_bytes!("valid.leo");
+
+    expect_pass(bytes);
+}
+
+#[test]
+fn test_undefined_variable() {
+    let bytes = include_bytes!("undefined_variable.leo");
+
+    assert_eq!(expect_errors(bytes), vec!["cannot find value `b` in this scope"]);
+}
+
+#[test]
+fn test_out_of_scope_variable() {
+    let bytes = include_bytes!("out_of_scope_variable.leo");
+
+    assert_eq!(expect_errors(bytes), vec!["cannot find value `b` in this scope"]);
+}
+
+#[test]
+fn test_conditional_boolean() {
+    let bytes = include_bytes!("conditional_boolean.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "if, else conditional must resolve to a boolean, found `u8`"
+    ]);
+}
+
+#[test]
+fn test_multiple_errors() {
+    let bytes = include_bytes!("multiple_errors.leo");
+
+    assert_eq!(expect_errors(bytes), vec![
+        "mismatched types: expected `bool`, found `u32`",
+        "cannot find value `c` in this scope",
+        "mismatched types: expected `field`, found `bool`",
+    ]);
+}
@@ -17,7 +17,7 @@
 use crate::Span;
 use leo_ast::{
     annotations::AnnotationArgument,
-    common::{Identifier as AstIdentifier, KeywordOrIdentifier, SelfKeyword, SelfKeywordOrIdentifier},
+    common::{GroupKeyword, Identifier as AstIdentifier, KeywordOrIdentifier, SelfKeyword, SelfKeywordOrIdentifier},
     expressions::CircuitName,
     functions::InputKeyword,
     imports::PackageName as AstPackageName,
@@ -93,6 +93,7 @@ impl<'ast> From<KeywordOrIdentifier<'ast>> for Identifier {
             KeywordOrIdentifier::SelfKeywordOrIdentifier(keyword) => Identifier::from(keyword),
             KeywordOrIdentifier::SelfType(self_type) => Identifier::from(self_type),
             KeywordOrIdentifier::Input(keyword) => Identifier::from(keyword),
+            KeywordOrIdentifier::Group(keyword) => Identifier::from(keyword),
         }
     }
 }
@@ -106,6 +107,15 @@ impl<'ast> From<SelfKeywordOrIdentifier<'ast>> for Identifier {
     }
 }
 
+impl<'ast> From<GroupKeyword<'ast>> for Identifier {
+    fn from(group: GroupKeyword<'ast>) -> Self {
+        Self {
+            name: group.keyword,
+            span: Span::from(group.span),
+        }
+    }
+}
+
 impl<'ast> From<SelfKeyword<'ast>> for Identifier {
     fn from(self_: SelfKeyword<'ast>) -> Self {
         Self {
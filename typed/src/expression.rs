@@ -22,6 +22,7 @@ use crate::{
     RangeOrExpression,
     Span,
     SpreadOrExpression,
+    Type,
 };
 use leo_ast::{
     access::{Access, AssigneeAccess},
@@ -30,6 +31,7 @@ use leo_ast::{
         ArrayInitializerExpression,
         ArrayInlineExpression,
         BinaryExpression,
+        CastExpression,
         CircuitInlineExpression,
         Expression as AstExpression,
         PostfixExpression,
@@ -74,6 +76,18 @@ pub enum Expression {
     Mul(Box<Expression>, Box<Expression>, Span),
     Div(Box<Expression>, Box<Expression>, Span),
     Pow(Box<Expression>, Box<Expression>, Span),
+    Rem(Box<Expression>, Box<Expression>, Span),
+
+    // Bitwise operations
+    BitAnd(Box<Expression>, Box<Expression>, Span),
+    BitOr(Box<Expression>, Box<Expression>, Span),
+    BitXor(Box<Expression>, Box<Expression>, Span),
+    Shl(Box<Expression>, Box<Expression>, Span),
+    Shr(Box<Expression>, Box<Expression>, Span),
+
+    // Casts
+    // (expression, target_type, span)
+    Cast(Box<Expression>, Type, Span),
 
     // Boolean operations
     Not(Box<Expression>, Span),
@@ -128,6 +142,15 @@ impl Expression {
             Expression::Mul(_, _, old_span) => *old_span = new_span.clone(),
             Expression::Div(_, _, old_span) => *old_span = new_span.clone(),
             Expression::Pow(_, _, old_span) => *old_span = new_span.clone(),
+            Expression::Rem(_, _, old_span) => *old_span = new_span.clone(),
+
+            Expression::BitAnd(_, _, old_span) => *old_span = new_span.clone(),
+            Expression::BitOr(_, _, old_span) => *old_span = new_span.clone(),
+            Expression::BitXor(_, _, old_span) => *old_span = new_span.clone(),
+            Expression::Shl(_, _, old_span) => *old_span = new_span.clone(),
+            Expression::Shr(_, _, old_span) => *old_span = new_span.clone(),
+
+            Expression::Cast(_, _, old_span) => *old_span = new_span.clone(),
 
             Expression::Not(_, old_span) => *old_span = new_span.clone(),
             Expression::Or(_, _, old_span) => *old_span = new_span.clone(),
@@ -154,6 +177,58 @@ impl Expression {
             _ => {}
         }
     }
+
+    pub fn span(&self) -> &Span {
+        match self {
+            Expression::Identifier(identifier) => &identifier.span,
+
+            Expression::Address(_, span) => span,
+            Expression::Boolean(_, span) => span,
+            Expression::Field(_, span) => span,
+            Expression::Group(value) => value.span(),
+            Expression::Implicit(_, span) => span,
+            Expression::Integer(_, _, span) => span,
+
+            Expression::Add(_, _, span) => span,
+            Expression::Sub(_, _, span) => span,
+            Expression::Mul(_, _, span) => span,
+            Expression::Div(_, _, span) => span,
+            Expression::Pow(_, _, span) => span,
+            Expression::Rem(_, _, span) => span,
+
+            Expression::BitAnd(_, _, span) => span,
+            Expression::BitOr(_, _, span) => span,
+            Expression::BitXor(_, _, span) => span,
+            Expression::Shl(_, _, span) => span,
+            Expression::Shr(_, _, span) => span,
+
+            Expression::Cast(_, _, span) => span,
+
+            Expression::Not(_, span) => span,
+            Expression::Negate(_, span) => span,
+            Expression::Or(_, _, span) => span,
+            Expression::And(_, _, span) => span,
+            Expression::Eq(_, _, span) => span,
+            Expression::Ge(_, _, span) => span,
+            Expression::Gt(_, _, span) => span,
+            Expression::Le(_, _, span) => span,
+            Expression::Lt(_, _, span) => span,
+
+            Expression::IfElse(_, _, _, span) => span,
+            Expression::Array(_, span) => span,
+            Expression::ArrayAccess(_, _, span) => span,
+
+            Expression::Tuple(_, span) => span,
+            Expression::TupleAccess(_, _, span) => span,
+
+            Expression::Circuit(_, _, span) => span,
+            Expression::CircuitMemberAccess(_, _, span) => span,
+            Expression::CircuitStaticFunctionAccess(_, _, span) => span,
+
+            Expression::FunctionCall(_, _, span) => span,
+            Expression::CoreFunctionCall(_, _, span) => span,
+        }
+    }
 }
 
 impl<'ast> Expression {
@@ -215,6 +290,17 @@ impl<'ast> fmt::Display for Expression {
             Expression::Mul(ref left, ref right, ref _span) => write!(f, "{} * {}", left, right),
             Expression::Div(ref left, ref right, ref _span) => write!(f, "{} / {}", left, right),
             Expression::Pow(ref left, ref right, ref _span) => write!(f, "{} ** {}", left, right),
+            Expression::Rem(ref left, ref right, ref _span) => write!(f, "{} % {}", left, right),
+
+            // Bitwise operations
+            Expression::BitAnd(ref left, ref right, ref _span) => write!(f, "{} & {}", left, right),
+            Expression::BitOr(ref left, ref right, ref _span) => write!(f, "{} | {}", left, right),
+            Expression::BitXor(ref left, ref right, ref _span) => write!(f, "{} ^ {}", left, right),
+            Expression::Shl(ref left, ref right, ref _span) => write!(f, "{} << {}", left, right),
+            Expression::Shr(ref left, ref right, ref _span) => write!(f, "{} >> {}", left, right),
+
+            // Casts
+            Expression::Cast(ref expression, ref type_, ref _span) => write!(f, "{} as {}", expression, type_),
 
             // Boolean operations
             Expression::Not(ref expression, ref _span) => write!(f, "!{}", expression),
@@ -371,6 +457,7 @@ impl<'ast> From<AstExpression<'ast>> for Expression {
             AstExpression::Identifier(variable) => Expression::from(variable),
             AstExpression::Unary(expression) => Expression::from(expression),
             AstExpression::Binary(expression) => Expression::from(expression),
+            AstExpression::Cast(expression) => Expression::from(expression),
             AstExpression::Ternary(expression) => Expression::from(expression),
             AstExpression::ArrayInline(expression) => Expression::from(expression),
             AstExpression::ArrayInitializer(expression) => Expression::from(expression),
@@ -485,6 +572,37 @@ impl<'ast> From<BinaryExpression<'ast>> for Expression {
                 Box::new(Expression::from(*expression.right)),
                 Span::from(expression.span),
             ),
+            BinaryOperation::Rem => Expression::Rem(
+                Box::new(Expression::from(*expression.left)),
+                Box::new(Expression::from(*expression.right)),
+                Span::from(expression.span),
+            ),
+            // Bitwise operations
+            BinaryOperation::BitAnd => Expression::BitAnd(
+                Box::new(Expression::from(*expression.left)),
+                Box::new(Expression::from(*expression.right)),
+                Span::from(expression.span),
+            ),
+            BinaryOperation::BitOr => Expression::BitOr(
+                Box::new(Expression::from(*expression.left)),
+                Box::new(Expression::from(*expression.right)),
+                Span::from(expression.span),
+            ),
+            BinaryOperation::BitXor => Expression::BitXor(
+                Box::new(Expression::from(*expression.left)),
+                Box::new(Expression::from(*expression.right)),
+                Span::from(expression.span),
+            ),
+            BinaryOperation::Shl => Expression::Shl(
+                Box::new(Expression::from(*expression.left)),
+                Box::new(Expression::from(*expression.right)),
+                Span::from(expression.span),
+            ),
+            BinaryOperation::Shr => Expression::Shr(
+                Box::new(Expression::from(*expression.left)),
+                Box::new(Expression::from(*expression.right)),
+                Span::from(expression.span),
+            ),
         }
     }
 }
@@ -577,6 +695,16 @@ impl<'ast> From<UnaryExpression<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<CastExpression<'ast>> for Expression {
+    fn from(expression: CastExpression<'ast>) -> Self {
+        Expression::Cast(
+            Box::new(Expression::from(*expression.expression)),
+            Type::from(expression.type_),
+            Span::from(expression.span),
+        )
+    }
+}
+
 impl<'ast> From<AddressValue<'ast>> for Expression {
     fn from(address: AddressValue<'ast>) -> Self {
         Expression::Address(address.address.value, Span::from(address.span))
@@ -66,7 +66,9 @@ impl Input {
                 TableOrSection::Section(section) => {
                     self.program_input.parse(section)?;
                 }
-                TableOrSection::Table(table) => return Err(InputParserError::table(table)),
+                TableOrSection::Table(table) => {
+                    self.program_input.parse_table(table)?;
+                }
             }
         }
 
@@ -92,6 +94,11 @@ impl Input {
         self.program_input.get(name)
     }
 
+    /// Returns `true` if the main function input with the given `name` is a public input
+    pub fn is_public(&self, name: &String) -> bool {
+        self.program_input.is_public(name)
+    }
+
     /// Returns the runtime register input values
     pub fn get_registers(&self) -> &Registers {
         self.program_input.get_registers()
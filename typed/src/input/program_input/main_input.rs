@@ -16,16 +16,20 @@
 
 use crate::InputValue;
 use leo_input::{definitions::Definition, InputParserError};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct MainInput {
     input: HashMap<String, Option<InputValue>>,
+    public: HashSet<String>,
 }
 
 impl MainInput {
     pub fn new() -> Self {
-        Self { input: HashMap::new() }
+        Self {
+            input: HashMap::new(),
+            public: HashSet::new(),
+        }
     }
 
     /// Returns an empty version of this struct with `None` values.
@@ -37,7 +41,10 @@ impl MainInput {
             *value = None;
         });
 
-        Self { input }
+        Self {
+            input,
+            public: self.public.clone(),
+        }
     }
 
     pub fn len(&self) -> usize {
@@ -60,6 +67,26 @@ impl MainInput {
         Ok(())
     }
 
+    /// Parses main input definitions of a `[[public]]` or `[[private]]` table and stores them in `self`.
+    pub fn parse_visibility(&mut self, definitions: Vec<Definition>, is_public: bool) -> Result<(), InputParserError> {
+        for definition in definitions.iter() {
+            let name = &definition.parameter.variable.value;
+
+            if is_public {
+                self.public.insert(name.clone());
+            } else {
+                self.public.remove(name);
+            }
+        }
+
+        self.parse(definitions)
+    }
+
+    /// Returns `true` if the main function input at `name` is declared in a `[[public]]` table.
+    pub fn is_public(&self, name: &String) -> bool {
+        self.public.contains(name)
+    }
+
     /// Returns an `Option` of the main function input at `name`
     pub fn get(&self, name: &String) -> Option<Option<InputValue>> {
         self.input.get(name).map(|input| input.clone())
@@ -17,6 +17,7 @@
 use crate::{InputValue, MainInput, Registers};
 use leo_input::{
     sections::{Header, Section},
+    tables::{Table, Visibility},
     InputParserError,
 };
 
@@ -66,6 +67,28 @@ impl ProgramInput {
         }
     }
 
+    /// Parse each main input of a `[[public]]` or `[[private]]` table and store them in `self`.
+    pub fn parse_table(&mut self, table: Table) -> Result<(), InputParserError> {
+        let is_public = match table.visibility {
+            Visibility::Public(_) => true,
+            Visibility::Private(_) => false,
+        };
+
+        for section in table.sections {
+            match section.header {
+                Header::Main(_main) => self.main.parse_visibility(section.definitions, is_public)?,
+                header => return Err(InputParserError::input_table_section(header)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the main function input at `name` is public
+    pub fn is_public(&self, name: &String) -> bool {
+        self.main.is_public(name)
+    }
+
     /// Returns the main function input value with the given `name`
     pub fn get(&self, name: &String) -> Option<Option<InputValue>> {
         self.main.get(name)
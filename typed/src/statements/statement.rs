@@ -43,6 +43,20 @@ pub enum Statement {
     Expression(Expression, Span),
 }
 
+impl Statement {
+    pub fn span(&self) -> &Span {
+        match self {
+            Statement::Return(_, span) => span,
+            Statement::Definition(_, _, _, span) => span,
+            Statement::Assign(_, _, span) => span,
+            Statement::Conditional(_, span) => span,
+            Statement::Iteration(_, _, _, _, span) => span,
+            Statement::Console(console) => &console.span,
+            Statement::Expression(_, span) => span,
+        }
+    }
+}
+
 impl<'ast> From<ReturnStatement<'ast>> for Statement {
     fn from(statement: ReturnStatement<'ast>) -> Self {
         Statement::Return(Expression::from(statement.expression), Span::from(statement.span))
@@ -131,6 +145,60 @@ impl<'ast> From<AssignStatement<'ast>> for Statement {
                         ),
                         Span::from(statement.span),
                     ),
+                    AssignOperation::RemAssign(ref _assign) => Statement::Assign(
+                        Assignee::from(statement.assignee),
+                        Expression::Rem(
+                            Box::new(converted),
+                            Box::new(Expression::from(statement.expression)),
+                            Span::from(statement.span.clone()),
+                        ),
+                        Span::from(statement.span),
+                    ),
+                    AssignOperation::BitAndAssign(ref _assign) => Statement::Assign(
+                        Assignee::from(statement.assignee),
+                        Expression::BitAnd(
+                            Box::new(converted),
+                            Box::new(Expression::from(statement.expression)),
+                            Span::from(statement.span.clone()),
+                        ),
+                        Span::from(statement.span),
+                    ),
+                    AssignOperation::BitOrAssign(ref _assign) => Statement::Assign(
+                        Assignee::from(statement.assignee),
+                        Expression::BitOr(
+                            Box::new(converted),
+                            Box::new(Expression::from(statement.expression)),
+                            Span::from(statement.span.clone()),
+                        ),
+                        Span::from(statement.span),
+                    ),
+                    AssignOperation::BitXorAssign(ref _assign) => Statement::Assign(
+                        Assignee::from(statement.assignee),
+                        Expression::BitXor(
+                            Box::new(converted),
+                            Box::new(Expression::from(statement.expression)),
+                            Span::from(statement.span.clone()),
+                        ),
+                        Span::from(statement.span),
+                    ),
+                    AssignOperation::ShlAssign(ref _assign) => Statement::Assign(
+                        Assignee::from(statement.assignee),
+                        Expression::Shl(
+                            Box::new(converted),
+                            Box::new(Expression::from(statement.expression)),
+                            Span::from(statement.span.clone()),
+                        ),
+                        Span::from(statement.span),
+                    ),
+                    AssignOperation::ShrAssign(ref _assign) => Statement::Assign(
+                        Assignee::from(statement.assignee),
+                        Expression::Shr(
+                            Box::new(converted),
+                            Box::new(Expression::from(statement.expression)),
+                            Span::from(statement.span.clone()),
+                        ),
+                        Span::from(statement.span),
+                    ),
                     AssignOperation::Assign(ref _assign) => unimplemented!("cannot assign twice to assign statement"),
                 }
             }
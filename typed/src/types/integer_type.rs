@@ -44,6 +44,26 @@ pub enum IntegerType {
     I128,
 }
 
+impl IntegerType {
+    /// Returns the number of bits in the integer type.
+    pub fn size(&self) -> usize {
+        match self {
+            IntegerType::U8 | IntegerType::I8 => 8,
+            IntegerType::U16 | IntegerType::I16 => 16,
+            IntegerType::U32 | IntegerType::I32 => 32,
+            IntegerType::U64 | IntegerType::I64 => 64,
+            IntegerType::U128 | IntegerType::I128 => 128,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            IntegerType::I8 | IntegerType::I16 | IntegerType::I32 | IntegerType::I64 | IntegerType::I128
+        )
+    }
+}
+
 impl From<AstIntegerType> for IntegerType {
     fn from(integer_type: AstIntegerType) -> Self {
         match integer_type {